@@ -0,0 +1,140 @@
+//! Throughput of `trade_info` over a batch of synthetic stream transactions.
+//!
+//! Fixtures are built with the `test-support` builders and run through
+//! `extract_transaction_data` first, so the account key lists are exactly
+//! what the stream loop hands to the parser. Run with
+//! `cargo bench --features test-support`.
+
+use bonkfun_trading_snipper_grpc::{
+    BonkBuyParam, RAYDIUM_LAUNCHPAD_PROGRAM_ID, extract_transaction_data, set_quiet,
+    test_support::{TransactionUpdateBuilder, bonk_buy_data, bonk_initialize_data},
+    trade_info,
+};
+use criterion::{BatchSize, Criterion, Throughput, criterion_group, criterion_main};
+use solana_sdk::{pubkey::Pubkey, system_program};
+use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+/// Account keys and instructions of one transaction, as `trade_info` takes them
+type Fixture = (Vec<Pubkey>, Vec<CompiledInstruction>);
+
+/// Keys before this index stay static when a fixture uses lookup tables
+const STATIC_KEY_COUNT: usize = 11;
+
+/// Launchpad program at index 0, then 20 distinct accounts; index 10 is the mint
+fn launch_keys() -> Vec<Pubkey> {
+    let mut keys = vec![RAYDIUM_LAUNCHPAD_PROGRAM_ID];
+    keys.extend((0..20).map(|_| Pubkey::new_unique()));
+    keys
+}
+
+/// Builds an update from the full key list, moving the tail into lookup tables if asked
+fn extract(keys: Vec<Pubkey>, ixs: Vec<(u32, Vec<u8>, Vec<u8>)>, loaded: bool) -> Fixture {
+    let mut builder = TransactionUpdateBuilder::new();
+    builder = if loaded && keys.len() > STATIC_KEY_COUNT {
+        let (static_keys, rest) = keys.split_at(STATIC_KEY_COUNT);
+        let (writable, readonly) = rest.split_at(rest.len() / 2);
+        builder
+            .account_keys(static_keys.to_vec())
+            .loaded_addresses(writable.to_vec(), readonly.to_vec())
+    } else {
+        builder.account_keys(keys)
+    };
+    for (program_id_index, accounts, data) in ixs {
+        builder = builder.instruction(program_id_index, accounts, data);
+    }
+
+    let (account_keys, ixs, _) =
+        extract_transaction_data(&builder.build()).expect("fixture should parse into account keys");
+    (account_keys, ixs)
+}
+
+/// A launch: initialize followed by the dev buy of the same mint
+fn bonk_launch(loaded: bool) -> Fixture {
+    let init_accounts = vec![1, 2, 3, 4, 5, 6, 10, 11, 12, 13, 14, 15, 16, 17, 18];
+    let buy_accounts = vec![1, 2, 3, 4, 5, 7, 8, 9, 11, 10, 12, 13, 14, 15, 0];
+    let buy_param = BonkBuyParam {
+        amount_in: 1_000_000_000,
+        minimum_amount_out: 0,
+        share_fee_rate: 0,
+    };
+
+    extract(
+        launch_keys(),
+        vec![
+            (0, init_accounts, bonk_initialize_data("Bench Token", "BENCH", "https://example.com/bench.json")),
+            (0, buy_accounts, bonk_buy_data(&buy_param)),
+        ],
+        loaded,
+    )
+}
+
+/// A buy on an already-launched token, dropped by `require_same_tx_init`
+fn bonk_lone_buy(loaded: bool) -> Fixture {
+    let buy_accounts = vec![1, 2, 3, 4, 5, 7, 8, 9, 11, 10, 12, 13, 14, 15, 0];
+    let buy_param = BonkBuyParam {
+        amount_in: 250_000_000,
+        minimum_amount_out: 1,
+        share_fee_rate: 0,
+    };
+
+    extract(launch_keys(), vec![(0, buy_accounts, bonk_buy_data(&buy_param))], loaded)
+}
+
+/// Unrelated traffic: a system transfer plus a short-data instruction
+fn unrelated(loaded: bool) -> Fixture {
+    let mut keys = vec![system_program::ID];
+    keys.extend((0..15).map(|_| Pubkey::new_unique()));
+
+    let mut transfer = vec![2, 0, 0, 0];
+    transfer.extend_from_slice(&5_000u64.to_le_bytes());
+
+    extract(
+        keys,
+        vec![(0, vec![1, 12], transfer), (0, vec![1], vec![3, 1])],
+        loaded,
+    )
+}
+
+/// Mostly unrelated transactions with launches and lone buys mixed in, half using lookup tables
+fn mixed_batch() -> Vec<Fixture> {
+    (0..1000)
+        .map(|i| {
+            let loaded = i % 2 == 0;
+            match i % 10 {
+                0 => bonk_launch(loaded),
+                1 => bonk_lone_buy(loaded),
+                _ => unrelated(loaded),
+            }
+        })
+        .collect()
+}
+
+fn bench_batch(c: &mut Criterion, name: &str, batch: Vec<Fixture>) {
+    let mut group = c.benchmark_group("trade_info");
+    group.throughput(Throughput::Elements(batch.len() as u64));
+    group.bench_function(name, |b| {
+        b.iter_batched(
+            || batch.clone(),
+            |batch| {
+                for (account_keys, ixs) in batch {
+                    std::hint::black_box(trade_info(ixs, account_keys));
+                }
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+fn trade_info_benches(c: &mut Criterion) {
+    // Detection logs every launch it sees; keep the measurement about parsing
+    set_quiet(true);
+
+    bench_batch(c, "mixed", mixed_batch());
+    bench_batch(c, "launch_static_keys", (0..100).map(|_| bonk_launch(false)).collect());
+    bench_batch(c, "launch_loaded_addresses", (0..100).map(|_| bonk_launch(true)).collect());
+    bench_batch(c, "unrelated", (0..100).map(|i| unrelated(i % 2 == 0)).collect());
+}
+
+criterion_group!(benches, trade_info_benches);
+criterion_main!(benches);
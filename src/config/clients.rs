@@ -9,22 +9,50 @@ pub static NOZOMI_CLIENT: OnceCell<Nozomi> = OnceCell::const_new();
 pub static ZSLOT_CLIENT: OnceCell<ZeroSlot> = OnceCell::const_new();
 pub static JITO_CLIENT: OnceCell<Jito> = OnceCell::const_new();
 
-pub async fn init_nozomi() {
+pub async fn init_nozomi() -> Result<(), String> {
+    if NOZOMI_CLIENT.initialized() {
+        println!("⚠️ Nozomi client already initialized, skipping");
+        return Ok(());
+    }
+
     let nozomi_api_key = CONFIG.services.nozomi_api_key.clone();
+    if nozomi_api_key.is_empty() {
+        return Err("Nozomi API key is not configured".to_string());
+    }
 
-    let nozomi = Nozomi::new_auto(nozomi_api_key).await;
-    nozomi.health_check(50);
-    NOZOMI_CLIENT.set(nozomi).unwrap();
+    NOZOMI_CLIENT
+        .get_or_init(|| async {
+            let nozomi = Nozomi::new_auto(nozomi_api_key).await;
+            nozomi.health_check(50);
+            nozomi
+        })
+        .await;
+    Ok(())
 }
 
-pub async fn init_zslot() {
+pub async fn init_zslot() -> Result<(), String> {
+    if ZSLOT_CLIENT.initialized() {
+        println!("⚠️ ZeroSlot client already initialized, skipping");
+        return Ok(());
+    }
+
     let zslot_api_key = CONFIG.services.zero_slot_key.clone();
+    if zslot_api_key.is_empty() {
+        return Err("ZeroSlot key is not configured".to_string());
+    }
 
-    let zslot = ZeroSlot::new_auto(zslot_api_key).await;
-    ZSLOT_CLIENT.set(zslot).unwrap();
+    ZSLOT_CLIENT
+        .get_or_init(|| async { ZeroSlot::new_auto(zslot_api_key).await })
+        .await;
+    Ok(())
 }
 
-pub async fn init_jito() {
-    let jito = Jito::new_auto(None).await;
-    JITO_CLIENT.set(jito).unwrap();
+pub async fn init_jito() -> Result<(), String> {
+    if JITO_CLIENT.initialized() {
+        println!("⚠️ Jito client already initialized, skipping");
+        return Ok(());
+    }
+
+    JITO_CLIENT.get_or_init(|| async { Jito::new_auto(None).await }).await;
+    Ok(())
 }
@@ -29,47 +29,205 @@ use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{
     commitment_config::CommitmentConfig,
     pubkey::Pubkey,
-    signer::{Signer, keypair::Keypair},
+    signer::{
+        Signer,
+        keypair::{Keypair, read_keypair_file},
+    },
+};
+use rand::Rng;
+use std::{
+    env,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
 };
-use std::{env, sync::Arc};
 
 use crate::CONFIG;
 
 /**
  * Validates and loads private key from configuration
  * 
- * This function safely loads the private key from configuration,
- * validates its format, and creates a Keypair for transaction signing.
+ * This function safely loads the private key and creates a Keypair for
+ * transaction signing. Sources are checked in order of precedence:
+ * 1. `[wallet].keypair_env` - environment variable holding the key
+ * 2. `[wallet].keypair_path` - standard Solana JSON keypair file
+ * 3. `[wallet].private_key` - inline base58 string
  * 
  * Security Features:
- * - Base58 format validation
+ * - Keys are validated by decoding to exactly 64 bytes
  * - Keypair integrity verification
  * - Error handling without exposing private key data
  * 
  * @returns Keypair - Validated wallet keypair
  */
 fn load_private_key() -> Keypair {
-    let private_key_str = &CONFIG.wallet.private_key;
+    let wallet = &CONFIG.wallet;
     
-    // Validate private key format
-    if private_key_str.is_empty() {
-        panic!("❌ Private key is empty. Please configure your wallet private key.");
+    if let Some(var) = &wallet.keypair_env {
+        let _ = dotenv();
+        match env::var(var) {
+            Ok(value) => {
+                let keypair = parse_keypair_str(&value).unwrap_or_else(|e| {
+                    panic!("❌ Failed to load private key from env {}: {}", var, e)
+                });
+                println!("✅ Private key loaded from env {}", var);
+                return keypair;
+            }
+            Err(_) => {
+                println!("⚠️ Environment variable {} not set, trying other key sources", var);
+            }
+        }
     }
     
-    if private_key_str.len() < 80 {
-        panic!("❌ Private key appears to be invalid (too short). Please check your configuration.");
+    if let Some(path) = &wallet.keypair_path {
+        match read_keypair_file(path) {
+            Ok(keypair) => {
+                println!("✅ Private key loaded from keypair file");
+                return keypair;
+            }
+            Err(e) => {
+                panic!("❌ Failed to read keypair file {}: {}", path, e);
+            }
+        }
     }
     
-    // Attempt to create keypair from base58 string
-    match Keypair::from_base58_string(private_key_str) {
-        Ok(keypair) => {
-            println!("✅ Private key loaded successfully");
-            keypair
-        }
-        Err(e) => {
-            panic!("❌ Failed to load private key: {}. Please check your configuration.", e);
-        }
+    if wallet.private_key.is_empty() {
+        panic!("❌ Private key is empty. Please configure keypair_env, keypair_path or private_key.");
+    }
+    
+    let keypair = parse_keypair_str(&wallet.private_key)
+        .unwrap_or_else(|e| panic!("❌ Failed to load private key: {}. Please check your configuration.", e));
+    println!("✅ Private key loaded successfully");
+    keypair
+}
+
+/**
+ * Loads every configured signing wallet
+ * 
+ * When `[wallet].private_keys` or `[wallet].keypair_paths` are set, all of
+ * them are loaded for rotation. Otherwise the single-key sources handled by
+ * `load_private_key` form a one-element list.
+ * 
+ * @returns Vec<Arc<Keypair>> - Signing wallets, never empty
+ */
+fn load_wallets() -> Vec<Arc<Keypair>> {
+    let wallet = &CONFIG.wallet;
+    
+    if wallet.private_keys.is_empty() && wallet.keypair_paths.is_empty() {
+        return vec![Arc::new(load_private_key())];
+    }
+    
+    let mut wallets = Vec::new();
+    
+    for (i, key) in wallet.private_keys.iter().enumerate() {
+        let keypair = parse_keypair_str(key)
+            .unwrap_or_else(|e| panic!("❌ Failed to load private_keys[{}]: {}", i, e));
+        wallets.push(Arc::new(keypair));
+    }
+    
+    for path in &wallet.keypair_paths {
+        let keypair = read_keypair_file(path)
+            .unwrap_or_else(|e| panic!("❌ Failed to read keypair file {}: {}", path, e));
+        wallets.push(Arc::new(keypair));
+    }
+    
+    println!("✅ Loaded {} signing wallets ({} selection)", wallets.len(), wallet.selection);
+    wallets
+}
+
+/**
+ * Selects the wallet to sign the next trade with
+ * 
+ * Uses round-robin rotation by default, or a uniformly random pick when
+ * `[wallet].selection = "random"`.
+ * 
+ * @returns Arc<Keypair> - Selected signing wallet
+ */
+pub fn next_wallet() -> Arc<Keypair> {
+    let index = if CONFIG.wallet.selection == "random" {
+        rand::thread_rng().gen_range(0..WALLETS.len())
+    } else {
+        NEXT_WALLET_INDEX.fetch_add(1, Ordering::Relaxed) % WALLETS.len()
+    };
+    
+    WALLETS[index].clone()
+}
+
+/**
+ * Picks the keypair that pays fees for a transaction owned by `wallet`
+ * 
+ * This is `[wallet].fee_payer_private_key` when set, otherwise the
+ * trading wallet pays its own fees.
+ * 
+ * @param wallet - Trading wallet that owns the tokens
+ * @returns &Keypair - Fee payer and first signer
+ */
+pub fn fee_payer_for(wallet: &Keypair) -> &Keypair {
+    FEE_PAYER.as_ref().unwrap_or(wallet)
+}
+
+/**
+ * Public key of the account paying fees for transactions owned by `owner`
+ * 
+ * @param owner - Trading wallet that owns the tokens
+ * @returns Pubkey - Fee payer's public key
+ */
+pub fn fee_payer_pubkey(owner: &Pubkey) -> Pubkey {
+    FEE_PAYER.as_ref().map(|fee_payer| fee_payer.pubkey()).unwrap_or(*owner)
+}
+
+/**
+ * Lists the signers of a transaction owned by `wallet`, fee payer first
+ * 
+ * @param wallet - Trading wallet that owns the tokens
+ * @returns Vec<&Keypair> - The fee payer, followed by the wallet if they differ
+ */
+pub fn transaction_signers(wallet: &Keypair) -> Vec<&Keypair> {
+    let fee_payer = fee_payer_for(wallet);
+    if fee_payer.pubkey() == wallet.pubkey() {
+        vec![wallet]
+    } else {
+        vec![fee_payer, wallet]
+    }
+}
+
+/**
+ * Finds the loaded wallet with the given public key
+ * 
+ * @param owner - Public key to look up
+ * @returns Option<Arc<Keypair>> - Matching signing wallet, if loaded
+ */
+pub fn wallet_for(owner: &Pubkey) -> Option<Arc<Keypair>> {
+    WALLETS
+        .iter()
+        .chain(SHADOW_WALLET.as_ref())
+        .find(|wallet| wallet.pubkey() == *owner)
+        .cloned()
+}
+
+/**
+ * Parses a keypair from either a base58 string or a JSON byte array
+ * 
+ * @param value - Raw key material
+ * @returns Result<Keypair, String> - Parsed keypair or error without key data
+ */
+fn parse_keypair_str(value: &str) -> Result<Keypair, String> {
+    let value = value.trim();
+    
+    let bytes: Vec<u8> = if value.starts_with('[') {
+        serde_json::from_str(value).map_err(|_| "invalid JSON keypair array".to_string())?
+    } else {
+        bs58::decode(value)
+            .into_vec()
+            .map_err(|_| "invalid base58 encoding".to_string())?
+    };
+    
+    if bytes.len() != 64 {
+        return Err(format!("expected 64 key bytes, got {}", bytes.len()));
     }
+    
+    Keypair::try_from(bytes.as_slice()).map_err(|e| e.to_string())
 }
 
 /**
@@ -157,9 +315,44 @@ fn create_rpc_client(endpoint: String) -> Arc<RpcClient> {
  * This is lazily initialized to ensure configuration is loaded
  * before attempting to parse the private key.
  */
-pub static PRIVATE_KEY: Lazy<Keypair> = Lazy::new(|| {
+pub static PRIVATE_KEY: Lazy<Arc<Keypair>> = Lazy::new(|| WALLETS[0].clone());
+
+/**
+ * All signing wallets available for trade rotation
+ * 
+ * Single-key configurations produce a one-element list.
+ */
+pub static WALLETS: Lazy<Vec<Arc<Keypair>>> = Lazy::new(|| {
     println!("🔐 Loading private key...");
-    load_private_key()
+    load_wallets()
+});
+
+static NEXT_WALLET_INDEX: AtomicUsize = AtomicUsize::new(0);
+
+/**
+ * Shadow wallet from `[trade.shadow].private_key`
+ * 
+ * None unless the shadow strategy is enabled.
+ */
+pub static SHADOW_WALLET: Lazy<Option<Arc<Keypair>>> = Lazy::new(|| {
+    let shadow = CONFIG.trade.shadow.as_ref().filter(|shadow| shadow.enabled)?;
+    let keypair = parse_keypair_str(&shadow.private_key)
+        .unwrap_or_else(|e| panic!("❌ Failed to load shadow private_key: {}", e));
+    println!("👥 Shadow wallet {} mirrors every buy", keypair.pubkey());
+    Some(Arc::new(keypair))
+});
+
+/**
+ * Dedicated fee payer from `[wallet].fee_payer_private_key`
+ * 
+ * None when the trading wallets pay their own fees.
+ */
+pub static FEE_PAYER: Lazy<Option<Keypair>> = Lazy::new(|| {
+    let key = CONFIG.wallet.fee_payer_private_key.as_ref()?;
+    let keypair = parse_keypair_str(key)
+        .unwrap_or_else(|e| panic!("❌ Failed to load fee_payer_private_key: {}", e));
+    println!("⛽ Fees paid by dedicated fee payer {}", keypair.pubkey());
+    Some(keypair)
 });
 
 /**
@@ -195,6 +388,20 @@ pub static RPC_CLIENT: Lazy<Arc<RpcClient>> = Lazy::new(|| {
     create_rpc_client(RPC_ENDPOINT.clone())
 });
 
+/**
+ * RPC client for transaction sends and simulations
+ * 
+ * Uses `[rpc].send_endpoint` when set, so a paid low-latency RPC only
+ * serves the hot path; otherwise it shares the primary client.
+ */
+pub static SEND_RPC_CLIENT: Lazy<Arc<RpcClient>> = Lazy::new(|| match &CONFIG.rpc.send_endpoint {
+    Some(endpoint) => {
+        println!("🔌 Creating send RPC client: {}", endpoint);
+        create_rpc_client(endpoint.clone())
+    }
+    None => RPC_CLIENT.clone(),
+});
+
 /**
  * gRPC endpoint URL loaded from configuration
  * 
@@ -223,29 +430,62 @@ pub static GRPC_TOKEN: Lazy<String> = Lazy::new(|| {
  * This function should be called during application initialization
  * to ensure all required configuration is present and valid.
  * 
- * @returns Result<(), String> - Success or error message
+ * @returns Result<(), Vec<String>> - Success or every problem found
  */
-pub fn validate_configuration() -> Result<(), String> {
+pub fn validate_configuration() -> Result<(), Vec<String>> {
     println!("🔍 Validating configuration...");
+    let mut errors = Vec::new();
     
     // Validate private key
-    if CONFIG.wallet.private_key.is_empty() {
-        return Err("Private key is not configured".to_string());
+    let wallet = &CONFIG.wallet;
+    if wallet.private_key.is_empty()
+        && wallet.keypair_path.is_none()
+        && wallet.keypair_env.is_none()
+        && wallet.private_keys.is_empty()
+        && wallet.keypair_paths.is_empty()
+    {
+        errors.push("Private key is not configured".to_string());
+    }
+    
+    if wallet.selection != "round_robin" && wallet.selection != "random" {
+        errors.push(format!("Unknown wallet selection: {} (expected round_robin or random)", wallet.selection));
+    }
+    
+    if let Some(shadow) = CONFIG.trade.shadow.as_ref().filter(|shadow| shadow.enabled) {
+        if let Err(e) = parse_keypair_str(&shadow.private_key) {
+            errors.push(format!("shadow private_key is invalid: {}", e));
+        }
+    }
+    
+    if let Some(key) = &wallet.fee_payer_private_key {
+        if let Err(e) = parse_keypair_str(key) {
+            errors.push(format!("fee_payer_private_key is invalid: {}", e));
+        }
     }
     
     // Validate RPC endpoint
     if CONFIG.rpc.endpoint.is_empty() {
-        return Err("RPC endpoint is not configured".to_string());
+        errors.push("RPC endpoint is not configured".to_string());
+    }
+    
+    if let Some(endpoint) = &CONFIG.rpc.send_endpoint {
+        if !endpoint.starts_with("http://") && !endpoint.starts_with("https://") {
+            errors.push(format!("RPC send_endpoint must start with http:// or https://: {}", endpoint));
+        }
     }
     
     // Validate gRPC endpoint
     if CONFIG.grpc.endpoint.is_empty() {
-        return Err("gRPC endpoint is not configured".to_string());
+        errors.push("gRPC endpoint is not configured".to_string());
     }
     
     // Validate gRPC token
     if CONFIG.grpc.token.is_empty() {
-        return Err("gRPC token is not configured".to_string());
+        errors.push("gRPC token is not configured".to_string());
+    }
+    
+    if !errors.is_empty() {
+        return Err(errors);
     }
     
     println!("✅ Configuration validation passed");
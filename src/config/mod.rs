@@ -1,9 +1,13 @@
+#[cfg(feature = "relayers")]
 pub mod clients;
 pub mod credentials;
+pub mod runtime_setting;
 pub mod toml_setting;
 pub mod trade_setting;
 
+#[cfg(feature = "relayers")]
 pub use clients::*;
 pub use credentials::*;
+pub use runtime_setting::*;
 pub use toml_setting::*;
 pub use trade_setting::*;
@@ -0,0 +1,97 @@
+/**
+ * 🧭 Runtime Settings Module - Bonk.fun Trading Sniper Bot
+ * 
+ * This module holds settings chosen at launch time from the command line
+ * rather than from config.toml: the config file location, dry-run mode
+ * and the log verbosity.
+ * 
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use once_cell::sync::OnceCell;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+/// Default config file looked up in the working directory
+pub const DEFAULT_CONFIG_PATH: &str = "config.toml";
+
+static CONFIG_PATH: OnceCell<PathBuf> = OnceCell::new();
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+static LOG_LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Info as u8);
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Log verbosity, ordered from least to most verbose
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error = 0,
+    Warn = 1,
+    Info = 2,
+    Debug = 3,
+}
+
+impl std::str::FromStr for LogLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Ok(LogLevel::Error),
+            "warn" => Ok(LogLevel::Warn),
+            "info" => Ok(LogLevel::Info),
+            "debug" => Ok(LogLevel::Debug),
+            _ => Err(format!("unknown log level: {} (expected error/warn/info/debug)", s)),
+        }
+    }
+}
+
+/**
+ * Sets the config file path used by `CONFIG`
+ * 
+ * Must be called before `CONFIG` is first accessed; later calls are ignored.
+ * 
+ * @param path - Path to the config file
+ */
+pub fn set_config_path(path: PathBuf) {
+    if CONFIG_PATH.get_or_init(|| path.clone()) != &path {
+        println!("⚠️ Config path already set, ignoring override");
+    }
+}
+
+/// Returns the config file path, defaulting to `config.toml`
+pub fn config_path() -> PathBuf {
+    CONFIG_PATH
+        .get()
+        .cloned()
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_CONFIG_PATH))
+}
+
+/// Enables or disables dry-run mode (build trades but never submit them)
+pub fn set_dry_run(enabled: bool) {
+    DRY_RUN.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns true when trades must not be submitted
+pub fn is_dry_run() -> bool {
+    DRY_RUN.load(Ordering::Relaxed)
+}
+
+/// Sets the active log verbosity
+pub fn set_log_level(level: LogLevel) {
+    LOG_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Returns true when messages at `level` should be printed
+pub fn log_enabled(level: LogLevel) -> bool {
+    LOG_LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+/// Enables or disables quiet mode (no per-transaction chatter)
+pub fn set_quiet(enabled: bool) {
+    QUIET.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns true when per-transaction chatter should be printed
+pub fn chatter_enabled() -> bool {
+    !QUIET.load(Ordering::Relaxed) && log_enabled(LogLevel::Info)
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct ControlConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_control_bind")]
+    pub bind: String,
+    #[serde(default)]
+    pub secret: String,
+}
+
+impl Default for ControlConfig {
+    fn default() -> Self {
+        ControlConfig {
+            enabled: false,
+            bind: default_control_bind(),
+            secret: String::new(),
+        }
+    }
+}
+
+fn default_control_bind() -> String {
+    "127.0.0.1:8787".to_string()
+}
@@ -2,16 +2,125 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 pub struct WalletConfig {
+    #[serde(default)]
     pub private_key: String,
+    pub keypair_path: Option<String>,
+    pub keypair_env: Option<String>,
+    #[serde(default)]
+    pub private_keys: Vec<String>,
+    #[serde(default)]
+    pub keypair_paths: Vec<String>,
+    #[serde(default = "default_wallet_selection")]
+    pub selection: String,
+    pub fee_payer_private_key: Option<String>,
+}
+
+fn default_wallet_selection() -> String {
+    "round_robin".to_string()
 }
 
 #[derive(Debug, Deserialize)]
 pub struct RpcConfig {
     pub endpoint: String,
+    pub send_endpoint: Option<String>,
+    #[serde(default = "default_max_blockhash_age_secs")]
+    pub max_blockhash_age_secs: u64,
+    #[serde(default = "default_pause_on_stale_blockhash")]
+    pub pause_on_stale_blockhash: bool,
+}
+
+fn default_max_blockhash_age_secs() -> u64 {
+    20
+}
+
+fn default_pause_on_stale_blockhash() -> bool {
+    true
 }
 
 #[derive(Debug, Deserialize)]
 pub struct GrpcConfig {
     pub endpoint: String,
     pub token: String,
-}
\ No newline at end of file
+    #[serde(default = "default_keep_alive_interval_secs")]
+    pub keep_alive_interval_secs: u64,
+    #[serde(default = "default_keep_alive_timeout_secs")]
+    pub keep_alive_timeout_secs: u64,
+    #[serde(default = "default_keep_alive_while_idle")]
+    pub keep_alive_while_idle: bool,
+    #[serde(default)]
+    pub resume_on_reconnect: bool,
+    #[serde(default = "default_max_resume_gap_slots")]
+    pub max_resume_gap_slots: u64,
+    #[serde(default = "default_reconnect_delay_ms")]
+    pub reconnect_delay_ms: u64,
+    #[serde(default = "default_preflight_timeout_secs")]
+    pub preflight_timeout_secs: u64,
+    #[serde(default = "default_connect_max_attempts")]
+    pub connect_max_attempts: u32,
+    #[serde(default = "default_connect_retry_delay_ms")]
+    pub connect_retry_delay_ms: u64,
+    #[serde(default = "default_reconnect_max_delay_ms")]
+    pub reconnect_max_delay_ms: u64,
+    #[serde(default = "default_backoff_jitter")]
+    pub backoff_jitter: bool,
+    #[serde(default = "default_raw_channel_capacity")]
+    pub raw_channel_capacity: usize,
+    #[serde(default = "default_seen_signatures_capacity")]
+    pub seen_signatures_capacity: usize,
+    #[serde(default)]
+    pub include_failed: bool,
+    #[serde(default)]
+    pub include_vote: bool,
+    #[serde(default)]
+    pub parse_without_meta: bool,
+    #[serde(default)]
+    pub slot_state_path: Option<String>,
+}
+
+fn default_keep_alive_interval_secs() -> u64 {
+    10
+}
+
+fn default_keep_alive_timeout_secs() -> u64 {
+    5
+}
+
+fn default_keep_alive_while_idle() -> bool {
+    true
+}
+
+fn default_max_resume_gap_slots() -> u64 {
+    150
+}
+
+fn default_reconnect_delay_ms() -> u64 {
+    1000
+}
+
+fn default_preflight_timeout_secs() -> u64 {
+    30
+}
+
+fn default_connect_max_attempts() -> u32 {
+    5
+}
+
+fn default_connect_retry_delay_ms() -> u64 {
+    500
+}
+
+fn default_reconnect_max_delay_ms() -> u64 {
+    30_000
+}
+
+fn default_raw_channel_capacity() -> usize {
+    1024
+}
+
+fn default_seen_signatures_capacity() -> usize {
+    10_000
+}
+
+fn default_backoff_jitter() -> bool {
+    true
+}
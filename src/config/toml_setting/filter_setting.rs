@@ -4,8 +4,94 @@ use serde::{Deserialize, Serialize};
 pub struct FilterSetting {
     pub x_check: bool,
     pub x_filter_list: Vec<String>,
+    #[serde(default)]
+    pub x_required_fields: Vec<String>,
     pub dev_buy_check: bool,
-    pub dev_buy_limit: f64, // In lamports (1 SOL = 1_000_000_000 lamports)
+    // In SOL; `dev_buy_limit` is the old name for the lower bound
+    #[serde(alias = "dev_buy_limit", default)]
+    pub dev_buy_min: f64,
+    #[serde(default)]
+    pub dev_buy_max: Option<f64>,
     pub token_name_check: bool,
     pub token_name_filter_list: Vec<String>,
-}
\ No newline at end of file
+    #[serde(default = "default_allowed_quote_mints")]
+    pub allowed_quote_mints: Vec<String>,
+    #[serde(default = "default_allowed_token_programs")]
+    pub allowed_token_programs: Vec<String>,
+    #[serde(default = "default_moonshot_allowed_collateral")]
+    pub moonshot_allowed_collateral: Vec<String>,
+    #[serde(default)]
+    pub honeypot_check: bool,
+    // In SOL; 0 disables the quote-vault liquidity gate
+    #[serde(default)]
+    pub min_quote_liquidity_sol: f64,
+    #[serde(default = "default_filter_mode")]
+    pub mode: String,
+    #[serde(default = "default_log_rejects")]
+    pub log_rejects: bool,
+    #[serde(default = "default_metadata_host_allowlist")]
+    pub metadata_host_allowlist: Vec<String>,
+    #[serde(default = "default_empty_uri_policy")]
+    pub empty_uri_policy: String,
+    #[serde(default = "default_empty_uri_retry_ms")]
+    pub empty_uri_retry_ms: u64,
+    #[serde(default)]
+    pub max_share_fee_rate: Option<u64>,
+    #[serde(default = "default_max_instructions_scanned")]
+    pub max_instructions_scanned: usize,
+    #[serde(default = "default_require_same_tx_init")]
+    pub require_same_tx_init: bool,
+}
+
+fn default_filter_mode() -> String {
+    "all".to_string()
+}
+
+fn default_allowed_quote_mints() -> Vec<String> {
+    vec![spl_token::native_mint::ID.to_string()]
+}
+
+fn default_allowed_token_programs() -> Vec<String> {
+    vec![spl_token::ID.to_string()]
+}
+
+fn default_moonshot_allowed_collateral() -> Vec<String> {
+    vec!["sol".to_string()]
+}
+
+fn default_log_rejects() -> bool {
+    true
+}
+
+fn default_require_same_tx_init() -> bool {
+    true
+}
+
+fn default_empty_uri_policy() -> String {
+    "reject".to_string()
+}
+
+fn default_empty_uri_retry_ms() -> u64 {
+    500
+}
+
+fn default_max_instructions_scanned() -> usize {
+    64
+}
+
+fn default_metadata_host_allowlist() -> Vec<String> {
+    [
+        "ipfs.io",
+        "dweb.link",
+        "w3s.link",
+        "nftstorage.link",
+        "cf-ipfs.com",
+        "gateway.pinata.cloud",
+        "mypinata.cloud",
+        "arweave.net",
+        "irys.xyz",
+    ]
+    .iter()
+    .map(|host| host.to_string())
+    .collect()
+}
@@ -1,14 +1,21 @@
-use std::fs;
+use std::{
+    env, fmt, fs,
+    path::{Path, PathBuf},
+};
 
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
 use serde::Deserialize;
 
+pub mod control_setting;
 pub mod credentials;
 pub mod filter_setting;
+pub mod output_setting;
 pub mod trade_settings;
 
+pub use control_setting::*;
 pub use credentials::*;
 pub use filter_setting::*;
+pub use output_setting::*;
 pub use trade_settings::*;
 
 #[derive(Debug, Deserialize)]
@@ -20,9 +27,214 @@ pub struct Config {
     pub priority_fee: PriorityFeeConfig,
     pub services: ServicesConfig,
     pub filter: FilterSetting,
+    #[serde(default)]
+    pub output: OutputConfig,
+    #[serde(default)]
+    pub log: LogConfig,
+    #[serde(default)]
+    pub control: ControlConfig,
 }
 
-pub static CONFIG: Lazy<Config> = Lazy::new(|| {
-    let content = fs::read_to_string("config.toml").expect("Failed to read config.toml");
-    toml::from_str(&content).expect("Failed to parse config.toml")
+/// Errors raised while loading the config file
+#[derive(Debug)]
+pub enum ConfigError {
+    NotFound { path: PathBuf },
+    Io { path: PathBuf, source: std::io::Error },
+    Parse { path: PathBuf, line: Option<usize>, column: Option<usize>, message: String },
+    AlreadyLoaded,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::NotFound { path } => write!(
+                f,
+                "config file not found: {} (run from the project root, or pass --config <path>)",
+                path.display()
+            ),
+            ConfigError::Io { path, source } => {
+                write!(f, "failed to read config file {}: {}", path.display(), source)
+            }
+            ConfigError::Parse { path, line: Some(line), column: Some(column), message } => write!(
+                f,
+                "failed to parse {} at line {}, column {}: {}",
+                path.display(),
+                line,
+                column,
+                message
+            ),
+            ConfigError::Parse { path, message, .. } => {
+                write!(f, "failed to parse {}: {}", path.display(), message)
+            }
+            ConfigError::AlreadyLoaded => {
+                write!(f, "a config is already loaded; only one can be used per process")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Env vars starting with this override config fields, e.g. `BONK_TRADE__BUY_SOL_AMOUNT`
+pub const ENV_OVERRIDE_PREFIX: &str = "BONK_";
+
+impl Config {
+    /**
+     * Loads and parses a config file, then applies env var overrides
+     * 
+     * Any field can be overridden by `BONK_<SECTION>__<FIELD>`, with `__`
+     * between nesting levels (e.g. `BONK_WALLET__PRIVATE_KEY`). Env vars
+     * win over the file, and a `.env` file in the working directory is
+     * read too. Without any such vars the file is used as-is.
+     * 
+     * @param path - Path to the TOML config file
+     * @returns Result<Config, ConfigError> - Parsed config or typed error
+     */
+    pub fn load_from(path: &Path) -> Result<Config, ConfigError> {
+        let content = fs::read_to_string(path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => ConfigError::NotFound { path: path.to_path_buf() },
+            _ => ConfigError::Io { path: path.to_path_buf(), source: e },
+        })?;
+
+        let _ = dotenvy::dotenv();
+        let overrides = env_overrides();
+        if !overrides.is_empty() {
+            return Config::load_with_overrides(path, &content, overrides);
+        }
+
+        toml::from_str(&content).map_err(|e| parse_error(path, &content, e))
+    }
+}
+
+impl Config {
+    /// Parses the file as a table, applies the overrides and deserializes the result
+    fn load_with_overrides(
+        path: &Path,
+        content: &str,
+        overrides: Vec<(Vec<String>, String)>,
+    ) -> Result<Config, ConfigError> {
+        let mut table: toml::Table = toml::from_str(content).map_err(|e| parse_error(path, content, e))?;
+        for (keys, raw) in &overrides {
+            apply_env_override(&mut table, keys, raw);
+            println!("🌱 Config {} overridden from the environment", keys.join("."));
+        }
+
+        // Spans point into the file, which no longer matches the merged table
+        toml::Value::Table(table).try_into().map_err(|e: toml::de::Error| ConfigError::Parse {
+            path: path.to_path_buf(),
+            line: None,
+            column: None,
+            message: format!("{} (after env overrides)", e.message()),
+        })
+    }
+}
+
+/// Turns a TOML error into a `ConfigError` pointing at its line and column in the file
+fn parse_error(path: &Path, content: &str, e: toml::de::Error) -> ConfigError {
+    let (line, column) = match e.span() {
+        Some(span) => {
+            let (line, column) = line_column(content, span.start);
+            (Some(line), Some(column))
+        }
+        None => (None, None),
+    };
+    ConfigError::Parse {
+        path: path.to_path_buf(),
+        line,
+        column,
+        message: e.message().to_string(),
+    }
+}
+
+/// Collects `BONK_SECTION__FIELD=value` env vars as (lowercased key path, raw value), sorted by path
+fn env_overrides() -> Vec<(Vec<String>, String)> {
+    let mut overrides: Vec<_> = env::vars()
+        .filter_map(|(name, value)| {
+            let keys: Vec<String> = name
+                .strip_prefix(ENV_OVERRIDE_PREFIX)?
+                .split("__")
+                .map(|key| key.to_ascii_lowercase())
+                .collect();
+            (keys.len() >= 2 && keys.iter().all(|key| !key.is_empty())).then_some((keys, value))
+        })
+        .collect();
+    overrides.sort();
+    overrides
+}
+
+/**
+ * Sets one key path in the parsed table from a raw env value
+ * 
+ * The value is read as a TOML literal (number, bool, array), except where
+ * the file already has a string there, so a secret like `12345` or `true`
+ * stays a string. Anything that isn't a valid literal is taken as a string.
+ * 
+ * @param table - Parsed config file
+ * @param keys - Lowercased key path, e.g. ["trade", "buy_sol_amount"]
+ * @param raw - Env var value
+ */
+fn apply_env_override(table: &mut toml::Table, keys: &[String], raw: &str) {
+    let (field, sections) = keys.split_last().expect("override paths have at least two keys");
+
+    let mut current = table;
+    for section in sections {
+        let entry = current
+            .entry(section.clone())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        if !entry.is_table() {
+            *entry = toml::Value::Table(toml::Table::new());
+        }
+        current = entry.as_table_mut().expect("entry was just made a table");
+    }
+
+    let value = match current.get(field) {
+        Some(toml::Value::String(_)) => toml::Value::String(raw.to_string()),
+        _ => toml::from_str::<toml::Table>(&format!("value = {}", raw))
+            .ok()
+            .and_then(|mut parsed| parsed.remove("value"))
+            .unwrap_or_else(|| toml::Value::String(raw.to_string())),
+    };
+    current.insert(field.clone(), value);
+}
+
+/// Converts a byte offset into a 1-based (line, column) pair
+fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map(|i| i + 1).unwrap_or(0) + 1;
+    (line, column)
+}
+
+static LOADED_CONFIG: OnceCell<Config> = OnceCell::new();
+
+/**
+ * Loads the config from `path` and makes it the global `CONFIG`
+ * 
+ * Call this early in `main` so a missing or malformed file is reported
+ * before anything else touches `CONFIG`.
+ * 
+ * @param path - Path to the TOML config file
+ * @returns Result<&'static Config, ConfigError> - Loaded config or typed error
+ */
+pub fn load_config(path: &Path) -> Result<&'static Config, ConfigError> {
+    crate::set_config_path(path.to_path_buf());
+    LOADED_CONFIG.get_or_try_init(|| Config::load_from(path))
+}
+
+/**
+ * Makes an already parsed config the global `CONFIG`
+ * 
+ * Used when embedding the sniper with a config built in code. Fails if a
+ * config was already loaded, since `CONFIG` can't change once read.
+ * 
+ * @param config - Parsed configuration
+ * @returns Result<&'static Config, ConfigError> - Installed config or typed error
+ */
+pub fn install_config(config: Config) -> Result<&'static Config, ConfigError> {
+    LOADED_CONFIG.set(config).map_err(|_| ConfigError::AlreadyLoaded)?;
+    Ok(LOADED_CONFIG.get().expect("config was just installed"))
+}
+
+pub static CONFIG: Lazy<&'static Config> = Lazy::new(|| {
+    load_config(&crate::config_path()).unwrap_or_else(|e| panic!("❌ {}", e))
 });
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct OutputConfig {
+    #[serde(default)]
+    pub emit_launches: bool,
+    #[serde(default)]
+    pub audit_log_path: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct LogConfig {
+    #[serde(default)]
+    pub quiet: bool,
+}
@@ -3,19 +3,250 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Deserialize)]
 pub struct TradeConfig {
     pub buy_sol_amount: f64,
+    #[serde(default = "default_buy_mode")]
+    pub buy_mode: String,
+    #[serde(default)]
+    pub buy_percent: f64,
+    #[serde(default = "default_max_buy_sol")]
+    pub max_buy_sol: f64,
+    #[serde(default)]
+    pub copy_dev_buy: bool,
+    #[serde(default = "default_min_buy_sol")]
+    pub min_buy_sol: f64,
     pub third_party_fee: f64,
     pub slippage: f64,
+    #[serde(default)]
+    pub min_tokens_out: u64,
+    pub bonk_buy_sol_amount: Option<f64>,
+    pub pump_buy_sol_amount: Option<f64>,
+    pub moonshot_buy_sol_amount: Option<f64>,
+    #[serde(default = "default_enabled_programs")]
+    pub enabled_programs: Vec<String>,
+    #[serde(default = "default_submit_max_attempts")]
+    pub submit_max_attempts: u32,
+    #[serde(default = "default_submit_base_delay_ms")]
+    pub submit_base_delay_ms: u64,
+    #[serde(default = "default_submit_retry_budget_ms")]
+    pub submit_retry_budget_ms: u64,
+    #[serde(default = "default_max_update_age_ms")]
+    pub max_update_age_ms: u64,
+    #[serde(default)]
+    pub skip_ata_check: bool,
+    #[serde(default = "default_close_wsol_after_buy")]
+    pub close_wsol_after_buy: bool,
+    #[serde(default)]
+    pub verify_buy_balance: bool,
+    #[serde(default = "default_wrap_buffer_lamports")]
+    pub wrap_buffer_lamports: u64,
+    #[serde(default = "default_max_total_fee_sol")]
+    pub max_total_fee_sol: f64,
+    #[serde(default)]
+    pub max_trades_per_minute: u32,
+    #[serde(default)]
+    pub cooldown_ms: u64,
+    #[serde(default)]
+    pub max_trades: u64,
+    pub kill_switch_path: Option<String>,
+    #[serde(default)]
+    pub active_windows: Vec<String>,
+    #[serde(default = "default_trade_workers")]
+    pub trade_workers: usize,
+    #[serde(default = "default_queue_capacity")]
+    pub queue_capacity: usize,
+    #[serde(default = "default_queue_full_policy")]
+    pub queue_full_policy: String,
+    #[serde(default = "default_confirm_timeout_ms")]
+    pub confirm_timeout_ms: u64,
+    #[serde(default)]
+    pub use_versioned_tx: bool,
+    #[serde(default = "default_max_tx_accounts")]
+    pub max_tx_accounts: usize,
+    #[serde(default)]
+    pub lookup_tables: Vec<String>,
+    #[serde(default)]
+    pub max_hold_seconds: u64,
+    #[serde(default = "default_position_poll_interval_ms")]
+    pub position_poll_interval_ms: u64,
+    #[serde(default = "default_force_sell_min_out_pct")]
+    pub force_sell_min_out_pct: f64,
+    #[serde(default)]
+    pub sell_ladder: Vec<LadderRung>,
+    #[serde(default)]
+    pub trailing_stop_pct: f64,
+    #[serde(default)]
+    pub require_confirmed: bool,
+    #[serde(default = "default_confirm_window_ms")]
+    pub confirm_window_ms: u64,
+    pub shadow: Option<ShadowConfig>,
+}
+
+/// One take-profit step of `sell_ladder`
+#[derive(Debug, Clone, Deserialize)]
+pub struct LadderRung {
+    /// Share of the original position to sell, in %
+    pub pct_of_position: f64,
+    /// Gain over the entry price that triggers the sell, in % (100 = 2x)
+    pub at_gain: f64,
+}
+
+/// Second wallet mirroring every buy with its own sizing, for A/B comparisons
+#[derive(Debug, Deserialize)]
+pub struct ShadowConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub private_key: String,
+    pub buy_sol_amount: f64,
+    pub slippage: f64,
+}
+
+fn default_buy_mode() -> String {
+    "fixed".to_string()
+}
+
+fn default_max_buy_sol() -> f64 {
+    1.0
+}
+
+fn default_min_buy_sol() -> f64 {
+    0.0001
+}
+
+fn default_trade_workers() -> usize {
+    4
+}
+
+fn default_queue_capacity() -> usize {
+    64
+}
+
+fn default_max_tx_accounts() -> usize {
+    35
+}
+
+fn default_enabled_programs() -> Vec<String> {
+    vec!["bonk".to_string(), "pump".to_string(), "moonshot".to_string()]
+}
+
+fn default_queue_full_policy() -> String {
+    "drop_newest".to_string()
+}
+
+fn default_submit_max_attempts() -> u32 {
+    5
+}
+
+fn default_submit_base_delay_ms() -> u64 {
+    50
+}
+
+fn default_submit_retry_budget_ms() -> u64 {
+    2000
 }
 
 #[derive(Debug, Deserialize)]
 pub struct PriorityFeeConfig {
     pub cu: u64,
     pub priority_fee_micro_lamport: u64,
+    #[serde(default)]
+    pub dynamic: bool,
+    #[serde(default = "default_dynamic_percentile")]
+    pub dynamic_percentile: u8,
+    #[serde(default = "default_max_micro_lamport")]
+    pub max_micro_lamport: u64,
+    #[serde(default = "default_dynamic_refresh_ms")]
+    pub dynamic_refresh_ms: u64,
+    #[serde(default)]
+    pub auto_cu: bool,
+    #[serde(default = "default_auto_cu_margin_pct")]
+    pub auto_cu_margin_pct: f64,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct ServicesConfig {
+    #[serde(default)]
     pub nozomi_api_key: String,
+    #[serde(default)]
     pub zero_slot_key: String,
     pub confirm_service: String,
+    #[serde(default = "default_jito_tip_sol")]
+    pub jito_tip_sol: f64,
+    #[serde(default)]
+    pub use_jito_bundles: bool,
+    #[serde(default = "default_jito_block_engine_url")]
+    pub jito_block_engine_url: String,
+    #[serde(default = "default_bundle_timeout_ms")]
+    pub bundle_timeout_ms: u64,
+    #[serde(default = "default_init_timeout_ms")]
+    pub init_timeout_ms: u64,
+    #[serde(default = "default_submit_timeout_ms")]
+    pub submit_timeout_ms: u64,
+}
+
+fn default_dynamic_percentile() -> u8 {
+    75
+}
+
+fn default_max_micro_lamport() -> u64 {
+    100_000
+}
+
+fn default_dynamic_refresh_ms() -> u64 {
+    2000
+}
+
+fn default_auto_cu_margin_pct() -> f64 {
+    15.0
+}
+
+fn default_init_timeout_ms() -> u64 {
+    10_000
+}
+
+fn default_submit_timeout_ms() -> u64 {
+    2000
+}
+
+fn default_jito_block_engine_url() -> String {
+    "https://mainnet.block-engine.jito.wtf".to_string()
+}
+
+fn default_bundle_timeout_ms() -> u64 {
+    5000
+}
+
+fn default_jito_tip_sol() -> f64 {
+    0.0001
+}
+
+fn default_max_update_age_ms() -> u64 {
+    2000
+}
+
+fn default_close_wsol_after_buy() -> bool {
+    true
+}
+
+fn default_wrap_buffer_lamports() -> u64 {
+    5000
+}
+
+fn default_confirm_timeout_ms() -> u64 {
+    30000
+}
+
+fn default_max_total_fee_sol() -> f64 {
+    0.01
+}
+
+fn default_position_poll_interval_ms() -> u64 {
+    1000
+}
+
+fn default_force_sell_min_out_pct() -> f64 {
+    10.0
+}
+
+fn default_confirm_window_ms() -> u64 {
+    3000
 }
@@ -25,13 +25,28 @@
 
 use dotenvy::dotenv;
 use once_cell::sync::Lazy;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashSet;
 use std::env;
 use std::fs::{File, OpenOptions};
 use std::io::{BufRead, BufReader};
+use std::net::SocketAddr;
 use std::path::Path;
+use std::str::FromStr;
 use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::CONFIG;
+use crate::{
+    BONK_FEE_RATE_DENOMINATOR, CONFIG, FilterRejectReason, Launchpad, MOON_FIXED_SIDE_EXACT_IN,
+    MOONSHOT_COLLATERAL_SOL, MoonBuyParam, MoonshotMintInfo, current_priority_fee,
+};
+
+/// Sanity bounds for any configured buy amount, in SOL
+const MIN_BUY_SOL_AMOUNT: f64 = 0.0001;
+const MAX_BUY_SOL_AMOUNT: f64 = 10.0;
+
+/// Slack when checking that ladder percentages add up to 100, for float sums like 33.3 * 3
+pub const LADDER_PCT_EPSILON: f64 = 0.01;
 
 /**
  * Validates and loads confirmation service configuration
@@ -51,7 +66,7 @@ fn load_confirmation_service() -> String {
             service
         }
         _ => {
-            eprintln!("⚠️ Invalid confirmation service: {}. Defaulting to NOZOMI", service);
+            println!("⚠️ Invalid confirmation service: {}. Defaulting to NOZOMI", service);
             "NOZOMI".to_string()
         }
     }
@@ -72,20 +87,20 @@ fn load_priority_fee_config() -> (u64, u64, f64) {
     
     // Validate compute units (typical range: 50k - 1.4M)
     if cu < 50_000 || cu > 1_400_000 {
-        eprintln!("⚠️ Compute units out of recommended range: {} (should be 50k-1.4M)", cu);
+        println!("⚠️ Compute units out of recommended range: {} (should be 50k-1.4M)", cu);
     }
     
     // Validate priority fee (typical range: 1 - 1000 micro-lamports)
     if priority_fee_micro_lamport < 1 || priority_fee_micro_lamport > 1000 {
-        eprintln!("⚠️ Priority fee out of recommended range: {} micro-lamports (should be 1-1000)", priority_fee_micro_lamport);
+        println!("⚠️ Priority fee out of recommended range: {} micro-lamports (should be 1-1000)", priority_fee_micro_lamport);
     }
     
     // Validate third party fee
     if third_party_fee < 0.0 || third_party_fee > 1.0 {
-        eprintln!("⚠️ Third party fee out of valid range: {} (should be 0.0-1.0)", third_party_fee);
+        println!("⚠️ Third party fee out of valid range: {} (should be 0.0-1.0)", third_party_fee);
     }
     
-    println!("✅ Priority fee configured: {} CU, {} micro-lamports, {} SOL fee", 
+    println!("✅ Priority fee configured: {} CU, {} micro-lamports, {} third party fee rate", 
              cu, priority_fee_micro_lamport, third_party_fee);
     
     (cu, priority_fee_micro_lamport, third_party_fee)
@@ -100,25 +115,168 @@ fn load_priority_fee_config() -> (u64, u64, f64) {
  * @returns u64 - Buy amount in lamports
  */
 fn load_buy_amount() -> u64 {
-    let buy_sol_amount = CONFIG.trade.buy_sol_amount;
-    
+    sol_buy_amount_to_lamports("Buy amount", CONFIG.trade.buy_sol_amount)
+}
+
+/**
+ * Validates and loads a per-launchpad buy amount override
+ * 
+ * Falls back to the global `buy_sol_amount` when the override is unset.
+ * 
+ * @param label - Launchpad name for logging
+ * @param override_sol - Optional override in SOL
+ * @returns u64 - Buy amount in lamports
+ */
+fn load_launchpad_buy_amount(label: &str, override_sol: Option<f64>) -> u64 {
+    match override_sol {
+        Some(amount) => sol_buy_amount_to_lamports(&format!("{} buy amount", label), amount),
+        None => *BUY_SOL_AMOUNT,
+    }
+}
+
+/**
+ * Checks a SOL buy amount against the sanity bounds and converts it to lamports
+ * 
+ * @param label - Setting name for logging
+ * @param buy_sol_amount - Buy amount in SOL
+ * @returns u64 - Buy amount in lamports
+ */
+fn sol_buy_amount_to_lamports(label: &str, buy_sol_amount: f64) -> u64 {
     // Validate buy amount (minimum 0.0001 SOL, maximum 10 SOL)
-    if buy_sol_amount < 0.0001 {
-        eprintln!("⚠️ Buy amount too small: {} SOL (minimum 0.0001 SOL)", buy_sol_amount);
+    if buy_sol_amount < MIN_BUY_SOL_AMOUNT {
+        println!("⚠️ {} too small: {} SOL (minimum 0.0001 SOL)", label, buy_sol_amount);
     }
     
-    if buy_sol_amount > 10.0 {
-        eprintln!("⚠️ Buy amount too large: {} SOL (maximum 10 SOL)", buy_sol_amount);
+    if buy_sol_amount > MAX_BUY_SOL_AMOUNT {
+        println!("⚠️ {} too large: {} SOL (maximum 10 SOL)", label, buy_sol_amount);
     }
     
     // Convert SOL to lamports (1 SOL = 10^9 lamports)
     let buy_amount_lamports = (buy_sol_amount * 10_f64.powf(9.0)) as u64;
     
-    println!("✅ Buy amount configured: {} SOL ({} lamports)", buy_sol_amount, buy_amount_lamports);
+    println!("✅ {} configured: {} SOL ({} lamports)", label, buy_sol_amount, buy_amount_lamports);
     
     buy_amount_lamports
 }
 
+/**
+ * Returns the `[trade.shadow]` settings when the shadow strategy is enabled
+ * 
+ * @returns Option<&'static ShadowConfig> - Shadow settings, None when disabled
+ */
+pub fn shadow_config() -> Option<&'static ShadowConfig> {
+    CONFIG.trade.shadow.as_ref().filter(|shadow| shadow.enabled)
+}
+
+/**
+ * Returns the buy amount for a launchpad
+ * 
+ * @param launchpad - Launchpad the opportunity was detected on
+ * @returns u64 - Buy amount in lamports
+ */
+pub fn buy_amount_for(launchpad: Launchpad) -> u64 {
+    match launchpad {
+        Launchpad::Bonk => *BONK_BUY_SOL_AMOUNT,
+        Launchpad::Pump => *PUMP_BUY_SOL_AMOUNT,
+        Launchpad::Moonshot => *MOONSHOT_BUY_SOL_AMOUNT,
+    }
+}
+
+/**
+ * Sizes a percent-mode buy from the wallet balance
+ * 
+ * The amount is `buy_percent` of the balance, capped at `max_buy_sol`.
+ * Amounts under the 0.0001 SOL minimum come back as None so a low
+ * balance skips the trade instead of sending a dust buy.
+ * 
+ * @param wallet_balance - Cached wallet balance in lamports
+ * @returns Option<u64> - Buy amount in lamports, or None if too small
+ */
+pub fn percent_buy_amount(wallet_balance: u64) -> Option<u64> {
+    let amount = (wallet_balance as f64 * CONFIG.trade.buy_percent / 100.0) as u64;
+    let cap = (CONFIG.trade.max_buy_sol * 10_f64.powf(9.0)) as u64;
+    let min = (MIN_BUY_SOL_AMOUNT * 10_f64.powf(9.0)) as u64;
+    
+    let amount = amount.min(cap);
+    (amount >= min).then_some(amount)
+}
+
+/**
+ * Sizes a copy-trade buy from the dev's own buy
+ * 
+ * The dev's `amount_in` is clamped to `[min_buy_sol, max_buy_sol]`, so an
+ * absurd dev buy can't drain the wallet and a dust one still buys something.
+ * 
+ * @param dev_amount_in - SOL in of the dev's buy (`BuyAmount::sol_in`), in lamports
+ * @returns (u64, bool) - Buy amount in lamports, and whether it was clamped
+ */
+pub fn copy_buy_amount(dev_amount_in: u64) -> (u64, bool) {
+    let min = (CONFIG.trade.min_buy_sol * 10_f64.powf(9.0)) as u64;
+    let max = (CONFIG.trade.max_buy_sol * 10_f64.powf(9.0)) as u64;
+    
+    let amount = dev_amount_in.clamp(min, max.max(min));
+    (amount, amount != dev_amount_in)
+}
+
+/**
+ * Parses an active window such as `"13:30-20:00"` (UTC)
+ * 
+ * @param window - `HH:MM-HH:MM` range, start inclusive, end exclusive
+ * @returns Result<(u32, u32), String> - (start, end) as minutes since midnight UTC or error
+ */
+pub fn parse_active_window(window: &str) -> Result<(u32, u32), String> {
+    let parse_time = |time: &str| -> Option<u32> {
+        let (hours, minutes) = time.trim().split_once(':')?;
+        let hours: u32 = hours.parse().ok()?;
+        let minutes: u32 = minutes.parse().ok()?;
+        (hours < 24 && minutes < 60).then_some(hours * 60 + minutes)
+    };
+
+    let (start, end) = window
+        .split_once('-')
+        .ok_or_else(|| format!("active window {} is not HH:MM-HH:MM", window))?;
+    match (parse_time(start), parse_time(end)) {
+        (Some(start), Some(end)) if start != end => Ok((start, end)),
+        (Some(_), Some(_)) => Err(format!("active window {} is empty", window)),
+        _ => Err(format!("active window {} is not HH:MM-HH:MM (UTC)", window)),
+    }
+}
+
+/**
+ * Returns whether `minute_of_day` falls in any of `windows`
+ * 
+ * A window whose end is before its start crosses midnight, e.g.
+ * `22:00-02:00`. No windows means always active.
+ * 
+ * @param windows - (start, end) minutes since midnight UTC
+ * @param minute_of_day - Minutes since midnight UTC to check
+ * @returns bool - True if trading is allowed at that minute
+ */
+pub fn in_active_window(windows: &[(u32, u32)], minute_of_day: u32) -> bool {
+    windows.is_empty()
+        || windows.iter().any(|&(start, end)| {
+            if start < end {
+                (start..end).contains(&minute_of_day)
+            } else {
+                minute_of_day >= start || minute_of_day < end
+            }
+        })
+}
+
+/// Returns whether buys are submitted for launches on `launchpad`
+pub fn program_enabled(launchpad: Launchpad) -> bool {
+    ENABLED_PROGRAMS.contains(&launchpad)
+}
+
+/// Returns whether the current UTC time is inside `[trade].active_windows`
+pub fn active_window_open() -> bool {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    in_active_window(&ACTIVE_WINDOWS, ((secs % 86_400) / 60) as u32)
+}
+
 /**
  * Validates and loads slippage configuration
  * 
@@ -132,11 +290,11 @@ fn load_slippage() -> f64 {
     
     // Validate slippage (minimum 0.1%, maximum 100%)
     if slippage_percent < 0.1 {
-        eprintln!("⚠️ Slippage too low: {}% (minimum 0.1%)", slippage_percent);
+        println!("⚠️ Slippage too low: {}% (minimum 0.1%)", slippage_percent);
     }
     
     if slippage_percent > 100.0 {
-        eprintln!("⚠️ Slippage too high: {}% (maximum 100%)", slippage_percent);
+        println!("⚠️ Slippage too high: {}% (maximum 100%)", slippage_percent);
     }
     
     // Convert percentage to decimal
@@ -147,6 +305,62 @@ fn load_slippage() -> f64 {
     slippage_decimal
 }
 
+/**
+ * Converts a decimal slippage (0.01 = 1%) into basis points
+ * 
+ * @param slippage_decimal - Slippage as a fraction
+ * @returns u64 - Slippage in basis points, rounded to the nearest bps
+ */
+pub fn slippage_to_bps(slippage_decimal: f64) -> u64 {
+    (slippage_decimal * 10_000.0).round().max(0.0) as u64
+}
+
+/**
+ * Builds Moonshot buy parameters from the global slippage setting
+ * 
+ * Buys spend an exact amount of SOL, so `fixed_side` is exact-in. The
+ * expected `token_amount` comes from a curve quote and is left at 0 for
+ * the caller to fill in. The amount is only meaningful for SOL
+ * collateral, so launches in any other currency, or one missing from
+ * `[filter].moonshot_allowed_collateral`, are refused here rather than
+ * turned into a transaction that wraps SOL for an SPL-collateral curve.
+ * 
+ * @param mint_info - Moonshot launch being bought
+ * @param collateral_lamports - SOL to spend, in lamports
+ * @returns Result<MoonBuyParam, FilterRejectReason> - Buy parameters, or why the collateral can't be bought
+ */
+pub fn moon_buy_param_from_config(
+    mint_info: &MoonshotMintInfo,
+    collateral_lamports: u64,
+) -> Result<MoonBuyParam, FilterRejectReason> {
+    let currency = mint_info.collateral_currency;
+    if !MOONSHOT_ALLOWED_COLLATERAL.contains(&currency) || currency != MOONSHOT_COLLATERAL_SOL {
+        return Err(FilterRejectReason::CollateralNotAllowed {
+            currency: mint_info.collateral_name(),
+        });
+    }
+
+    Ok(MoonBuyParam {
+        token_amount: 0,
+        collateral_amount: collateral_lamports,
+        fixed_side: MOON_FIXED_SIDE_EXACT_IN,
+        slippage_bps: slippage_to_bps(*SLIPPAGE),
+    })
+}
+
+/**
+ * Parses a `[filter].moonshot_allowed_collateral` entry
+ * 
+ * @param name - `sol`, or a raw Moonshot currency id
+ * @returns Option<u8> - Currency id, None if unrecognized
+ */
+pub fn parse_moonshot_collateral(name: &str) -> Option<u8> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "sol" => Some(MOONSHOT_COLLATERAL_SOL),
+        other => other.parse().ok(),
+    }
+}
+
 /**
  * Calculates total transaction cost including fees
  * 
@@ -154,29 +368,87 @@ fn load_slippage() -> f64 {
  * the buy amount, priority fees, and third-party fees.
  * 
  * @param base_amount - Base transaction amount in lamports
+ * @param compute_unit_limit - Compute unit limit the transaction requests
  * @returns u64 - Total cost in lamports
  */
-pub fn calculate_total_cost(base_amount: u64) -> u64 {
-    let (cu, priority_fee_micro_lamport, third_party_fee) = *PRIORITY_FEE;
+pub fn calculate_total_cost(base_amount: u64, compute_unit_limit: u64) -> u64 {
+    let (_, _, third_party_fee) = *PRIORITY_FEE;
+    let priority_fee_micro_lamport = current_priority_fee();
     
     // Calculate priority fee cost
-    let priority_fee_cost = cu * priority_fee_micro_lamport;
+    let priority_fee_cost = priority_fee_lamports(compute_unit_limit, priority_fee_micro_lamport);
     
     // Calculate third party fee cost
-    let third_party_fee_cost = (base_amount as f64 * third_party_fee * 10_f64.powf(9.0)) as u64;
+    let third_party_fee_cost = third_party_fee_lamports(base_amount, third_party_fee);
+    
+    // Calculate Jito tip cost (only paid when submitting through Jito)
+    let jito_tip_cost = if CONFIRM_SERVICE.as_str() == "JITO" { *JITO_TIP_LAMPORTS } else { 0 };
+    
+    // Wrapped on top of the buy; returned by the wSOL close, but it must be on hand first
+    let wrap_buffer = wrap_buffer_lamports();
     
     // Total cost
-    let total_cost = base_amount + priority_fee_cost + third_party_fee_cost;
+    let total_cost = base_amount + priority_fee_cost + third_party_fee_cost + jito_tip_cost + wrap_buffer;
     
     println!("💰 Transaction cost breakdown:");
     println!("   Base amount: {} lamports", base_amount);
     println!("   Priority fee: {} lamports", priority_fee_cost);
     println!("   Third party fee: {} lamports", third_party_fee_cost);
+    println!("   Jito tip: {} lamports", jito_tip_cost);
+    println!("   Wrap buffer: {} lamports", wrap_buffer);
     println!("   Total cost: {} lamports", total_cost);
     
     total_cost
 }
 
+/// Priority fee for `cu` compute units at a price in micro-lamports per CU, rounded up
+pub fn priority_fee_lamports(cu: u64, micro_lamports_per_cu: u64) -> u64 {
+    (cu * micro_lamports_per_cu).div_ceil(1_000_000)
+}
+
+/// Third party fee on a buy; the rate is a fraction of the lamport amount (0.01 = 1%)
+pub fn third_party_fee_lamports(base_amount: u64, fee_rate: f64) -> u64 {
+    (base_amount as f64 * fee_rate) as u64
+}
+
+/// Lamports wrapped on top of each buy; only with the wSOL close, which is what returns them
+pub fn wrap_buffer_lamports() -> u64 {
+    if CONFIG.trade.close_wsol_after_buy {
+        CONFIG.trade.wrap_buffer_lamports
+    } else {
+        0
+    }
+}
+
+/**
+ * Checks the fees of a transaction against the configured ceiling
+ * 
+ * Fees are everything `calculate_total_cost` adds on top of the base amount,
+ * except the wrap buffer, which is unwrapped again rather than spent.
+ * 
+ * @param base_amount - Base transaction amount in lamports
+ * @param compute_unit_limit - Compute unit limit the built transaction requests
+ * @returns Result<u64, String> - Total fees in lamports, or why they're over the cap
+ */
+pub fn check_fee_cap(base_amount: u64, compute_unit_limit: u64) -> Result<u64, String> {
+    let fees = calculate_total_cost(base_amount, compute_unit_limit) - base_amount - wrap_buffer_lamports();
+    enforce_fee_cap(fees, CONFIG.trade.max_total_fee_sol)
+}
+
+/// Passes `fees` through, or reports that they're over a `max_total_fee_sol` cap
+fn enforce_fee_cap(fees: u64, max_total_fee_sol: f64) -> Result<u64, String> {
+    let max_fee_lamports = (max_total_fee_sol * 10_f64.powf(9.0)) as u64;
+    
+    if fees > max_fee_lamports {
+        return Err(format!(
+            "transaction fees {} lamports exceed max_total_fee_sol cap of {} lamports",
+            fees, max_fee_lamports
+        ));
+    }
+    
+    Ok(fees)
+}
+
 /**
  * Validates wallet balance for transaction
  * 
@@ -199,7 +471,7 @@ pub fn validate_wallet_balance(required_amount: u64, wallet_balance: u64) -> boo
     let total_required = required_amount + safety_margin;
     
     if wallet_balance < total_required {
-        eprintln!("⚠️ Wallet balance close to required amount: {} lamports (recommended: {} lamports)", 
+        println!("⚠️ Wallet balance close to required amount: {} lamports (recommended: {} lamports)", 
                  wallet_balance, total_required);
     }
     
@@ -222,6 +494,94 @@ pub static CONFIRM_SERVICE: Lazy<String> = Lazy::new(|| {
     load_confirmation_service()
 });
 
+/**
+ * Jito tip amount in lamports
+ * 
+ * This is lazily initialized to ensure configuration is loaded
+ * before attempting to validate the tip amount.
+ */
+pub static JITO_TIP_LAMPORTS: Lazy<u64> = Lazy::new(|| {
+    let tip_sol = CONFIG.services.jito_tip_sol;
+    let tip_lamports = (tip_sol * 10_f64.powf(9.0)) as u64;
+    println!("✅ Jito tip configured: {} SOL ({} lamports)", tip_sol, tip_lamports);
+    tip_lamports
+});
+
+/**
+ * Quote mints launches may be paired with
+ * 
+ * Invalid entries are rejected by `validate_filter_configuration`, so
+ * they are simply dropped here.
+ */
+pub static ALLOWED_QUOTE_MINTS: Lazy<HashSet<Pubkey>> = Lazy::new(|| {
+    CONFIG
+        .filter
+        .allowed_quote_mints
+        .iter()
+        .filter_map(|mint| Pubkey::from_str(mint).ok())
+        .collect()
+});
+
+/**
+ * Parsed `[trade].active_windows`
+ * 
+ * Invalid entries are rejected by `validate_trading_configuration`, so
+ * they are simply dropped here.
+ */
+pub static ACTIVE_WINDOWS: Lazy<Vec<(u32, u32)>> = Lazy::new(|| {
+    CONFIG
+        .trade
+        .active_windows
+        .iter()
+        .filter_map(|window| parse_active_window(window).ok())
+        .collect()
+});
+
+/**
+ * Launchpads buys are submitted for
+ * 
+ * Invalid entries are rejected by `validate_trading_configuration`, so
+ * they are simply dropped here.
+ */
+pub static ENABLED_PROGRAMS: Lazy<HashSet<Launchpad>> = Lazy::new(|| {
+    CONFIG
+        .trade
+        .enabled_programs
+        .iter()
+        .filter_map(|name| Launchpad::from_name(name))
+        .collect()
+});
+
+/**
+ * Moonshot collateral currencies launches may use
+ * 
+ * Invalid entries are rejected by `validate_filter_configuration`, so
+ * they are simply dropped here.
+ */
+pub static MOONSHOT_ALLOWED_COLLATERAL: Lazy<HashSet<u8>> = Lazy::new(|| {
+    CONFIG
+        .filter
+        .moonshot_allowed_collateral
+        .iter()
+        .filter_map(|name| parse_moonshot_collateral(name))
+        .collect()
+});
+
+/**
+ * Token programs a launch's base mint may belong to
+ * 
+ * Invalid entries are rejected by `validate_filter_configuration`, so
+ * they are simply dropped here.
+ */
+pub static ALLOWED_TOKEN_PROGRAMS: Lazy<HashSet<Pubkey>> = Lazy::new(|| {
+    CONFIG
+        .filter
+        .allowed_token_programs
+        .iter()
+        .filter_map(|program| Pubkey::from_str(program).ok())
+        .collect()
+});
+
 /**
  * Priority fee configuration parameters
  * 
@@ -244,6 +604,20 @@ pub static BUY_SOL_AMOUNT: Lazy<u64> = Lazy::new(|| {
     load_buy_amount()
 });
 
+/**
+ * Per-launchpad buy amounts in lamports
+ * 
+ * Each falls back to the global buy amount when no override is configured.
+ */
+pub static BONK_BUY_SOL_AMOUNT: Lazy<u64> =
+    Lazy::new(|| load_launchpad_buy_amount("Bonk", CONFIG.trade.bonk_buy_sol_amount));
+
+pub static PUMP_BUY_SOL_AMOUNT: Lazy<u64> =
+    Lazy::new(|| load_launchpad_buy_amount("Pump", CONFIG.trade.pump_buy_sol_amount));
+
+pub static MOONSHOT_BUY_SOL_AMOUNT: Lazy<u64> =
+    Lazy::new(|| load_launchpad_buy_amount("Moonshot", CONFIG.trade.moonshot_buy_sol_amount));
+
 /**
  * Slippage as decimal value
  * 
@@ -255,46 +629,422 @@ pub static SLIPPAGE: Lazy<f64> = Lazy::new(|| {
     load_slippage()
 });
 
+/**
+ * Shadow buy amount in lamports, 0 when `[trade.shadow]` is disabled
+ */
+pub static SHADOW_BUY_AMOUNT: Lazy<u64> = Lazy::new(|| {
+    shadow_config()
+        .map(|shadow| sol_buy_amount_to_lamports("Shadow buy amount", shadow.buy_sol_amount))
+        .unwrap_or(0)
+});
+
 /**
  * Validates all trading configuration on startup
  * 
  * This function should be called during application initialization
  * to ensure all trading parameters are valid and within safe bounds.
  * 
- * @returns Result<(), String> - Success or error message
+ * @returns Result<(), Vec<String>> - Success or every problem found
  */
-pub fn validate_trading_configuration() -> Result<(), String> {
+pub fn validate_trading_configuration() -> Result<(), Vec<String>> {
     println!("🔍 Validating trading configuration...");
+    let mut errors = Vec::new();
     
     // Validate buy amount
     let buy_amount = CONFIG.trade.buy_sol_amount;
     if buy_amount <= 0.0 {
-        return Err("Buy amount must be greater than 0".to_string());
+        errors.push("Buy amount must be greater than 0".to_string());
+    }
+    
+    // Validate percent buy sizing
+    match CONFIG.trade.buy_mode.as_str() {
+        "fixed" => {}
+        "percent" => {
+            if CONFIG.trade.buy_percent <= 0.0 || CONFIG.trade.buy_percent > 100.0 {
+                errors.push("buy_percent must be greater than 0 and at most 100 in percent mode".to_string());
+            }
+            if CONFIG.trade.max_buy_sol < MIN_BUY_SOL_AMOUNT {
+                errors.push(format!("max_buy_sol must be at least {} SOL", MIN_BUY_SOL_AMOUNT));
+            }
+        }
+        other => errors.push(format!("Invalid buy_mode: {} (expected fixed or percent)", other)),
+    }
+    
+    // Validate copy-trade bounds
+    if CONFIG.trade.copy_dev_buy {
+        if CONFIG.trade.buy_mode == "percent" {
+            errors.push("copy_dev_buy can't be combined with buy_mode = \"percent\"".to_string());
+        }
+        if CONFIG.trade.min_buy_sol < MIN_BUY_SOL_AMOUNT {
+            errors.push(format!("min_buy_sol must be at least {} SOL", MIN_BUY_SOL_AMOUNT));
+        }
+        if CONFIG.trade.max_buy_sol < CONFIG.trade.min_buy_sol {
+            errors.push("max_buy_sol must be at least min_buy_sol".to_string());
+        }
+    }
+    
+    // Validate per-launchpad buy amount overrides
+    let overrides = [
+        ("bonk_buy_sol_amount", CONFIG.trade.bonk_buy_sol_amount),
+        ("pump_buy_sol_amount", CONFIG.trade.pump_buy_sol_amount),
+        ("moonshot_buy_sol_amount", CONFIG.trade.moonshot_buy_sol_amount),
+    ];
+    for (name, amount) in overrides {
+        if let Some(amount) = amount {
+            if !(MIN_BUY_SOL_AMOUNT..=MAX_BUY_SOL_AMOUNT).contains(&amount) {
+                errors.push(format!("{} must be between 0.0001 and 10 SOL", name));
+            }
+        }
+    }
+    
+    // Validate enabled launchpads
+    for name in &CONFIG.trade.enabled_programs {
+        if Launchpad::from_name(name).is_none() {
+            errors.push(format!(
+                "Invalid enabled_programs entry: {} (expected bonk, pump or moonshot)",
+                name
+            ));
+        }
     }
     
     // Validate third party fee
     let third_party_fee = CONFIG.trade.third_party_fee;
     if third_party_fee < 0.0 {
-        return Err("Third party fee cannot be negative".to_string());
+        errors.push("Third party fee cannot be negative".to_string());
     }
     
     // Validate slippage
     let slippage = CONFIG.trade.slippage;
     if slippage <= 0.0 || slippage > 100.0 {
-        return Err("Slippage must be between 0.1 and 100.0".to_string());
+        errors.push("Slippage must be between 0.1 and 100.0".to_string());
+    }
+    
+    // Validate the shadow strategy's own sizing
+    if let Some(shadow) = shadow_config() {
+        if shadow.buy_sol_amount <= 0.0 {
+            errors.push("shadow buy_sol_amount must be greater than 0".to_string());
+        }
+        if shadow.slippage <= 0.0 || shadow.slippage > 100.0 {
+            errors.push("shadow slippage must be between 0.1 and 100.0".to_string());
+        }
+    }
+    
+    // Validate Jito tip (Jito ignores bundles tipping under 1000 lamports)
+    let jito_tip = CONFIG.services.jito_tip_sol;
+    if !(0.000001..=0.1).contains(&jito_tip) {
+        errors.push("jito_tip_sol must be between 0.000001 and 0.1 SOL".to_string());
+    }
+    
+    if CONFIG.services.submit_timeout_ms == 0 {
+        errors.push("submit_timeout_ms must be greater than 0".to_string());
+    }
+    
+    for table in &CONFIG.trade.lookup_tables {
+        if Pubkey::from_str(table).is_err() {
+            errors.push(format!("lookup_tables entry {} is not a valid pubkey", table));
+        }
+    }
+    
+    // Validate force-sell floor
+    if !(0.0..=100.0).contains(&CONFIG.trade.force_sell_min_out_pct) {
+        errors.push("force_sell_min_out_pct must be between 0 and 100".to_string());
+    }
+    
+    // Validate trade queue
+    if CONFIG.trade.trade_workers == 0 {
+        errors.push("trade_workers must be at least 1".to_string());
+    }
+    if CONFIG.trade.queue_capacity == 0 {
+        errors.push("queue_capacity must be at least 1".to_string());
+    }
+    if !["drop_newest", "drop_oldest"].contains(&CONFIG.trade.queue_full_policy.as_str()) {
+        errors.push(format!(
+            "Invalid queue_full_policy: {} (expected drop_newest or drop_oldest)",
+            CONFIG.trade.queue_full_policy
+        ));
+    }
+    
+    // Validate confirmation window
+    if CONFIG.trade.require_confirmed && CONFIG.trade.confirm_window_ms == 0 {
+        errors.push("confirm_window_ms must be greater than 0 when require_confirmed is set".to_string());
+    }
+    
+    for window in &CONFIG.trade.active_windows {
+        if let Err(e) = parse_active_window(window) {
+            errors.push(e);
+        }
+    }
+    
+    if CONFIG.trade.position_poll_interval_ms == 0 {
+        errors.push("position_poll_interval_ms must be greater than 0".to_string());
+    }
+    
+    // Validate the exit ladder: rungs in rising order, never selling more than the whole position
+    let mut previous_gain = 0.0;
+    let mut ladder_total = 0.0;
+    for (index, rung) in CONFIG.trade.sell_ladder.iter().enumerate() {
+        if !(rung.pct_of_position > 0.0 && rung.pct_of_position <= 100.0) {
+            errors.push(format!("sell_ladder rung {} pct_of_position must be between 0 and 100", index + 1));
+        }
+        if rung.at_gain <= previous_gain {
+            errors.push(format!(
+                "sell_ladder rung {} at_gain must be greater than 0 and than the rung before it",
+                index + 1
+            ));
+        }
+        previous_gain = rung.at_gain;
+        ladder_total += rung.pct_of_position;
+    }
+    if ladder_total > 100.0 + LADDER_PCT_EPSILON {
+        errors.push(format!("sell_ladder sells {}% of the position, more than 100%", ladder_total));
+    }
+    if !(0.0..100.0).contains(&CONFIG.trade.trailing_stop_pct) {
+        errors.push("trailing_stop_pct must be at least 0 and below 100".to_string());
+    }
+    
+    // Validate fee cap
+    if CONFIG.trade.max_total_fee_sol <= 0.0 {
+        errors.push("max_total_fee_sol must be greater than 0".to_string());
     }
     
     // Validate priority fee parameters
     let cu = CONFIG.priority_fee.cu;
     if cu == 0 {
-        return Err("Compute units cannot be zero".to_string());
+        errors.push("Compute units cannot be zero".to_string());
     }
     
     let priority_fee = CONFIG.priority_fee.priority_fee_micro_lamport;
     if priority_fee == 0 {
-        return Err("Priority fee cannot be zero".to_string());
+        errors.push("Priority fee cannot be zero".to_string());
+    }
+    
+    if CONFIG.priority_fee.dynamic {
+        if CONFIG.priority_fee.dynamic_percentile > 100 {
+            errors.push("dynamic_percentile must be between 0 and 100".to_string());
+        }
+        if CONFIG.priority_fee.max_micro_lamport == 0 {
+            errors.push("max_micro_lamport must be greater than 0".to_string());
+        }
+        if CONFIG.priority_fee.dynamic_refresh_ms == 0 {
+            errors.push("dynamic_refresh_ms must be greater than 0".to_string());
+        }
+    }
+    
+    if CONFIG.priority_fee.auto_cu && !(0.0..=100.0).contains(&CONFIG.priority_fee.auto_cu_margin_pct) {
+        errors.push("auto_cu_margin_pct must be between 0 and 100".to_string());
+    }
+    
+    if !errors.is_empty() {
+        return Err(errors);
     }
     
     println!("✅ Trading configuration validation passed");
     Ok(())
 }
+
+/**
+ * Validates filter configuration on startup
+ * 
+ * Catches filters that are enabled but can never match, and nonsensical
+ * limits, before the stream goes live.
+ * 
+ * @returns Result<(), Vec<String>> - Success or every problem found
+ */
+pub fn validate_filter_configuration() -> Result<(), Vec<String>> {
+    println!("🔍 Validating filter configuration...");
+    let mut errors = Vec::new();
+    let filter = &CONFIG.filter;
+    
+    if !["all", "any"].contains(&filter.mode.as_str()) {
+        errors.push(format!("Invalid filter mode: {} (expected all or any)", filter.mode));
+    }
+    
+    if filter.dev_buy_min < 0.0 {
+        errors.push("dev_buy_min cannot be negative".to_string());
+    }
+    
+    if let Some(max) = filter.dev_buy_max {
+        if max < filter.dev_buy_min {
+            errors.push(format!("dev_buy_max ({}) is below dev_buy_min ({})", max, filter.dev_buy_min));
+        }
+    }
+    
+    if filter.min_quote_liquidity_sol < 0.0 {
+        errors.push("min_quote_liquidity_sol cannot be negative".to_string());
+    }
+    
+    if filter.max_share_fee_rate.is_some_and(|rate| rate > BONK_FEE_RATE_DENOMINATOR) {
+        errors.push(format!("max_share_fee_rate cannot exceed {} (100%)", BONK_FEE_RATE_DENOMINATOR));
+    }
+    
+    if filter.x_check && filter.x_filter_list.is_empty() && filter.x_required_fields.is_empty() {
+        errors.push("x_check is enabled but x_filter_list and x_required_fields are both empty".to_string());
+    }
+    
+    if filter.x_filter_list.iter().any(|entry| entry.trim().is_empty()) {
+        errors.push("x_filter_list contains an empty entry, which matches everything".to_string());
+    }
+    
+    if filter.metadata_host_allowlist.iter().any(|host| host.trim().is_empty() || host.contains('/')) {
+        errors.push("metadata_host_allowlist entries must be bare host names".to_string());
+    }
+    
+    if !["reject", "allow", "retry_once_after_ms"].contains(&filter.empty_uri_policy.as_str()) {
+        errors.push(format!(
+            "Invalid empty_uri_policy: {} (expected reject, allow or retry_once_after_ms)",
+            filter.empty_uri_policy
+        ));
+    }
+    
+    for name in &filter.moonshot_allowed_collateral {
+        match parse_moonshot_collateral(name) {
+            Some(MOONSHOT_COLLATERAL_SOL) => {}
+            Some(_) => {
+                println!("⚠️ moonshot_allowed_collateral entry {} is not SOL; such launches are skipped by the buy path", name);
+            }
+            None => errors.push(format!(
+                "Invalid moonshot_allowed_collateral entry: {} (expected sol or a currency id)",
+                name
+            )),
+        }
+    }
+    
+    if filter.token_name_check && filter.token_name_filter_list.is_empty() {
+        errors.push("token_name_check is enabled but token_name_filter_list is empty".to_string());
+    }
+    
+    if filter.allowed_quote_mints.is_empty() {
+        errors.push("allowed_quote_mints is empty, so every launch would be skipped".to_string());
+    }
+    
+    for mint in &filter.allowed_quote_mints {
+        match Pubkey::from_str(mint) {
+            Ok(pubkey) if pubkey != spl_token::native_mint::ID => {
+                println!("⚠️ allowed_quote_mints entry {} is not wSOL; such launches are skipped by the buy path", mint);
+            }
+            Ok(_) => {}
+            Err(e) => errors.push(format!("allowed_quote_mints entry {} is not a valid pubkey: {}", mint, e)),
+        }
+    }
+    
+    if filter.allowed_token_programs.is_empty() {
+        errors.push("allowed_token_programs is empty, so every launch would be skipped".to_string());
+    }
+    
+    for program in &filter.allowed_token_programs {
+        match Pubkey::from_str(program) {
+            Ok(pubkey) if pubkey != spl_token::ID && pubkey != spl_token_2022::ID => {
+                println!("⚠️ allowed_token_programs entry {} is neither SPL Token nor Token-2022", program);
+            }
+            Ok(_) => {}
+            Err(e) => errors.push(format!("allowed_token_programs entry {} is not a valid pubkey: {}", program, e)),
+        }
+    }
+    
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    
+    println!("✅ Filter configuration validation passed");
+    Ok(())
+}
+
+/**
+ * Validates the control endpoint configuration on startup
+ * 
+ * The endpoint can sell everything, so it won't start without a shared
+ * secret, even on localhost: any web page can POST to a loopback port.
+ * 
+ * @returns Result<(), Vec<String>> - Success or every problem found
+ */
+pub fn validate_control_configuration() -> Result<(), Vec<String>> {
+    let control = &CONFIG.control;
+    if !control.enabled {
+        return Ok(());
+    }
+    
+    let mut errors = Vec::new();
+    if control.secret.is_empty() {
+        errors.push("control endpoint is enabled, so a control secret is required".to_string());
+    }
+    
+    if let Err(e) = control.bind.parse::<SocketAddr>() {
+        errors.push(format!("control bind {} is not a valid socket address: {}", control.bind, e));
+    }
+    
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/**
+ * Runs every configuration validator and aggregates the results
+ * 
+ * @returns Result<(), Vec<String>> - Success or every problem found
+ */
+pub fn validate_all_configuration() -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+    
+    for result in [
+        crate::validate_configuration(),
+        validate_trading_configuration(),
+        validate_filter_configuration(),
+        validate_control_configuration(),
+    ] {
+        if let Err(mut found) = result {
+            errors.append(&mut found);
+        }
+    }
+    
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_percent_of_one_sol_is_a_hundredth_of_a_sol() {
+        assert_eq!(third_party_fee_lamports(1_000_000_000, 0.01), 10_000_000);
+        assert_eq!(third_party_fee_lamports(1_000_000_000, 0.0), 0);
+    }
+
+    #[test]
+    fn priority_fee_converts_micro_lamports_and_rounds_up() {
+        // 200k CU at 5000 micro-lamports/CU is 1_000_000_000 micro-lamports = 1000 lamports
+        assert_eq!(priority_fee_lamports(200_000, 5_000), 1_000);
+        assert_eq!(priority_fee_lamports(200_000, 1), 1);
+        assert_eq!(priority_fee_lamports(200_000, 0), 0);
+    }
+
+    #[test]
+    fn fees_over_the_cap_abort_the_buy() {
+        // 1% of a 1 SOL buy plus 1000 lamports of priority fee, against a 0.01 SOL cap
+        let fees = third_party_fee_lamports(1_000_000_000, 0.01) + priority_fee_lamports(200_000, 5_000);
+        assert!(enforce_fee_cap(fees, 0.01).is_err());
+        assert_eq!(enforce_fee_cap(10_000_000, 0.01), Ok(10_000_000));
+        assert_eq!(enforce_fee_cap(fees, 0.02), Ok(fees));
+    }
+
+    #[test]
+    fn slippage_converts_to_basis_points() {
+        assert_eq!(slippage_to_bps(0.01), 100);
+        assert_eq!(slippage_to_bps(0.0), 0);
+    }
+
+    #[test]
+    fn slippage_bps_round_to_nearest() {
+        // 0.29 * 10_000 is 2899.999... in f64; truncating would lose a bps
+        assert_eq!(slippage_to_bps(0.29), 2_900);
+        assert_eq!(slippage_to_bps(0.00126), 13);
+        assert_eq!(slippage_to_bps(0.00124), 12);
+    }
+
+    #[test]
+    fn full_slippage_is_ten_thousand_bps() {
+        assert_eq!(slippage_to_bps(1.0), 10_000);
+    }
+
+    #[test]
+    fn negative_slippage_clamps_to_zero_bps() {
+        assert_eq!(slippage_to_bps(-0.05), 0);
+    }
+}
@@ -1,3 +1,18 @@
 pub const PUMPF_INITIAL_VIRTUAL_SOL_RESERVES: u64 = 30_000_000_000;
 pub const PUMPF_INITIAL_VIRTUAL_TOKEN_RESERVES: u64 = 73_000_000_000_000;
-pub const PUMPF_TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
\ No newline at end of file
+pub const PUMPF_TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
+
+/// Approximate Solana slot duration
+pub const MS_PER_SLOT: u64 = 400;
+
+/// Moonshot `fixed_side` for trades with an exact input amount (SOL in)
+pub const MOON_FIXED_SIDE_EXACT_IN: u8 = 0;
+
+/// Raydium Launchpad pool state PDA seed: [BONK_POOL_SEED, base_mint, quote_mint]
+pub const BONK_POOL_SEED: &[u8] = b"pool";
+
+/// Raydium Launchpad vault PDA seed: [BONK_POOL_VAULT_SEED, pool_state, mint]
+pub const BONK_POOL_VAULT_SEED: &[u8] = b"pool_vault";
+
+/// Raydium Launchpad fee rates are parts per million (10_000 = 1%)
+pub const BONK_FEE_RATE_DENOMINATOR: u64 = 1_000_000;
@@ -1,6 +1,14 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_sdk::pubkey::Pubkey;
 
+use crate::{MOONSHOT_PROGRAM_ID, PUMP_FUN_PROGRAM_ID, RAYDIUM_LAUNCHPAD_PROGRAM_ID};
+
+/// SOL a launchpad buy puts in, whatever the launchpad calls it
+pub trait BuyAmount {
+    /// Lamports spent (or at most spent) by the buy
+    fn sol_in(&self) -> u64;
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct PumpfunBuy {
     pub global: Pubkey,
@@ -23,6 +31,12 @@ pub struct PumpfunBuyParam {
     pub max_sol_cost: u64,
 }
 
+impl BuyAmount for PumpfunBuyParam {
+    fn sol_in(&self) -> u64 {
+        self.max_sol_cost
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PumpfunMintInfo {
     pub name: String,
@@ -50,13 +64,19 @@ pub struct BonkBuy {
     pub program: Pubkey,             // #15
 }
 
-#[derive(Debug, BorshDeserialize, Clone, Copy)]
+#[derive(Debug, BorshDeserialize, BorshSerialize, Clone, Copy)]
 pub struct BonkBuyParam {
     pub amount_in: u64,
     pub minimum_amount_out: u64,
     pub share_fee_rate: u64,
 }
 
+impl BuyAmount for BonkBuyParam {
+    fn sol_in(&self) -> u64 {
+        self.amount_in
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BonkMintParams {
     pub decimals: u8,
@@ -109,6 +129,22 @@ pub struct BonkfunMIntInfo {
     pub vesting_param: BonkVestingParams,
 }
 
+/// `migrate_type` value for a pool graduating to the Raydium AMM (v4)
+pub const MIGRATE_TYPE_AMM: u8 = 0;
+/// `migrate_type` value for a pool graduating to Raydium CPSwap
+pub const MIGRATE_TYPE_CPSWAP: u8 = 1;
+
+/// Moonshot `collateral_currency` of a SOL-denominated launch (`Currency::Sol`)
+pub const MOONSHOT_COLLATERAL_SOL: u8 = 0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BonkMigration {
+    pub payer: Pubkey,      // #1
+    pub base_mint: Pubkey,  // #2
+    pub quote_mint: Pubkey, // #3
+    pub migrate_type: u8,
+}
+
 #[derive(Debug, Clone)]
 pub struct MoonBuy {
     pub sender: Pubkey,                   // #1 - Sender
@@ -132,6 +168,12 @@ pub struct MoonBuyParam {
     pub slippage_bps: u64,
 }
 
+impl BuyAmount for MoonBuyParam {
+    fn sol_in(&self) -> u64 {
+        self.collateral_amount
+    }
+}
+
 #[derive(Debug, BorshDeserialize, Clone)]
 pub struct MoonBuyParamWrapper {
     pub data: MoonBuyParam,
@@ -143,8 +185,94 @@ pub struct MoonshotMintInfo {
     pub symbol: String,
     pub uri: String,
     pub decimals: u8,
-    pub collateral_currency: u8, // 0 = USDC, 1 = USDT, etc.
+    pub collateral_currency: u8, // 0 = SOL; anything else is an SPL collateral
     pub amount: u64,             // Total token supply
     pub curve_type: u8,          // 0=Constant, 1=Fixed, 2=Linear
     pub migration_target: u8,    // 0=None, 1=AMM, 2=CPSWAP
 }
+
+impl MoonshotMintInfo {
+    /// Collateral as named in `[filter].moonshot_allowed_collateral`: `sol`, or the raw currency id
+    pub fn collateral_name(&self) -> String {
+        match self.collateral_currency {
+            MOONSHOT_COLLATERAL_SOL => "sol".to_string(),
+            other => other.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Launchpad {
+    Bonk,
+    Pump,
+    Moonshot,
+}
+
+impl Launchpad {
+    /// Launchpad owning `program_id`, if it's one of ours
+    pub fn for_program(program_id: &Pubkey) -> Option<Launchpad> {
+        match *program_id {
+            RAYDIUM_LAUNCHPAD_PROGRAM_ID => Some(Launchpad::Bonk),
+            PUMP_FUN_PROGRAM_ID => Some(Launchpad::Pump),
+            MOONSHOT_PROGRAM_ID => Some(Launchpad::Moonshot),
+            _ => None,
+        }
+    }
+
+    /// Launchpad named `name` in config (`bonk`, `pump`, `moonshot`)
+    pub fn from_name(name: &str) -> Option<Launchpad> {
+        match name.to_ascii_lowercase().as_str() {
+            "bonk" => Some(Launchpad::Bonk),
+            "pump" => Some(Launchpad::Pump),
+            "moonshot" => Some(Launchpad::Moonshot),
+            _ => None,
+        }
+    }
+
+    /// Lowercase name used in config and metric labels
+    pub fn name(&self) -> &'static str {
+        match self {
+            Launchpad::Bonk => "bonk",
+            Launchpad::Pump => "pump",
+            Launchpad::Moonshot => "moonshot",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum LaunchEvent {
+    Bonk {
+        mint_info: BonkfunMIntInfo,
+        buy: BonkBuy,
+        buy_param: BonkBuyParam,
+        tx_id: String,
+    },
+    /// A launchpad pool graduated to an AMM; launchpad buys and sells no longer work for it
+    Migration {
+        mint: Pubkey,
+        quote_mint: Pubkey,
+        migrate_type: u8,
+        tx_id: String,
+    },
+}
+
+impl LaunchEvent {
+    pub fn launchpad(&self) -> Launchpad {
+        match self {
+            LaunchEvent::Bonk { .. } | LaunchEvent::Migration { .. } => Launchpad::Bonk,
+        }
+    }
+
+    pub fn tx_id(&self) -> &str {
+        match self {
+            LaunchEvent::Bonk { tx_id, .. } | LaunchEvent::Migration { tx_id, .. } => tx_id,
+        }
+    }
+
+    pub fn mint(&self) -> Pubkey {
+        match self {
+            LaunchEvent::Bonk { buy, .. } => buy.base_token_mint,
+            LaunchEvent::Migration { mint, .. } => *mint,
+        }
+    }
+}
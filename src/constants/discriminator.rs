@@ -1,8 +1,19 @@
+//! Anchor instruction discriminators: the first 8 bytes of
+//! `sha256("global:<method_name>")`. They depend only on the method name,
+//! never on the program, so two programs with a method of the same name
+//! share bytes: Pump.fun's and Moonshot's `buy` are both
+//! `PUMP_BUY_DISC == MOON_BUY_DISC`. A discriminator match therefore means
+//! nothing until the instruction's program id has been checked.
+
 pub const PUMP_CREATE_DISC: [u8; 8] = [24, 30, 200, 40, 5, 28, 7, 119];
 pub const PUMP_BUY_DISC: [u8; 8] = [102, 6, 61, 18, 1, 218, 235, 234];
 
 pub const BONK_INIT_DISC: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
 pub const BONK_BUY_IN_DISC: [u8; 8] = [250, 234, 13, 123, 213, 156, 19, 236];
+pub const BONK_SELL_IN_DISC: [u8; 8] = [149, 39, 222, 155, 211, 124, 152, 26];
+pub const BONK_MIGRATE_AMM_DISC: [u8; 8] = [207, 82, 192, 145, 254, 207, 145, 223];
+pub const BONK_MIGRATE_CPSWAP_DISC: [u8; 8] = [136, 92, 200, 103, 28, 218, 144, 140];
 
 pub const MOON_MINT_DISC: [u8; 8] = [3, 44, 164, 184, 123, 13, 245, 179];
+/// Same bytes as `PUMP_BUY_DISC` (both are Anchor's `buy`); tell them apart by program id
 pub const MOON_BUY_DISC: [u8; 8] = [102, 6, 61, 18, 1, 218, 235, 234];
@@ -0,0 +1,182 @@
+/**
+ * ⚠️ Error Types - Bonk.fun Trading Sniper Bot
+ * 
+ * Typed errors so callers can tell a rejected launch from a parse failure
+ * or a relayer outage, and so retry logic can separate transient failures
+ * from permanent ones.
+ * 
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use solana_client::client_error::ClientError;
+use solana_sdk::{program_error::ProgramError, signature::Signature, transaction::TransactionError};
+use std::fmt;
+
+use crate::{BundleError, FilterRejectReason, SubmitError, is_transient_client_error};
+
+/// Errors raised while decoding instruction data or account layouts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The data ended before a field could be read
+    UnexpectedEof { needed: usize, available: usize },
+    /// A string field wasn't valid UTF-8
+    InvalidUtf8,
+    /// A string length prefix exceeded the remaining data or the sane maximum
+    StringTooLong { len: usize, max: usize },
+    /// The curve type byte isn't one we know how to decode
+    UnknownCurveType(u8),
+    /// An instruction referenced fewer accounts than its layout requires
+    TooFewAccounts { expected: usize, actual: usize },
+    /// An account index pointed past the end of the account key list
+    AccountIndexOutOfBounds { index: usize, len: usize },
+    /// Borsh decoding of a parameter struct failed
+    Borsh(String),
+    /// A read failed in a named field starting at `offset` in the instruction data
+    InField { offset: usize, field: &'static str, source: Box<ParseError> },
+}
+
+impl ParseError {
+    /// Tags an error with the field being read and the offset it started at
+    pub fn in_field(offset: usize, field: &'static str, source: ParseError) -> Self {
+        ParseError::InField { offset, field, source: Box::new(source) }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedEof { needed, available } => {
+                write!(f, "unexpected end of data: needed {} bytes, {} available", needed, available)
+            }
+            ParseError::InvalidUtf8 => write!(f, "string field is not valid UTF-8"),
+            ParseError::StringTooLong { len, max } => {
+                write!(f, "string length {} exceeds the limit of {} bytes", len, max)
+            }
+            ParseError::UnknownCurveType(curve_type) => write!(f, "unknown curve type {}", curve_type),
+            ParseError::TooFewAccounts { expected, actual } => {
+                write!(f, "expected {} accounts, got {}", expected, actual)
+            }
+            ParseError::AccountIndexOutOfBounds { index, len } => {
+                write!(f, "account index {} out of bounds ({} keys)", index, len)
+            }
+            ParseError::Borsh(e) => write!(f, "borsh decode failed: {}", e),
+            ParseError::InField { offset, field, source } => {
+                write!(f, "parse error at offset {} in field {}: {}", offset, field, source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::InField { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Crate-wide error for the detection and trading pipeline
+#[derive(Debug)]
+pub enum SniperError {
+    /// Configuration is missing or out of bounds for this operation
+    Config(String),
+    Parse(ParseError),
+    Rpc(ClientError),
+    /// A confirmation service (Nozomi, Zero Slot, Jito) failed
+    Relayer { service: String, message: String },
+    /// A confirmation service didn't answer within `[services].submit_timeout_ms`
+    RelayerTimeout { service: String },
+    /// The launch was rejected by a trading filter
+    Filter(FilterRejectReason),
+    /// An instruction or transaction couldn't be built
+    TransactionBuild(String),
+    /// The transaction landed but failed on-chain
+    TransactionFailed { signature: Signature, error: TransactionError },
+    /// The transaction wasn't confirmed in time
+    ConfirmTimeout { signature: Signature },
+}
+
+impl SniperError {
+    /// True if retrying the same operation may succeed
+    pub fn is_transient(&self) -> bool {
+        match self {
+            SniperError::Rpc(e) => is_transient_client_error(e),
+            SniperError::Relayer { .. } | SniperError::RelayerTimeout { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for SniperError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SniperError::Config(e) => write!(f, "config error: {}", e),
+            SniperError::Parse(e) => write!(f, "parse error: {}", e),
+            SniperError::Rpc(e) => write!(f, "rpc error: {}", e),
+            SniperError::Relayer { service, message } => write!(f, "{} error: {}", service, message),
+            SniperError::RelayerTimeout { service } => write!(f, "{} submission timed out", service),
+            SniperError::Filter(reason) => write!(f, "filtered: {}", reason),
+            SniperError::TransactionBuild(e) => write!(f, "failed to build transaction: {}", e),
+            SniperError::TransactionFailed { signature, error } => {
+                write!(f, "transaction {} failed on-chain: {}", signature, error)
+            }
+            SniperError::ConfirmTimeout { signature } => {
+                write!(f, "transaction {} not confirmed in time", signature)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SniperError {}
+
+impl From<ParseError> for SniperError {
+    fn from(e: ParseError) -> Self {
+        SniperError::Parse(e)
+    }
+}
+
+impl From<FilterRejectReason> for SniperError {
+    fn from(reason: FilterRejectReason) -> Self {
+        SniperError::Filter(reason)
+    }
+}
+
+impl From<ClientError> for SniperError {
+    fn from(e: ClientError) -> Self {
+        SniperError::Rpc(e)
+    }
+}
+
+impl From<SubmitError> for SniperError {
+    fn from(e: SubmitError) -> Self {
+        match e {
+            SubmitError::Rpc(e) => SniperError::Rpc(e),
+            SubmitError::Relayer { service, message } => SniperError::Relayer {
+                service: service.to_string(),
+                message,
+            },
+            SubmitError::RelayerTimeout { service, .. } => SniperError::RelayerTimeout {
+                service: service.to_string(),
+            },
+            SubmitError::Build(e) => e,
+        }
+    }
+}
+
+impl From<BundleError> for SniperError {
+    fn from(e: BundleError) -> Self {
+        SniperError::Relayer {
+            service: "JITO".to_string(),
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<ProgramError> for SniperError {
+    fn from(e: ProgramError) -> Self {
+        SniperError::TransactionBuild(e.to_string())
+    }
+}
@@ -1,9 +1,16 @@
 pub mod config;
 pub mod constants;
+pub mod error;
 pub mod modules;
+pub mod sniper;
 pub mod utils;
 
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
+
 pub use config::*;
 pub use constants::*;
+pub use error::*;
 pub use modules::*;
+pub use sniper::*;
 pub use utils::*;
@@ -17,178 +17,148 @@
  * @version 2.0.0
  */
 
-use bonk_sniper_rust::*;
-use std::{net::SocketAddr, sync::Arc};
-use tokio::{net::TcpListener, sync::Mutex};
-use yellowstone_grpc_proto::geyser::SubscribeRequestFilterTransactions;
+use bonkfun_trading_snipper_grpc::*;
+use clap::{Parser, ValueEnum};
+use std::path::{Path, PathBuf};
+
+/// Command line arguments
+#[derive(Debug, Parser)]
+#[command(name = "bonk-sniper", version, about = "Bonk.fun Trading Sniper Bot")]
+struct Cli {
+    /// Path to the config file
+    #[arg(long, default_value = DEFAULT_CONFIG_PATH)]
+    config: PathBuf,
+
+    /// Detect and build trades but never submit them
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Log verbosity: error, warn, info or debug
+    #[arg(long, default_value = "info")]
+    log_level: LogLevel,
+
+    /// Run mode
+    #[arg(long, value_enum, default_value_t = Mode::Snipe)]
+    mode: Mode,
+
+    /// Recorded updates to replay (one base64 SubscribeUpdate per line), required with `--mode replay`
+    #[arg(required_if_eq("mode", "replay"))]
+    file: Option<PathBuf>,
+}
+
+/// Run modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Mode {
+    /// Stream live transactions and snipe launches
+    Snipe,
+    /// Feed recorded updates through the pipeline in dry-run
+    Replay,
+    /// Load and validate the configuration, then exit
+    ValidateConfig,
+}
 
 /// Main application entry point
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+    let config = load_config_or_exit(&cli.config);
+    set_dry_run(cli.dry_run);
+    set_log_level(cli.log_level);
+    
     println!("🚀 Starting Bonk.fun Trading Sniper Bot v2.0.0...");
     println!("📦 Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git");
     println!("👨‍💻 Author: solship");
     
-    // Initialize external services and global state
-    initialize_services().await?;
-    
-    // Start background tasks for optimal performance
-    start_background_tasks().await?;
-    
-    // Setup and start gRPC transaction monitoring
-    start_transaction_monitoring().await?;
-    
-    Ok(())
+    let sniper = Sniper::new(config)?;
+    match cli.mode {
+        Mode::ValidateConfig => run_validate_config(),
+        Mode::Replay => match cli.file {
+            Some(file) => run_replay(file).await,
+            None => Err("--mode replay needs a file of recorded updates".into()),
+        },
+        Mode::Snipe => run_snipe(sniper).await,
+    }
 }
 
 /**
- * Initializes all external services and clients
- * 
- * This function sets up connections to various services:
- * - Nozomi confirmation service
- * - Zero Slot confirmation service  
- * - Jito bundle service
+ * Reads the config file, exiting with code 1 if it's missing or malformed
  * 
- * @returns Result<(), Box<dyn std::error::Error>> - Success or error
+ * @param path - Path to the TOML config file
+ * @returns Config - Parsed configuration
  */
-async fn initialize_services() -> Result<(), Box<dyn std::error::Error>> {
-    println!("🔧 Initializing external services...");
-    
-    // Initialize confirmation services
-    init_nozomi().await;
-    init_zslot().await;
-    init_jito().await;
-    
-    println!("✅ External services initialized successfully");
-    Ok(())
+fn load_config_or_exit(path: &Path) -> Config {
+    set_config_path(path.to_path_buf());
+    match Config::load_from(path) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            eprintln!("💡 Copy config.toml from the repository as a starting point and check its syntax.");
+            std::process::exit(1);
+        }
+    }
 }
 
 /**
- * Starts background tasks for optimal performance
- * 
- * Background tasks include:
- * - Blockhash management for transaction signing
- * - Health monitoring and metrics collection
- * - Connection keep-alive management
+ * Runs the live sniping pipeline until Ctrl-C
  * 
+ * @param sniper - Sniper built from the loaded config
  * @returns Result<(), Box<dyn std::error::Error>> - Success or error
  */
-async fn start_background_tasks() -> Result<(), Box<dyn std::error::Error>> {
-    println!("🔄 Starting background tasks...");
+async fn run_snipe(sniper: Sniper) -> Result<(), Box<dyn std::error::Error>> {
+    // Fail fast on a bad config before connecting to anything
+    validate_config_or_exit();
     
-    // Start blockhash handler loop in background for optimal performance
-    tokio::spawn(async {
-        println!("📡 Blockhash handler started");
-        loop {
-            match recent_blockhash_handler(RPC_CLIENT.clone()).await {
-                Ok(_) => {
-                    // Successfully updated blockhash
-                }
-                Err(e) => {
-                    eprintln!("❌ Blockhash handler error: {}", e);
-                    // Continue running despite errors
-                }
-            }
-            
-            // Small delay to prevent excessive RPC calls
-            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    let shutdown = CancellationToken::new();
+    let ctrl_c = shutdown.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            ctrl_c.cancel();
         }
     });
     
-    println!("✅ Background tasks started successfully");
-    Ok(())
+    sniper.run(shutdown).await
 }
 
 /**
- * Sets up and starts gRPC transaction monitoring
+ * Validates the configuration without connecting to anything
  * 
- * This function:
- * 1. Establishes gRPC connection to Helius Laserstream
- * 2. Configures transaction filters for Bonk.fun programs
- * 3. Starts processing transaction updates
- * 4. Handles connection errors and reconnection
- * 
- * @returns Result<(), Box<dyn std::error::Error>> - Success or error
+ * Exits the process with code 0 when valid and 1 otherwise.
  */
-async fn start_transaction_monitoring() -> Result<(), Box<dyn std::error::Error>> {
-    println!("🌐 Setting up gRPC transaction monitoring...");
-    
-    // Setup gRPC client with error handling
-    let mut grpc_client = match setup_client_grpc(GRPC_ENDPOINT.to_string(), GRPC_TOKEN.to_string()).await {
-        Ok(client) => {
-            println!("✅ gRPC client connected successfully");
-            client
-        }
-        Err(e) => {
-            eprintln!("❌ Failed to connect to gRPC: {}", e);
-            return Err(e);
-        }
-    };
-
-    // Setup subscription channel
-    let (subscribe_tx, subscribe_rx) = match grpc_client.subscribe().await {
-        Ok(channel) => {
-            println!("✅ gRPC subscription channel established");
-            channel
-        }
-        Err(e) => {
-            eprintln!("❌ Failed to create subscription channel: {}", e);
-            return Err(Box::new(e));
-        }
-    };
-
-    // Configure transaction filters for Bonk.fun programs
-    let subscribe_filter = create_transaction_filter();
-    
-    // Send subscription request with error handling
-    match send_subscription_request_grpc(subscribe_tx, subscribe_filter).await {
-        Ok(_) => {
-            println!("✅ Transaction filter subscription sent successfully");
-        }
-        Err(e) => {
-            eprintln!("❌ Failed to send subscription request: {}", e);
-            return Err(e);
-        }
-    }
+fn run_validate_config() -> ! {
+    validate_config_or_exit();
+    println!("✅ Configuration is valid");
+    std::process::exit(0);
+}
 
-    // Start processing transaction updates with comprehensive error handling
-    println!("🎯 Starting transaction processing loop...");
-    match process_updates_grpc(subscribe_rx).await {
-        Ok(_) => {
-            println!("✅ Transaction processing completed successfully");
-        }
-        Err(e) => {
-            eprintln!("❌ Error processing transaction updates: {}", e);
-            return Err(e);
+/**
+ * Validates the whole configuration, exiting with code 1 on any problem
+ * 
+ * All problems are reported together so a config can be fixed in one pass.
+ */
+fn validate_config_or_exit() {
+    if let Err(errors) = validate_all_configuration() {
+        eprintln!("❌ Configuration is invalid ({} problems):", errors.len());
+        for error in &errors {
+            eprintln!("   - {}", error);
         }
+        std::process::exit(1);
     }
-
-    Ok(())
 }
 
 /**
- * Creates transaction filter for Bonk.fun programs
+ * Replays recorded updates through the processing pipeline
  * 
- * This function configures which programs to monitor:
- * - Moonshot program for token launches
- * - Pump.fun program for pump tokens
- * - Raydium launchpad for liquidity events
+ * Replay always runs in dry-run mode so historical launches are never bought.
  * 
- * @returns SubscribeRequestFilterTransactions - Configured filter
+ * @param file - File of recorded updates
+ * @returns Result<(), Box<dyn std::error::Error>> - Success or error
  */
-fn create_transaction_filter() -> SubscribeRequestFilterTransactions {
-    println!("🔍 Configuring transaction filters...");
+async fn run_replay(file: PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+    set_dry_run(true);
     
-    SubscribeRequestFilterTransactions {
-        account_include: vec![
-            MOONSHOT_PROGRAM_ID.to_string(),
-            PUMP_FUN_PROGRAM_ID.to_string(),
-            RAYDIUM_LAUNCHPAD_PROGRAM_ID.to_string(),
-        ],
-        account_exclude: vec![],
-        account_required: vec![],
-        vote: Some(false),
-        failed: Some(false),
-        signature: None,
-    }
+    let updates = load_replay_updates(&file)?;
+    println!("🔁 Replaying {} updates from {}", updates.len(), file.display());
+    
+    start_background_tasks().await?;
+    process_updates_grpc(replay_stream(updates)).await
 }
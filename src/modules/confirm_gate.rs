@@ -0,0 +1,217 @@
+/**
+ * 🔒 Confirmation Gate Module - Bonk.fun Trading Sniper Bot
+ *
+ * With `[trade].require_confirmed`, launches are detected at `processed`
+ * but only traded once the slot they landed in is confirmed, so launches
+ * on a fork that gets dropped are never bought.
+ *
+ * State per mint:
+ * - Pending: seen at processed, waiting for its slot to be confirmed
+ * - Released: slot confirmed within the window, handed back for trading
+ * - Dropped: slot died, or the window passed without a confirmation
+ *
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use solana_sdk::pubkey::Pubkey;
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use crate::{LaunchEvent, clock_now, increment_counter};
+
+/// A launch seen at processed whose slot hasn't been confirmed yet
+#[derive(Debug)]
+struct PendingLaunch {
+    event: LaunchEvent,
+    slot: u64,
+    seen_at: Instant,
+}
+
+/// Holds launches until their slot is confirmed, keyed by mint
+#[derive(Debug)]
+pub struct ConfirmGate {
+    window: Duration,
+    pending: HashMap<Pubkey, PendingLaunch>,
+}
+
+impl ConfirmGate {
+    pub fn new(window: Duration) -> Self {
+        ConfirmGate {
+            window,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Number of launches waiting for confirmation
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /**
+     * Records a launch seen at processed
+     *
+     * A mint already pending keeps its first sighting, so a repeat launch
+     * transaction can't extend the window.
+     *
+     * @param mint - Mint of the launched token
+     * @param slot - Slot the launch transaction landed in
+     * @param event - Detected launch event
+     */
+    pub fn hold(&mut self, mint: Pubkey, slot: u64, event: LaunchEvent) {
+        self.pending.entry(mint).or_insert_with(|| PendingLaunch {
+            event,
+            slot,
+            seen_at: clock_now(),
+        });
+    }
+
+    /**
+     * Releases every pending launch from a slot that was just confirmed
+     *
+     * Launches past the window are dropped first, so a late confirmation
+     * doesn't trigger a buy.
+     *
+     * @param slot - Slot that reached confirmed
+     * @returns Vec<LaunchEvent> - Launches ready to trade
+     */
+    pub fn confirm_slot(&mut self, slot: u64) -> Vec<LaunchEvent> {
+        self.expire();
+
+        let mints: Vec<Pubkey> = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| pending.slot == slot)
+            .map(|(mint, _)| *mint)
+            .collect();
+
+        mints
+            .into_iter()
+            .filter_map(|mint| self.pending.remove(&mint))
+            .map(|pending| {
+                increment_counter("launches_confirmed");
+                pending.event
+            })
+            .collect()
+    }
+
+    /// Drops every pending launch from a slot that won't be confirmed
+    pub fn drop_slot(&mut self, slot: u64) {
+        self.pending.retain(|mint, pending| {
+            if pending.slot != slot {
+                return true;
+            }
+            increment_counter("launches_unconfirmed_dropped");
+            println!("🗑️ Dropping launch of {}: slot {} died before confirming", mint, slot);
+            false
+        });
+    }
+
+    /// Drops pending launches whose confirmation window has passed
+    pub fn expire(&mut self) {
+        let window = self.window;
+        let now = clock_now();
+        self.pending.retain(|mint, pending| {
+            if now.duration_since(pending.seen_at) <= window {
+                return true;
+            }
+            increment_counter("launches_unconfirmed_dropped");
+            println!(
+                "🗑️ Dropping launch of {}: slot {} not confirmed within {}ms",
+                mint,
+                pending.slot,
+                window.as_millis()
+            );
+            false
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::ManualClockGuard;
+
+    const WINDOW: Duration = Duration::from_millis(500);
+
+    fn launch(mint: Pubkey, tx_id: &str) -> LaunchEvent {
+        LaunchEvent::Migration {
+            mint,
+            quote_mint: spl_token::native_mint::ID,
+            migrate_type: 0,
+            tx_id: tx_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn confirmed_slot_releases_its_launches() {
+        let _clock = ManualClockGuard::install();
+        let mut gate = ConfirmGate::new(WINDOW);
+        let (mint, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+        gate.hold(mint, 10, launch(mint, "a"));
+        gate.hold(other, 11, launch(other, "b"));
+
+        let released = gate.confirm_slot(10);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].mint(), mint);
+        assert_eq!(gate.pending_count(), 1);
+    }
+
+    #[test]
+    fn dead_slot_drops_its_launches() {
+        let _clock = ManualClockGuard::install();
+        let mut gate = ConfirmGate::new(WINDOW);
+        let mint = Pubkey::new_unique();
+        gate.hold(mint, 10, launch(mint, "a"));
+
+        gate.drop_slot(10);
+        assert_eq!(gate.pending_count(), 0);
+        assert!(gate.confirm_slot(10).is_empty());
+    }
+
+    #[test]
+    fn late_confirmation_releases_nothing() {
+        let clock = ManualClockGuard::install();
+        let mut gate = ConfirmGate::new(WINDOW);
+        let mint = Pubkey::new_unique();
+        gate.hold(mint, 10, launch(mint, "a"));
+
+        clock.advance(WINDOW + Duration::from_millis(1));
+        assert!(gate.confirm_slot(10).is_empty());
+        assert_eq!(gate.pending_count(), 0);
+    }
+
+    #[test]
+    fn repeat_sighting_keeps_the_first() {
+        let clock = ManualClockGuard::install();
+        let mut gate = ConfirmGate::new(WINDOW);
+        let mint = Pubkey::new_unique();
+        gate.hold(mint, 10, launch(mint, "first"));
+
+        clock.advance(WINDOW / 2);
+        gate.hold(mint, 12, launch(mint, "repeat"));
+        assert_eq!(gate.pending_count(), 1);
+        assert!(gate.confirm_slot(12).is_empty());
+
+        let released = gate.confirm_slot(10);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].tx_id(), "first");
+    }
+
+    #[test]
+    fn repeat_sighting_does_not_extend_the_window() {
+        let clock = ManualClockGuard::install();
+        let mut gate = ConfirmGate::new(WINDOW);
+        let mint = Pubkey::new_unique();
+        gate.hold(mint, 10, launch(mint, "first"));
+
+        clock.advance(WINDOW / 2);
+        gate.hold(mint, 10, launch(mint, "repeat"));
+        clock.advance(WINDOW / 2 + Duration::from_millis(1));
+        gate.expire();
+        assert_eq!(gate.pending_count(), 0);
+    }
+}
@@ -0,0 +1,281 @@
+/**
+ * 📈 Bonding Curve Module - Bonk.fun Trading Sniper Bot
+ * 
+ * This module implements Raydium Launchpad bonding curve quotes, shared by
+ * slippage protection, expected-output estimates, market-cap filters and
+ * exit logic.
+ * 
+ * Reserve Model:
+ * - `virtual_base` / `virtual_quote` are the curve's virtual reserves
+ * - `real_base` is the base amount already sold out of the pool
+ * - `real_quote` is the quote amount raised so far
+ * 
+ * Quotes are computed before trading fees and round down, matching the
+ * on-chain program so a quote never promises more than a swap delivers.
+ * 
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
+
+use crate::{BonkCurveParams, RPC_CLIENT, SniperError};
+
+/// Fixed-point scale used by the linear curve slope (Q64)
+const Q64: u128 = 1 << 64;
+
+/// Offset of `virtual_base` in the launchpad pool state account; `virtual_quote`,
+/// `real_base` and `real_quote` follow as consecutive u64s
+const POOL_STATE_VIRTUAL_BASE_OFFSET: usize = 37;
+
+/// Live pool reserves needed to price a swap
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CurveReserves {
+    pub virtual_base: u64,
+    pub virtual_quote: u64,
+    pub real_base: u64,
+    pub real_quote: u64,
+}
+
+/**
+ * Reads the current reserves out of a launchpad pool state account
+ * 
+ * @param pool_state - Pool state account of the launch
+ * @returns Result<CurveReserves, SniperError> - Live reserves or error
+ */
+pub async fn fetch_curve_reserves(pool_state: &Pubkey) -> Result<CurveReserves, SniperError> {
+    let account = RPC_CLIENT
+        .get_account_with_commitment(pool_state, CommitmentConfig::processed())
+        .await?
+        .value
+        .ok_or_else(|| SniperError::TransactionBuild(format!("pool state {} not found", pool_state)))?;
+
+    parse_curve_reserves(&account.data).ok_or_else(|| {
+        SniperError::TransactionBuild(format!(
+            "pool state {} is too short ({} bytes)",
+            pool_state,
+            account.data.len()
+        ))
+    })
+}
+
+/**
+ * Decodes the reserves from raw pool state account data
+ * 
+ * @param data - Pool state account data
+ * @returns Option<CurveReserves> - Reserves, or None if the data is too short
+ */
+pub fn parse_curve_reserves(data: &[u8]) -> Option<CurveReserves> {
+    let field = |index: usize| {
+        let start = POOL_STATE_VIRTUAL_BASE_OFFSET + index * 8;
+        data.get(start..start + 8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+    };
+
+    Some(CurveReserves {
+        virtual_base: field(0)?,
+        virtual_quote: field(1)?,
+        real_base: field(2)?,
+        real_quote: field(3)?,
+    })
+}
+
+/**
+ * Least base tokens to accept for a quote under a slippage tolerance
+ * 
+ * @param expected_out - Quoted base tokens out
+ * @param slippage_decimal - Tolerated slippage as a fraction (0.01 = 1%)
+ * @returns u64 - Slippage-bounded minimum, 0 at 100% slippage
+ */
+pub fn slippage_min_out(expected_out: u64, slippage_decimal: f64) -> u64 {
+    let keep = (1.0 - slippage_decimal).clamp(0.0, 1.0);
+    (expected_out as f64 * keep).floor() as u64
+}
+
+/**
+ * Quotes how many base tokens a SOL input buys
+ * 
+ * @param curve - Curve the pool was launched with
+ * @param sol_in - Quote (SOL) input in lamports
+ * @param reserves - Current pool reserves
+ * @returns u64 - Base tokens out, 0 if the curve can't fill the input
+ */
+pub fn quote_base_out(curve: &BonkCurveParams, sol_in: u64, reserves: &CurveReserves) -> u64 {
+    let sol_in = sol_in as u128;
+    let virtual_base = reserves.virtual_base as u128;
+    let virtual_quote = reserves.virtual_quote as u128;
+    let real_base = reserves.real_base as u128;
+    let real_quote = reserves.real_quote as u128;
+
+    let out = match curve {
+        BonkCurveParams::Constant(_) => {
+            // x * y = k over (virtual_quote + real_quote, virtual_base - real_base)
+            let input_reserve = virtual_quote + real_quote;
+            let output_reserve = virtual_base.saturating_sub(real_base);
+            if input_reserve + sol_in == 0 {
+                return 0;
+            }
+            sol_in * output_reserve / (input_reserve + sol_in)
+        }
+        BonkCurveParams::Fixed(_) => {
+            // Constant price of virtual_quote / virtual_base
+            if virtual_quote == 0 {
+                return 0;
+            }
+            sol_in * virtual_base / virtual_quote
+        }
+        BonkCurveParams::Linear(_) => {
+            // price(x) = a * x / Q64, so quote for [x0, x1] = a * (x1² - x0²) / (2 * Q64)
+            let a = virtual_base;
+            if a == 0 {
+                return 0;
+            }
+            // An input too large for u128 math is far beyond what any curve can fill
+            let x0 = real_base;
+            let Some(x1_squared) = (2 * sol_in)
+                .checked_mul(Q64)
+                .and_then(|scaled| (scaled / a).checked_add(x0 * x0))
+            else {
+                return 0;
+            };
+            isqrt(x1_squared).saturating_sub(x0)
+        }
+    };
+
+    u64::try_from(out).unwrap_or(u64::MAX)
+}
+
+/**
+ * Quotes how much SOL selling base tokens returns
+ * 
+ * @param curve - Curve the pool was launched with
+ * @param base_in - Base token input
+ * @param reserves - Current pool reserves
+ * @returns u64 - Quote (SOL) out in lamports
+ */
+pub fn quote_sol_out(curve: &BonkCurveParams, base_in: u64, reserves: &CurveReserves) -> u64 {
+    let base_in = base_in as u128;
+    let virtual_base = reserves.virtual_base as u128;
+    let virtual_quote = reserves.virtual_quote as u128;
+    let real_base = reserves.real_base as u128;
+    let real_quote = reserves.real_quote as u128;
+
+    let out = match curve {
+        BonkCurveParams::Constant(_) => {
+            let input_reserve = virtual_base.saturating_sub(real_base);
+            let output_reserve = virtual_quote + real_quote;
+            if input_reserve + base_in == 0 {
+                return 0;
+            }
+            // Overflow only happens at amounts that would drain the pool anyway
+            base_in
+                .checked_mul(output_reserve)
+                .map_or(real_quote, |product| product / (input_reserve + base_in))
+        }
+        BonkCurveParams::Fixed(_) => {
+            if virtual_base == 0 {
+                return 0;
+            }
+            base_in * virtual_quote / virtual_base
+        }
+        BonkCurveParams::Linear(_) => {
+            let a = virtual_base;
+            let x0 = real_base;
+            let x1 = x0.saturating_sub(base_in);
+            a.checked_mul(x0 * x0 - x1 * x1)
+                .map_or(real_quote, |product| product / (2 * Q64))
+        }
+    };
+
+    // Can never pay out more than the pool has raised
+    u64::try_from(out.min(real_quote)).unwrap_or(u64::MAX)
+}
+
+/// Integer square root (floor) via Newton's method
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    // ceil(n / 2), without overflowing at u128::MAX
+    let mut y = x / 2 + (x & 1);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BonkConstantCurve, BonkFixedCurve, BonkLinearCurve};
+
+    fn constant() -> BonkCurveParams {
+        BonkCurveParams::Constant(BonkConstantCurve {
+            supply: 0,
+            total_base_sell: 0,
+            total_quote_fund_raising: 0,
+            migrate_type: 0,
+        })
+    }
+
+    fn fixed() -> BonkCurveParams {
+        BonkCurveParams::Fixed(BonkFixedCurve { supply: 0, total_quote_fund_raising: 0, migrate_type: 0 })
+    }
+
+    fn linear() -> BonkCurveParams {
+        BonkCurveParams::Linear(BonkLinearCurve { supply: 0, total_quote_fund_raising: 0, migrate_type: 0 })
+    }
+
+    fn reserves(virtual_base: u64, virtual_quote: u64, real_base: u64, real_quote: u64) -> CurveReserves {
+        CurveReserves { virtual_base, virtual_quote, real_base, real_quote }
+    }
+
+    #[test]
+    fn constant_curve_quotes_round_trip() {
+        assert_eq!(quote_base_out(&constant(), 1_000, &reserves(1_000_000, 1_000, 0, 0)), 500_000);
+        assert_eq!(quote_sol_out(&constant(), 500_000, &reserves(1_000_000, 1_000, 500_000, 1_000)), 1_000);
+    }
+
+    #[test]
+    fn fixed_curve_quotes_at_a_constant_price() {
+        assert_eq!(quote_base_out(&fixed(), 10, &reserves(1_000_000, 1_000, 0, 0)), 10_000);
+        assert_eq!(quote_sol_out(&fixed(), 10_000, &reserves(1_000_000, 1_000, 10_000, 10)), 10);
+        assert_eq!(quote_base_out(&fixed(), 10, &reserves(1_000_000, 0, 0, 0)), 0);
+    }
+
+    #[test]
+    fn linear_curve_quotes_round_trip() {
+        // Slope of 2^-32 lamports per token: 2^31 lamports buy the first 2^32 tokens
+        let slope = 1 << 32;
+        assert_eq!(quote_base_out(&linear(), 1 << 31, &reserves(slope, 0, 0, 0)), 1 << 32);
+        assert_eq!(quote_sol_out(&linear(), 1 << 32, &reserves(slope, 0, 1 << 32, 1 << 31)), 1 << 31);
+    }
+
+    #[test]
+    fn sell_quote_never_exceeds_what_was_raised() {
+        assert_eq!(quote_sol_out(&constant(), 999_999, &reserves(1_000_000, 1_000, 999_999, 5)), 5);
+    }
+
+    #[test]
+    fn linear_buy_too_large_for_the_math_quotes_nothing() {
+        assert_eq!(quote_base_out(&linear(), u64::MAX, &reserves(1, 0, 0, 0)), 0);
+        assert_eq!(quote_base_out(&linear(), 1 << 62, &reserves(1, 0, u64::MAX, 0)), 0);
+    }
+
+    #[test]
+    fn isqrt_floors_up_to_the_largest_input() {
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+    }
+
+    #[test]
+    fn sells_too_large_for_the_math_pay_out_what_was_raised() {
+        let huge = reserves(u64::MAX, u64::MAX, u64::MAX, u64::MAX);
+        assert_eq!(quote_sol_out(&linear(), u64::MAX, &huge), u64::MAX);
+        assert_eq!(quote_sol_out(&constant(), u64::MAX, &reserves(1, u64::MAX, 0, u64::MAX)), u64::MAX);
+    }
+}
@@ -0,0 +1,760 @@
+/**
+ * 🧹 Launch Filters Module - Bonk.fun Trading Sniper Bot
+ * 
+ * This module defines the `LaunchFilter` trait and the built-in checks
+ * (token name, dev buy, Twitter/X). Filters enabled in `[filter]` are
+ * registered at startup; custom filters can be added with
+ * `register_launch_filter` without touching the trading pipeline.
+ * 
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use serde_json::Value;
+use solana_client::client_error::reqwest::{self, Url};
+use std::{
+    fmt,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use crate::{BONK_FEE_RATE_DENOMINATOR, BuyAmount, CONFIG, LaunchEvent};
+
+/// Why a launch was rejected by the filters
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterRejectReason {
+    /// Token name isn't in `token_name_filter_list`
+    TokenNameNotAllowed { name: String },
+    /// Dev buy falls outside `[dev_buy_min, dev_buy_max]`
+    DevBuyOutOfRange { amount_lamports: u64 },
+    /// Dev buy sets a referral fee above `max_share_fee_rate`
+    ShareFeeTooHigh { share_fee_rate: u64 },
+    /// Metadata doesn't link to any `x_filter_list` pattern
+    SocialLinkMissing,
+    /// A field from `x_required_fields` is absent or empty
+    SocialFieldMissing { field: String },
+    /// Metadata couldn't be fetched, so the social check couldn't run
+    MetadataFetchFailed(String),
+    /// Metadata URI is empty or unparseable and `empty_uri_policy` rejects it
+    MetadataUriMissing { uri: String },
+    /// Metadata URI isn't safe to fetch (scheme, host or address not allowed)
+    MetadataUriNotAllowed { uri: String, reason: String },
+    /// Moonshot launch collateralized in a currency missing from `moonshot_allowed_collateral`
+    CollateralNotAllowed { currency: String },
+    /// Rejected by a custom filter
+    Custom { filter: String, reason: String },
+    /// `any` mode: every filter rejected the launch
+    NonePassed(Vec<FilterRejectReason>),
+}
+
+impl FilterRejectReason {
+    /// Short stable name, used as the metric label
+    pub fn label(&self) -> &'static str {
+        match self {
+            FilterRejectReason::TokenNameNotAllowed { .. } => "token_name",
+            FilterRejectReason::DevBuyOutOfRange { .. } => "dev_buy",
+            FilterRejectReason::ShareFeeTooHigh { .. } => "share_fee",
+            FilterRejectReason::SocialLinkMissing => "social_link",
+            FilterRejectReason::SocialFieldMissing { .. } => "social_field",
+            FilterRejectReason::MetadataFetchFailed(_) => "metadata_fetch",
+            FilterRejectReason::MetadataUriMissing { .. } => "metadata_uri_missing",
+            FilterRejectReason::MetadataUriNotAllowed { .. } => "metadata_uri",
+            FilterRejectReason::CollateralNotAllowed { .. } => "moonshot_collateral",
+            FilterRejectReason::Custom { .. } => "custom",
+            FilterRejectReason::NonePassed(_) => "none_passed",
+        }
+    }
+}
+
+impl fmt::Display for FilterRejectReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterRejectReason::TokenNameNotAllowed { name } => write!(f, "token name {:?} not allowed", name),
+            FilterRejectReason::DevBuyOutOfRange { amount_lamports } => {
+                write!(f, "dev buy of {} SOL out of range", lamports_to_sol(*amount_lamports))
+            }
+            FilterRejectReason::ShareFeeTooHigh { share_fee_rate } => {
+                write!(f, "share fee of {}% too high", share_fee_percent(*share_fee_rate))
+            }
+            FilterRejectReason::SocialLinkMissing => write!(f, "no matching social link in metadata"),
+            FilterRejectReason::SocialFieldMissing { field } => write!(f, "metadata has no {} link", field),
+            FilterRejectReason::MetadataFetchFailed(e) => write!(f, "metadata fetch failed: {}", e),
+            FilterRejectReason::MetadataUriMissing { uri } => write!(f, "metadata uri {:?} is empty or invalid", uri),
+            FilterRejectReason::MetadataUriNotAllowed { uri, reason } => {
+                write!(f, "metadata uri {} not fetched: {}", uri, reason)
+            }
+            FilterRejectReason::CollateralNotAllowed { currency } => {
+                write!(f, "moonshot collateral {} not allowed", currency)
+            }
+            FilterRejectReason::Custom { filter, reason } => write!(f, "{}: {}", filter, reason),
+            FilterRejectReason::NonePassed(reasons) => {
+                let reasons = reasons.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+                write!(f, "no filter passed ({})", reasons.join("; "))
+            }
+        }
+    }
+}
+
+/// A check a launch must pass before it is bought
+#[async_trait]
+pub trait LaunchFilter: Send + Sync {
+    /// Returns Ok if the launch passes this check, or why it doesn't
+    async fn check(&self, event: &LaunchEvent) -> Result<(), FilterRejectReason>;
+
+    /// Name used in logs
+    fn name(&self) -> &str;
+}
+
+/// Upper bound on a metadata fetch, so a tarpit URI can't stall the trade task
+const METADATA_FETCH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Largest metadata document read, so a hostile URI can't stream an endless body
+const MAX_METADATA_BYTES: usize = 64 * 1024;
+
+/// Host suffixes that only resolve inside private networks
+const PRIVATE_HOST_SUFFIXES: &[&str] = &[".localhost", ".local", ".localdomain", ".internal", ".lan", ".home.arpa"];
+
+/// Registered filters, cheapest first; built-ins come from `[filter]`
+static LAUNCH_FILTERS: Lazy<RwLock<Vec<Arc<dyn LaunchFilter>>>> =
+    Lazy::new(|| RwLock::new(build_filters_from_config()));
+
+/**
+ * Builds the built-in filters enabled in `[filter]`
+ * 
+ * Cheap local checks come before the network-bound Twitter/X check so
+ * it can be skipped once the outcome is decided.
+ * 
+ * @returns Vec<Arc<dyn LaunchFilter>> - Enabled filters
+ */
+fn build_filters_from_config() -> Vec<Arc<dyn LaunchFilter>> {
+    let filter = &CONFIG.filter;
+    let mut filters: Vec<Arc<dyn LaunchFilter>> = Vec::new();
+
+    if filter.token_name_check {
+        filters.push(Arc::new(TokenNameFilter {
+            allowed_names: filter.token_name_filter_list.clone(),
+        }));
+    }
+    if filter.dev_buy_check {
+        filters.push(Arc::new(DevBuyFilter {
+            min_lamports: sol_to_lamports(filter.dev_buy_min),
+            max_lamports: filter.dev_buy_max.map(sol_to_lamports).unwrap_or(u64::MAX),
+        }));
+    }
+    if let Some(max_rate) = filter.max_share_fee_rate {
+        filters.push(Arc::new(ShareFeeFilter { max_rate }));
+    }
+    if filter.x_check {
+        filters.push(Arc::new(SocialMediaFilter {
+            patterns: filter.x_filter_list.clone(),
+            required_fields: filter.x_required_fields.clone(),
+            empty_uri_policy: EmptyUriPolicy::from_config(&filter.empty_uri_policy, filter.empty_uri_retry_ms),
+        }));
+    }
+
+    filters
+}
+
+/// Adds a custom filter, evaluated after the built-in ones
+pub fn register_launch_filter(filter: Arc<dyn LaunchFilter>) {
+    println!("🧹 Registered launch filter: {}", filter.name());
+    LAUNCH_FILTERS.write().unwrap().push(filter);
+}
+
+/// Snapshot of the registered filters, safe to hold across awaits
+pub fn launch_filters() -> Vec<Arc<dyn LaunchFilter>> {
+    LAUNCH_FILTERS.read().unwrap().clone()
+}
+
+/// Passes launches whose token name is in the allow list
+pub struct TokenNameFilter {
+    pub allowed_names: Vec<String>,
+}
+
+#[async_trait]
+impl LaunchFilter for TokenNameFilter {
+    async fn check(&self, event: &LaunchEvent) -> Result<(), FilterRejectReason> {
+        let LaunchEvent::Bonk { mint_info, .. } = event else {
+            return Ok(());
+        };
+        let token_name = &mint_info.base_mint_param.name;
+
+        if !self.allowed_names.iter().any(|name| name == token_name) {
+            return Err(FilterRejectReason::TokenNameNotAllowed { name: token_name.clone() });
+        }
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "token_name_check"
+    }
+}
+
+/**
+ * Passes launches whose dev buy falls within `[min, max]` lamports
+ * 
+ * Both bounds are inclusive, so a buy of exactly either bound passes.
+ */
+pub struct DevBuyFilter {
+    pub min_lamports: u64,
+    pub max_lamports: u64,
+}
+
+#[async_trait]
+impl LaunchFilter for DevBuyFilter {
+    async fn check(&self, event: &LaunchEvent) -> Result<(), FilterRejectReason> {
+        let LaunchEvent::Bonk { buy_param, .. } = event else {
+            return Ok(());
+        };
+
+        let sol_in = buy_param.sol_in();
+        if !(self.min_lamports..=self.max_lamports).contains(&sol_in) {
+            return Err(FilterRejectReason::DevBuyOutOfRange { amount_lamports: sol_in });
+        }
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "dev_buy_check"
+    }
+}
+
+/// Passes launches whose dev buy sets a share (referral) fee of at most `max_rate`
+pub struct ShareFeeFilter {
+    /// Parts per million, like the on-chain `share_fee_rate`
+    pub max_rate: u64,
+}
+
+#[async_trait]
+impl LaunchFilter for ShareFeeFilter {
+    async fn check(&self, event: &LaunchEvent) -> Result<(), FilterRejectReason> {
+        let LaunchEvent::Bonk { buy_param, .. } = event else {
+            return Ok(());
+        };
+
+        if buy_param.share_fee_rate > self.max_rate {
+            return Err(FilterRejectReason::ShareFeeTooHigh { share_fee_rate: buy_param.share_fee_rate });
+        }
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "max_share_fee_rate"
+    }
+}
+
+/**
+ * What the social check does with a launch whose metadata isn't there yet
+ * 
+ * Many launches go out before the dev has uploaded metadata, leaving the
+ * URI empty or pointing at content that doesn't resolve yet.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmptyUriPolicy {
+    /// Reject launches with an empty or invalid URI
+    Reject,
+    /// Let launches with an empty or invalid URI through the social check
+    Allow,
+    /// Reject an empty or invalid URI, but fetch once more after the delay when a fetch fails
+    RetryOnceAfter(Duration),
+}
+
+impl EmptyUriPolicy {
+    /// Parses `[filter].empty_uri_policy`; unknown values fall back to `reject`
+    pub fn from_config(policy: &str, retry_ms: u64) -> Self {
+        match policy {
+            "allow" => EmptyUriPolicy::Allow,
+            "retry_once_after_ms" => EmptyUriPolicy::RetryOnceAfter(Duration::from_millis(retry_ms)),
+            _ => EmptyUriPolicy::Reject,
+        }
+    }
+}
+
+/// Passes launches whose metadata links to one of the configured patterns
+pub struct SocialMediaFilter {
+    pub patterns: Vec<String>,
+    /// Social fields that must be present and non-empty
+    pub required_fields: Vec<String>,
+    /// Handling of launches whose metadata is missing
+    pub empty_uri_policy: EmptyUriPolicy,
+}
+
+#[async_trait]
+impl LaunchFilter for SocialMediaFilter {
+    async fn check(&self, event: &LaunchEvent) -> Result<(), FilterRejectReason> {
+        let LaunchEvent::Bonk { mint_info, tx_id, .. } = event else {
+            return Ok(());
+        };
+
+        let uri = mint_info.base_mint_param.uri.trim();
+        if uri.is_empty() || Url::parse(uri).is_err() {
+            if self.empty_uri_policy == EmptyUriPolicy::Allow {
+                println!("🔗 TX {}: metadata uri {:?} is empty or invalid, allowed by empty_uri_policy", tx_id, uri);
+                return Ok(());
+            }
+            println!("🔗 TX {}: metadata uri {:?} is empty or invalid, rejected by empty_uri_policy", tx_id, uri);
+            return Err(FilterRejectReason::MetadataUriMissing { uri: uri.to_string() });
+        }
+
+        let url = check_metadata_uri(uri, &CONFIG.filter.metadata_host_allowlist).map_err(|reason| {
+            FilterRejectReason::MetadataUriNotAllowed { uri: uri.to_string(), reason }
+        })?;
+        let addrs = resolve_public_addrs(uri, &url).await?;
+
+        let response_text = match (fetch_metadata(url.clone(), &addrs).await, self.empty_uri_policy) {
+            (Ok(text), _) => text,
+            (Err(e), EmptyUriPolicy::RetryOnceAfter(delay)) => {
+                println!(
+                    "🔁 TX {}: metadata fetch failed ({}), retrying once in {}ms",
+                    tx_id,
+                    e,
+                    delay.as_millis()
+                );
+                tokio::time::sleep(delay).await;
+                let text = fetch_metadata(url, &addrs).await?;
+                println!("🔗 TX {}: metadata fetched on retry", tx_id);
+                text
+            }
+            (Err(e), _) => return Err(e),
+        };
+
+        check_social_links(&response_text, &self.patterns, &self.required_fields)
+    }
+
+    fn name(&self) -> &str {
+        "x_check"
+    }
+}
+
+/**
+ * Resolves a metadata host and checks every address it points to
+ * 
+ * A public-looking name can still resolve to a private address, so the
+ * addresses DNS returns are what gets checked. A lookup that fails is a
+ * fetch failure; one that reaches a private address is a refusal.
+ * 
+ * @param uri - Metadata URI from the launch, for the rejection
+ * @param url - URL that passed `check_metadata_uri`
+ * @returns Result<Vec<SocketAddr>, FilterRejectReason> - Addresses to connect to, or why not
+ */
+async fn resolve_public_addrs(uri: &str, url: &Url) -> Result<Vec<SocketAddr>, FilterRejectReason> {
+    let host = url.host_str().unwrap_or_default().trim_matches(|c| c == '[' || c == ']');
+    let port = url.port_or_known_default().unwrap_or(443);
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|e| FilterRejectReason::MetadataFetchFailed(format!("could not resolve {}: {}", host, e)))?
+        .collect();
+
+    if addrs.is_empty() {
+        return Err(FilterRejectReason::MetadataFetchFailed(format!("{} resolved to no addresses", host)));
+    }
+    if let Some(addr) = addrs.iter().find(|addr| !is_public_ip(&addr.ip())) {
+        return Err(FilterRejectReason::MetadataUriNotAllowed {
+            uri: uri.to_string(),
+            reason: format!("host {} resolves to non-public address {}", host, addr.ip()),
+        });
+    }
+    Ok(addrs)
+}
+
+/**
+ * Fetches a metadata document, treating non-success statuses as failures
+ * 
+ * The connection is pinned to the addresses already checked, so a second
+ * DNS answer can't point it somewhere else. Redirects are off so an
+ * allowed host can't bounce elsewhere, and the body is capped at
+ * `MAX_METADATA_BYTES`.
+ * 
+ * @param url - URL that passed `check_metadata_uri`
+ * @param addrs - Public addresses from `resolve_public_addrs`
+ * @returns Result<String, FilterRejectReason> - Document body or why it couldn't be read
+ */
+async fn fetch_metadata(url: Url, addrs: &[SocketAddr]) -> Result<String, FilterRejectReason> {
+    let fetch_failed = |e: reqwest::Error| FilterRejectReason::MetadataFetchFailed(e.to_string());
+    let too_large = || {
+        FilterRejectReason::MetadataFetchFailed(format!("metadata exceeds {} bytes", MAX_METADATA_BYTES))
+    };
+
+    let client = reqwest::Client::builder()
+        .timeout(METADATA_FETCH_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::none())
+        .resolve_to_addrs(url.host_str().unwrap_or_default(), addrs)
+        .build()
+        .map_err(fetch_failed)?;
+
+    let mut response = client
+        .get(url)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(fetch_failed)?;
+
+    if response.content_length().is_some_and(|len| len > MAX_METADATA_BYTES as u64) {
+        return Err(too_large());
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(fetch_failed)? {
+        if body.len() + chunk.len() > MAX_METADATA_BYTES {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+/// Metadata fields holding social links, at the top level or under `extensions`
+const SOCIAL_FIELDS: [&str; 4] = ["twitter", "website", "telegram", "discord"];
+
+/**
+ * Checks token metadata for the configured social links
+ * 
+ * Only the social fields are inspected: top-level `twitter`, `website`,
+ * `telegram` and `discord`, plus every string under `extensions`. Each
+ * required field must be non-empty, and when patterns are set at least
+ * one social link must contain one of them. A body that isn't JSON falls
+ * back to a substring search over the raw text; required fields can't be
+ * checked there, so they fail.
+ * 
+ * @param body - Raw metadata response
+ * @param patterns - Substrings a social link must contain (empty = any)
+ * @param required_fields - Fields that must be present and non-empty
+ * @returns Result<(), FilterRejectReason> - Ok if the metadata qualifies
+ */
+pub fn check_social_links(
+    body: &str,
+    patterns: &[String],
+    required_fields: &[String],
+) -> Result<(), FilterRejectReason> {
+    let metadata = match serde_json::from_str::<Value>(body) {
+        Ok(metadata) => metadata,
+        Err(_) => {
+            if let Some(field) = required_fields.first() {
+                return Err(FilterRejectReason::SocialFieldMissing { field: field.clone() });
+            }
+            if patterns.iter().any(|pattern| body.contains(pattern.as_str())) {
+                return Ok(());
+            }
+            return Err(FilterRejectReason::SocialLinkMissing);
+        }
+    };
+
+    let extensions = metadata.get("extensions");
+    let field_value = |field: &str| -> Option<&str> {
+        [metadata.get(field), extensions.and_then(|ext| ext.get(field))]
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(str::trim)
+            .find(|value| !value.is_empty())
+    };
+
+    for field in required_fields {
+        if field_value(field).is_none() {
+            return Err(FilterRejectReason::SocialFieldMissing { field: field.clone() });
+        }
+    }
+
+    if patterns.is_empty() {
+        return Ok(());
+    }
+
+    let mut links: Vec<&str> = SOCIAL_FIELDS.iter().filter_map(|field| field_value(field)).collect();
+    if let Some(Value::Object(ext)) = extensions {
+        links.extend(ext.values().filter_map(Value::as_str));
+    }
+
+    if links.iter().any(|link| patterns.iter().any(|pattern| link.contains(pattern.as_str()))) {
+        Ok(())
+    } else {
+        Err(FilterRejectReason::SocialLinkMissing)
+    }
+}
+
+/**
+ * Checks that a launch's metadata URI is safe to fetch
+ * 
+ * The URI is chosen by the token creator, so only http(s) URIs on an
+ * allowed host are fetched. An entry matches the host itself and its
+ * subdomains; an empty allowlist allows any public host. IP literals in
+ * private, loopback, link-local or unspecified ranges are always refused,
+ * as are `localhost`, single-label names and private-network suffixes
+ * such as `.internal`. Where a name resolves to is checked separately,
+ * right before the fetch.
+ * 
+ * @param uri - Metadata URI from the launch
+ * @param allowlist - Allowed hosts from `[filter].metadata_host_allowlist`
+ * @returns Result<Url, String> - Parsed URL, or why it was refused
+ */
+pub fn check_metadata_uri(uri: &str, allowlist: &[String]) -> Result<Url, String> {
+    let url = Url::parse(uri).map_err(|e| format!("invalid uri: {}", e))?;
+
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err(format!("scheme {} not allowed", url.scheme()));
+    }
+
+    let host = url.host_str().ok_or("uri has no host")?.to_ascii_lowercase();
+
+    if let Ok(ip) = host.trim_matches(|c| c == '[' || c == ']').parse::<IpAddr>() {
+        if !is_public_ip(&ip) {
+            return Err(format!("address {} is not public", ip));
+        }
+    } else if !is_public_hostname(&host) {
+        return Err(format!("host {} is not a public name", host));
+    }
+
+    let allowed = allowlist.is_empty()
+        || allowlist.iter().any(|entry| {
+            let entry = entry.to_ascii_lowercase();
+            host == entry || host.ends_with(&format!(".{}", entry))
+        });
+    if !allowed {
+        return Err(format!("host {} not in metadata_host_allowlist", host));
+    }
+
+    Ok(url)
+}
+
+/// Rejects names that can only resolve locally: `localhost`, single labels and private suffixes
+fn is_public_hostname(host: &str) -> bool {
+    let host = host.trim_end_matches('.');
+    host != "localhost"
+        && host.contains('.')
+        && !PRIVATE_HOST_SUFFIXES.iter().any(|suffix| host.ends_with(suffix))
+}
+
+fn is_public_ip(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => {
+            !(v4.is_private()
+                || v4.is_loopback()
+                || v4.is_link_local()
+                || v4.is_unspecified()
+                || v4.is_broadcast()
+                || v4.is_documentation()
+                // 100.64.0.0/10 carrier-grade NAT
+                || (v4.octets()[0] == 100 && (v4.octets()[1] & 0xc0) == 64))
+        }
+        IpAddr::V6(v6) => {
+            let segments = v6.segments();
+            !(v6.is_loopback()
+                || v6.is_unspecified()
+                // fc00::/7 unique local, fe80::/10 link-local
+                || (segments[0] & 0xfe00) == 0xfc00
+                || (segments[0] & 0xffc0) == 0xfe80
+                || v6.to_ipv4_mapped().is_some_and(|v4| !is_public_ip(&IpAddr::V4(v4))))
+        }
+    }
+}
+
+/// Converts SOL to lamports, rounding so 0.1 SOL isn't off by one lamport
+fn sol_to_lamports(sol: f64) -> u64 {
+    (sol * 10_f64.powi(9)).round() as u64
+}
+
+fn lamports_to_sol(lamports: u64) -> f64 {
+    lamports as f64 / 10_f64.powi(9)
+}
+
+/// Converts an on-chain fee rate (parts per million) to a percentage
+pub fn share_fee_percent(share_fee_rate: u64) -> f64 {
+    share_fee_rate as f64 * 100.0 / BONK_FEE_RATE_DENOMINATOR as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BonkBuy, BonkBuyParam, BonkCurveParams, BonkFixedCurve, BonkMintParams, BonkVestingParams,
+        BonkfunMIntInfo,
+    };
+    use solana_sdk::pubkey::Pubkey;
+
+    fn bonk_launch(amount_in: u64) -> LaunchEvent {
+        let key = Pubkey::default();
+        LaunchEvent::Bonk {
+            mint_info: BonkfunMIntInfo {
+                base_mint_param: BonkMintParams {
+                    decimals: 6,
+                    name: "Token".to_string(),
+                    symbol: "TKN".to_string(),
+                    uri: String::new(),
+                },
+                curve_param: BonkCurveParams::Fixed(BonkFixedCurve { supply: 0, total_quote_fund_raising: 0, migrate_type: 0 }),
+                vesting_param: BonkVestingParams { total_locked_amount: 0, cliff_period: 0, unlock_period: 0 },
+            },
+            buy: BonkBuy {
+                payer: key,
+                authority: key,
+                global_config: key,
+                platform_config: key,
+                pool_state: key,
+                user_base_token: key,
+                user_quote_token: key,
+                base_vault: key,
+                quote_vault: key,
+                base_token_mint: key,
+                quote_token_mint: key,
+                base_token_program: key,
+                quote_token_program: key,
+                event_authority: key,
+                program: key,
+            },
+            buy_param: BonkBuyParam { amount_in, minimum_amount_out: 0, share_fee_rate: 0 },
+            tx_id: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn dev_buy_on_either_bound_passes() {
+        let filter = DevBuyFilter { min_lamports: 1_000, max_lamports: 5_000 };
+        assert!(filter.check(&bonk_launch(1_000)).await.is_ok());
+        assert!(filter.check(&bonk_launch(5_000)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn dev_buy_just_outside_the_bounds_is_rejected() {
+        let filter = DevBuyFilter { min_lamports: 1_000, max_lamports: 5_000 };
+        assert!(matches!(
+            filter.check(&bonk_launch(999)).await,
+            Err(FilterRejectReason::DevBuyOutOfRange { amount_lamports: 999 })
+        ));
+        assert!(matches!(
+            filter.check(&bonk_launch(5_001)).await,
+            Err(FilterRejectReason::DevBuyOutOfRange { amount_lamports: 5_001 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn dev_buy_without_a_max_is_unbounded_above() {
+        let filter = DevBuyFilter { min_lamports: 0, max_lamports: u64::MAX };
+        assert!(filter.check(&bonk_launch(0)).await.is_ok());
+        assert!(filter.check(&bonk_launch(u64::MAX)).await.is_ok());
+    }
+
+    #[test]
+    fn dev_buy_bounds_in_sol_convert_to_exact_lamports() {
+        assert_eq!(sol_to_lamports(0.1), 100_000_000);
+        assert_eq!(sol_to_lamports(0.3), 300_000_000);
+        assert_eq!(sol_to_lamports(1.0), 1_000_000_000);
+    }
+
+    fn allowlist(hosts: &[&str]) -> Vec<String> {
+        hosts.iter().map(|host| host.to_string()).collect()
+    }
+
+    #[test]
+    fn metadata_uri_on_an_allowed_host_passes() {
+        let allowed = allowlist(&["ipfs.io"]);
+        assert!(check_metadata_uri("https://ipfs.io/ipfs/abc", &allowed).is_ok());
+        assert!(check_metadata_uri("https://gateway.ipfs.io/ipfs/abc", &allowed).is_ok());
+        assert!(check_metadata_uri("https://example.com/meta.json", &[]).is_ok());
+    }
+
+    #[test]
+    fn metadata_uri_off_the_allowlist_is_refused() {
+        let allowed = allowlist(&["ipfs.io"]);
+        assert!(check_metadata_uri("https://evilipfs.io/meta.json", &allowed).is_err());
+        assert!(check_metadata_uri("https://example.com/meta.json", &allowed).is_err());
+    }
+
+    #[test]
+    fn metadata_uri_with_a_local_name_is_refused() {
+        for uri in [
+            "http://localhost/meta.json",
+            "http://LOCALHOST./meta.json",
+            "http://api.localhost/meta.json",
+            "http://metadata/computeMetadata",
+            "http://metadata.google.internal/computeMetadata",
+            "http://printer.local/meta.json",
+            "http://router.home.arpa/meta.json",
+        ] {
+            assert!(check_metadata_uri(uri, &[]).is_err(), "{} should be refused", uri);
+        }
+    }
+
+    #[test]
+    fn metadata_uri_with_a_private_address_is_refused() {
+        for uri in [
+            "http://127.0.0.1/meta.json",
+            "http://10.0.0.8/meta.json",
+            "http://169.254.169.254/latest/meta-data",
+            "http://100.64.0.1/meta.json",
+            "http://[::1]/meta.json",
+            "http://[fd00::1]/meta.json",
+            "http://[::ffff:192.168.1.1]/meta.json",
+        ] {
+            assert!(check_metadata_uri(uri, &[]).is_err(), "{} should be refused", uri);
+        }
+    }
+
+    #[test]
+    fn metadata_uri_with_another_scheme_is_refused() {
+        assert!(check_metadata_uri("file:///etc/passwd", &[]).is_err());
+        assert!(check_metadata_uri("ftp://example.com/meta.json", &[]).is_err());
+    }
+
+    #[tokio::test]
+    async fn resolved_private_address_is_refused() {
+        let uri = "http://127.0.0.1:8080/meta.json";
+        let url = Url::parse(uri).unwrap();
+        assert!(matches!(
+            resolve_public_addrs(uri, &url).await,
+            Err(FilterRejectReason::MetadataUriNotAllowed { .. })
+        ));
+    }
+
+    fn x_links() -> Vec<String> {
+        vec!["https://x.com/".to_string()]
+    }
+
+    #[test]
+    fn social_link_in_a_top_level_field_passes() {
+        let body = r#"{"name":"Token","twitter":"https://x.com/token"}"#;
+        assert!(check_social_links(body, &x_links(), &[]).is_ok());
+    }
+
+    #[test]
+    fn social_link_under_extensions_passes() {
+        let body = r#"{"name":"Token","extensions":{"twitter":"https://x.com/token"}}"#;
+        assert!(check_social_links(body, &x_links(), &[]).is_ok());
+    }
+
+    #[test]
+    fn link_outside_the_social_fields_is_ignored() {
+        let body = r#"{"name":"Token","description":"follow https://x.com/token","website":"https://token.fun"}"#;
+        assert!(matches!(
+            check_social_links(body, &x_links(), &[]),
+            Err(FilterRejectReason::SocialLinkMissing)
+        ));
+    }
+
+    #[test]
+    fn blank_required_field_is_missing() {
+        let required = vec!["twitter".to_string()];
+        let body = r#"{"twitter":"  ","extensions":{"twitter":""}}"#;
+        assert!(matches!(
+            check_social_links(body, &[], &required),
+            Err(FilterRejectReason::SocialFieldMissing { field }) if field == "twitter"
+        ));
+        assert!(check_social_links(r#"{"extensions":{"twitter":"https://x.com/token"}}"#, &[], &required).is_ok());
+    }
+
+    #[test]
+    fn non_json_metadata_falls_back_to_a_substring_search() {
+        assert!(check_social_links("twitter: https://x.com/token", &x_links(), &[]).is_ok());
+        assert!(check_social_links("no links here", &x_links(), &[]).is_err());
+        assert!(matches!(
+            check_social_links("twitter: https://x.com/token", &x_links(), &["twitter".to_string()]),
+            Err(FilterRejectReason::SocialFieldMissing { .. })
+        ));
+    }
+}
@@ -0,0 +1,135 @@
+/**
+ * 🍯 Honeypot Check Module - Bonk.fun Trading Sniper Bot
+ * 
+ * This module detects tokens that can be bought but not sold. It simulates
+ * a tiny buy, reads how many tokens it would yield, then simulates the same
+ * buy followed by selling half of them. A failing sell marks the launch as
+ * a honeypot.
+ * 
+ * Simulations run at `processed` commitment with the blockhash replaced so
+ * they see the freshest state the RPC node has.
+ * 
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::rpc_config::{RpcSimulateTransactionAccountsConfig, RpcSimulateTransactionConfig};
+use solana_sdk::{
+    commitment_config::CommitmentConfig, instruction::Instruction, message::Message,
+    system_instruction, transaction::Transaction,
+};
+use spl_token_2022::{extension::StateWithExtensions, state::Account as TokenAccount};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+use crate::{
+    BonkBuy, BonkBuyParam, SEND_RPC_CLIENT, build_bonk_buy_ix, build_bonk_sell_ix, chatter_enabled,
+    sync_native_ix,
+};
+
+/// SOL spent by the probe buy (0.0001 SOL)
+const PROBE_BUY_LAMPORTS: u64 = 100_000;
+
+/**
+ * Simulates buying and selling a tiny amount of the launch token
+ * 
+ * `bonk_buy` must already be prepared for the trading wallet (payer and
+ * user token accounts set).
+ * 
+ * @param bonk_buy - Prepared buy accounts
+ * @returns Result<bool, Box<dyn std::error::Error>> - True if the sell simulation succeeds
+ */
+pub async fn simulate_sellability(bonk_buy: &BonkBuy) -> Result<bool, Box<dyn std::error::Error>> {
+    let probe_buy = probe_buy_ixs(bonk_buy)?;
+
+    // First pass: learn how many tokens the probe buy yields
+    let accounts_config = RpcSimulateTransactionAccountsConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        addresses: vec![bonk_buy.user_base_token.to_string()],
+    };
+    let buy_result = simulate(&probe_buy, bonk_buy, Some(accounts_config)).await?;
+    if let Some(err) = buy_result.err {
+        return Err(format!("probe buy simulation failed: {}", err).into());
+    }
+
+    let tokens_out = buy_result
+        .accounts
+        .and_then(|accounts| accounts.into_iter().next().flatten())
+        .and_then(|account| account.decode::<solana_sdk::account::Account>())
+        .and_then(|account| {
+            // Works for both token programs; extensions are ignored
+            StateWithExtensions::<TokenAccount>::unpack(&account.data)
+                .ok()
+                .map(|token| token.base.amount)
+        })
+        .ok_or("probe buy simulation returned no token balance")?;
+
+    if tokens_out == 0 {
+        return Ok(false);
+    }
+
+    // Second pass: the same buy followed by selling half of what it bought
+    let sell_param = BonkBuyParam {
+        amount_in: tokens_out / 2,
+        minimum_amount_out: 0,
+        share_fee_rate: 0,
+    };
+    let mut buy_and_sell = probe_buy;
+    buy_and_sell.push(build_bonk_sell_ix(bonk_buy, &sell_param));
+
+    let sell_result = simulate(&buy_and_sell, bonk_buy, None).await?;
+    if let Some(err) = &sell_result.err {
+        if chatter_enabled() {
+            println!("🍯 Sell simulation failed for {}: {}", bonk_buy.base_token_mint, err);
+        }
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+/// Instructions for a probe buy of `PROBE_BUY_LAMPORTS`
+fn probe_buy_ixs(bonk_buy: &BonkBuy) -> Result<Vec<Instruction>, Box<dyn std::error::Error>> {
+    let buy_param = BonkBuyParam {
+        amount_in: PROBE_BUY_LAMPORTS,
+        minimum_amount_out: 0,
+        share_fee_rate: 0,
+    };
+
+    Ok(vec![
+        create_associated_token_account_idempotent(
+            &bonk_buy.payer,
+            &bonk_buy.payer,
+            &bonk_buy.base_token_mint,
+            &bonk_buy.base_token_program,
+        ),
+        create_associated_token_account_idempotent(
+            &bonk_buy.payer,
+            &bonk_buy.payer,
+            &bonk_buy.quote_token_mint,
+            &bonk_buy.quote_token_program,
+        ),
+        system_instruction::transfer(&bonk_buy.payer, &bonk_buy.user_quote_token, PROBE_BUY_LAMPORTS),
+        sync_native_ix(&bonk_buy.quote_token_program, &bonk_buy.user_quote_token)?,
+        build_bonk_buy_ix(bonk_buy, &buy_param),
+    ])
+}
+
+/// Simulates unsigned instructions against the freshest available state
+async fn simulate(
+    ixs: &[Instruction],
+    bonk_buy: &BonkBuy,
+    accounts: Option<RpcSimulateTransactionAccountsConfig>,
+) -> Result<solana_client::rpc_response::RpcSimulateTransactionResult, Box<dyn std::error::Error>> {
+    let tx = Transaction::new_unsigned(Message::new(ixs, Some(&bonk_buy.payer)));
+    let config = RpcSimulateTransactionConfig {
+        sig_verify: false,
+        replace_recent_blockhash: true,
+        commitment: Some(CommitmentConfig::processed()),
+        accounts,
+        ..Default::default()
+    };
+
+    Ok(SEND_RPC_CLIENT.simulate_transaction_with_config(&tx, config).await?.value)
+}
@@ -0,0 +1,78 @@
+use borsh::BorshSerialize;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_instruction,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{
+    BONK_BUY_IN_DISC, BONK_SELL_IN_DISC, BonkBuy, BonkBuyParam, JITO_TIP_ACCOUNTS, JITO_TIP_LAMPORTS, PRIORITY_FEE,
+    RAYDIUM_LAUNCHPAD_PROGRAM_ID, chatter_enabled, current_priority_fee,
+};
+
+static NEXT_JITO_TIP_ACCOUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Builds the compute budget instructions from the priority fee config.
+pub fn compute_budget_ixs() -> Vec<Instruction> {
+    let (cu, _, _) = *PRIORITY_FEE;
+
+    vec![
+        ComputeBudgetInstruction::set_compute_unit_limit(cu as u32),
+        ComputeBudgetInstruction::set_compute_unit_price(current_priority_fee()),
+    ]
+}
+
+/// Builds a Raydium Launchpad `buy_exact_in` instruction.
+pub fn build_bonk_buy_ix(bonk_buy: &BonkBuy, param: &BonkBuyParam) -> Instruction {
+    launchpad_swap_ix(BONK_BUY_IN_DISC, bonk_buy, param)
+}
+
+/// Builds a Raydium Launchpad `sell_exact_in` instruction.
+/// Takes the same accounts as a buy; `amount_in` is in base token units.
+pub fn build_bonk_sell_ix(bonk_buy: &BonkBuy, param: &BonkBuyParam) -> Instruction {
+    launchpad_swap_ix(BONK_SELL_IN_DISC, bonk_buy, param)
+}
+
+fn launchpad_swap_ix(disc: [u8; 8], bonk_buy: &BonkBuy, param: &BonkBuyParam) -> Instruction {
+    let mut data = disc.to_vec();
+    param
+        .serialize(&mut data)
+        .expect("serializing into a Vec cannot fail");
+
+    let accounts = vec![
+        AccountMeta::new(bonk_buy.payer, true),
+        AccountMeta::new_readonly(bonk_buy.authority, false),
+        AccountMeta::new_readonly(bonk_buy.global_config, false),
+        AccountMeta::new_readonly(bonk_buy.platform_config, false),
+        AccountMeta::new(bonk_buy.pool_state, false),
+        AccountMeta::new(bonk_buy.user_base_token, false),
+        AccountMeta::new(bonk_buy.user_quote_token, false),
+        AccountMeta::new(bonk_buy.base_vault, false),
+        AccountMeta::new(bonk_buy.quote_vault, false),
+        AccountMeta::new_readonly(bonk_buy.base_token_mint, false),
+        AccountMeta::new_readonly(bonk_buy.quote_token_mint, false),
+        AccountMeta::new_readonly(bonk_buy.base_token_program, false),
+        AccountMeta::new_readonly(bonk_buy.quote_token_program, false),
+        AccountMeta::new_readonly(bonk_buy.event_authority, false),
+        AccountMeta::new_readonly(bonk_buy.program, false),
+    ];
+
+    Instruction {
+        program_id: RAYDIUM_LAUNCHPAD_PROGRAM_ID,
+        accounts,
+        data,
+    }
+}
+
+/// Builds a transfer of the configured Jito tip, rotating among the tip accounts.
+pub fn jito_tip_ix(payer: &Pubkey) -> Instruction {
+    let index = NEXT_JITO_TIP_ACCOUNT.fetch_add(1, Ordering::Relaxed) % JITO_TIP_ACCOUNTS.len();
+    let tip_account = JITO_TIP_ACCOUNTS[index];
+
+    if chatter_enabled() {
+        println!("💎 Jito tip of {} lamports to {}", *JITO_TIP_LAMPORTS, tip_account);
+    }
+    system_instruction::transfer(payer, &tip_account, *JITO_TIP_LAMPORTS)
+}
@@ -1,7 +1,25 @@
+pub mod confirm_gate;
+pub mod curve;
+pub mod filters;
+pub mod honeypot;
 pub mod instructions;
 pub mod parse;
+pub mod pool;
+pub mod positions;
 pub mod process_update_grpc;
+pub mod seen_signatures;
+pub mod token_program;
+pub mod trade_queue;
 
+pub use confirm_gate::*;
+pub use curve::*;
+pub use filters::*;
+pub use honeypot::*;
 pub use instructions::*;
 pub use parse::*;
+pub use pool::*;
+pub use positions::*;
 pub use process_update_grpc::*;
+pub use seen_signatures::*;
+pub use token_program::*;
+pub use trade_queue::*;
@@ -1,99 +1,159 @@
 use crate::{
-    read_string, read_u64_le, BonkConstantCurve, BonkCurveParams, BonkFixedCurve, BonkLinearCurve, BonkMintParams, BonkVestingParams, BonkfunMIntInfo
+    read_field, read_string_body, read_string_len, read_u64_le, read_u8, BonkBuyParam, BonkConstantCurve, BonkCurveParams, BonkFixedCurve, BonkLinearCurve, BonkMintParams, BonkVestingParams, BonkfunMIntInfo, ParseError
 };
 
-pub fn parse_mint_params(data: &[u8], offset: &mut usize) -> BonkMintParams {
-    let decimals = data[*offset];
-    *offset += 1;
+/// Reads a length-prefixed string, naming the prefix and the body separately in errors
+fn read_named_string(
+    data: &[u8],
+    offset: &mut usize,
+    len_field: &'static str,
+    field: &'static str,
+) -> Result<String, ParseError> {
+    let len = read_field(len_field, offset, |o| read_string_len(data, o))?;
+    read_field(field, offset, |o| read_string_body(data, o, len))
+}
+
+pub fn parse_mint_params(data: &[u8], offset: &mut usize) -> Result<BonkMintParams, ParseError> {
+    let decimals = read_field("mint.decimals", offset, |o| read_u8(data, o))?;
 
-    let name = read_string(data, offset);
-    let symbol = read_string(data, offset);
-    let uri = read_string(data, offset);
+    let name = read_named_string(data, offset, "mint.name.len", "mint.name")?;
+    let symbol = read_named_string(data, offset, "mint.symbol.len", "mint.symbol")?;
+    let uri = read_named_string(data, offset, "mint.uri.len", "mint.uri")?;
 
-    BonkMintParams {
+    Ok(BonkMintParams {
         decimals,
         name,
         symbol,
         uri,
-    }
+    })
 }
 
-pub fn parse_constant_curve(data: &[u8], offset: &mut usize) -> BonkConstantCurve {
-    let supply = read_u64_le(data, offset);
-    let total_base_sell = read_u64_le(data, offset);
-    let total_quote_fund_raising = read_u64_le(data, offset);
-    let migrate_type = data[*offset];
-    *offset += 1;
+pub fn parse_constant_curve(data: &[u8], offset: &mut usize) -> Result<BonkConstantCurve, ParseError> {
+    let supply = read_field("curve.constant.supply", offset, |o| read_u64_le(data, o))?;
+    let total_base_sell = read_field("curve.constant.total_base_sell", offset, |o| read_u64_le(data, o))?;
+    let total_quote_fund_raising =
+        read_field("curve.constant.total_quote_fund_raising", offset, |o| read_u64_le(data, o))?;
+    let migrate_type = read_field("curve.constant.migrate_type", offset, |o| read_u8(data, o))?;
 
-    BonkConstantCurve {
+    Ok(BonkConstantCurve {
         supply,
         total_base_sell,
         total_quote_fund_raising,
         migrate_type,
-    }
+    })
 }
 
-pub fn parse_fixed_curve(data: &[u8], offset: &mut usize) -> BonkFixedCurve {
-    let supply = read_u64_le(data, offset);
-    let total_quote_fund_raising = read_u64_le(data, offset);
-    let migrate_type = data[*offset];
-    *offset += 1;
+pub fn parse_fixed_curve(data: &[u8], offset: &mut usize) -> Result<BonkFixedCurve, ParseError> {
+    let supply = read_field("curve.fixed.supply", offset, |o| read_u64_le(data, o))?;
+    let total_quote_fund_raising =
+        read_field("curve.fixed.total_quote_fund_raising", offset, |o| read_u64_le(data, o))?;
+    let migrate_type = read_field("curve.fixed.migrate_type", offset, |o| read_u8(data, o))?;
 
-    BonkFixedCurve {
+    Ok(BonkFixedCurve {
         supply,
         total_quote_fund_raising,
         migrate_type,
-    }
+    })
 }
 
-pub fn parse_linear_curve(data: &[u8], offset: &mut usize) -> BonkLinearCurve {
-    let supply = read_u64_le(data, offset);
-    let total_quote_fund_raising = read_u64_le(data, offset);
-    let migrate_type = data[*offset];
-    *offset += 1;
+pub fn parse_linear_curve(data: &[u8], offset: &mut usize) -> Result<BonkLinearCurve, ParseError> {
+    let supply = read_field("curve.linear.supply", offset, |o| read_u64_le(data, o))?;
+    let total_quote_fund_raising =
+        read_field("curve.linear.total_quote_fund_raising", offset, |o| read_u64_le(data, o))?;
+    let migrate_type = read_field("curve.linear.migrate_type", offset, |o| read_u8(data, o))?;
 
-    BonkLinearCurve {
+    Ok(BonkLinearCurve {
         supply,
         total_quote_fund_raising,
         migrate_type,
-    }
+    })
 }
 
-pub fn parse_curve_params(data: &[u8], offset: &mut usize) -> BonkCurveParams {
-    let curve_type = data[*offset];
-    *offset += 1;
+pub fn parse_curve_params(data: &[u8], offset: &mut usize) -> Result<BonkCurveParams, ParseError> {
+    let curve_type_offset = *offset;
+    let curve_type = read_field("curve.type", offset, |o| read_u8(data, o))?;
 
     match curve_type {
-        0 => BonkCurveParams::Constant(parse_constant_curve(data, offset)),
-        1 => BonkCurveParams::Fixed(parse_fixed_curve(data, offset)),
-        2 => BonkCurveParams::Linear(parse_linear_curve(data, offset)),
+        0 => Ok(BonkCurveParams::Constant(parse_constant_curve(data, offset)?)),
+        1 => Ok(BonkCurveParams::Fixed(parse_fixed_curve(data, offset)?)),
+        2 => Ok(BonkCurveParams::Linear(parse_linear_curve(data, offset)?)),
         // Add other curve types here
-        _ => panic!("Unknown curve type"),
+        _ => Err(ParseError::in_field(curve_type_offset, "curve.type", ParseError::UnknownCurveType(curve_type))),
     }
 }
 
-pub fn parse_vesting_params(data: &[u8], offset: &mut usize) -> BonkVestingParams {
-    let total_locked_amount = read_u64_le(data, offset);
-    let cliff_period = read_u64_le(data, offset);
-    let unlock_period = read_u64_le(data, offset);
+pub fn parse_vesting_params(data: &[u8], offset: &mut usize) -> Result<BonkVestingParams, ParseError> {
+    let total_locked_amount = read_field("vesting.total_locked_amount", offset, |o| read_u64_le(data, o))?;
+    let cliff_period = read_field("vesting.cliff_period", offset, |o| read_u64_le(data, o))?;
+    let unlock_period = read_field("vesting.unlock_period", offset, |o| read_u64_le(data, o))?;
 
-    BonkVestingParams {
+    Ok(BonkVestingParams {
         total_locked_amount,
         cliff_period,
         unlock_period,
-    }
+    })
 }
 
-pub fn parse_bonk_initialize_params(data: &[u8]) -> BonkfunMIntInfo {
+pub fn parse_bonk_initialize_params(data: &[u8]) -> Result<BonkfunMIntInfo, ParseError> {
     let mut offset: usize = 8;
 
-    let base_mint_param = parse_mint_params(data, &mut offset);
-    let curve_param = parse_curve_params(data, &mut offset);
-    let vesting_param = parse_vesting_params(data, &mut offset);
+    let base_mint_param = parse_mint_params(data, &mut offset)?;
+    let curve_param = parse_curve_params(data, &mut offset)?;
+    let vesting_param = parse_vesting_params(data, &mut offset)?;
 
-    BonkfunMIntInfo {
+    Ok(BonkfunMIntInfo {
         base_mint_param,
         curve_param,
         vesting_param,
+    })
+}
+
+/// Decodes `buy_exact_in` arguments with the same little-endian readers as
+/// the initialize path, so both share one decoder for launchpad data
+pub fn parse_bonk_buy_params(data: &[u8]) -> Result<BonkBuyParam, ParseError> {
+    let mut offset: usize = 8;
+
+    let amount_in = read_field("buy.amount_in", &mut offset, |o| read_u64_le(data, o))?;
+    let minimum_amount_out = read_field("buy.minimum_amount_out", &mut offset, |o| read_u64_le(data, o))?;
+    let share_fee_rate = read_field("buy.share_fee_rate", &mut offset, |o| read_u64_le(data, o))?;
+
+    Ok(BonkBuyParam {
+        amount_in,
+        minimum_amount_out,
+        share_fee_rate,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::bonk_buy_data;
+    use borsh::BorshDeserialize;
+
+    #[test]
+    fn buy_params_decode_the_same_as_borsh() {
+        for (amount_in, minimum_amount_out, share_fee_rate) in [
+            (0, 0, 0),
+            (1_000_000_000, 35_000_000_000_000, 2_500),
+            (u64::MAX, 1, u64::MAX - 1),
+        ] {
+            let data = bonk_buy_data(&BonkBuyParam { amount_in, minimum_amount_out, share_fee_rate });
+
+            let manual = parse_bonk_buy_params(&data).unwrap();
+            let borsh = BonkBuyParam::deserialize(&mut &data[8..]).unwrap();
+
+            assert_eq!(manual.amount_in, borsh.amount_in);
+            assert_eq!(manual.minimum_amount_out, borsh.minimum_amount_out);
+            assert_eq!(manual.share_fee_rate, borsh.share_fee_rate);
+        }
+    }
+
+    #[test]
+    fn truncated_buy_params_are_rejected() {
+        let data = bonk_buy_data(&BonkBuyParam { amount_in: 1, minimum_amount_out: 2, share_fee_rate: 3 });
+        let truncated = &data[..data.len() - 1];
+
+        assert!(parse_bonk_buy_params(truncated).is_err());
+        assert!(BonkBuyParam::deserialize(&mut &truncated[8..]).is_err());
     }
 }
@@ -17,15 +17,16 @@
  */
 
 use crate::{
-    BONK_BUY_IN_DISC, BONK_INIT_DISC, BonkBuy, BonkBuyParam, BonkfunMIntInfo, MoonBuy,
+    BONK_BUY_IN_DISC, BONK_INIT_DISC, BONK_MIGRATE_AMM_DISC, BONK_MIGRATE_CPSWAP_DISC, BonkBuy,
+    BonkBuyParam, BonkMigration, BonkfunMIntInfo, MIGRATE_TYPE_AMM, MIGRATE_TYPE_CPSWAP, MoonBuy,
     MoonBuyParamWrapper, MoonshotMintInfo, PumpfunBuy, PumpfunBuyParam, PumpfunMintInfo,
-    RAYDIUM_LAUNCHPAD_PROGRAM_ID, parse_bonk_initialize_params,
+    CONFIG, Launchpad, LogLevel, ParseError, chatter_enabled, increment_counter, log_enabled,
+    parse_bonk_buy_params, parse_bonk_initialize_params,
 };
-use borsh::BorshDeserialize;
 use solana_sdk::pubkey::Pubkey;
 use yellowstone_grpc_proto::{
     geyser::{SubscribeUpdate, subscribe_update::UpdateOneof},
-    prelude::CompiledInstruction,
+    prelude::{CompiledInstruction, Message, TransactionStatusMeta},
 };
 
 /**
@@ -36,11 +37,23 @@ use yellowstone_grpc_proto::{
  * - Compiled instructions
  * - Transaction signature/ID
  * 
+ * An update without meta is skipped unless `[grpc].parse_without_meta`
+ * is set and the message uses no lookup tables, in which case the static
+ * account keys are already the full list.
+ * 
  * @param update - gRPC transaction update
  * @returns Option<(Vec<Pubkey>, Vec<CompiledInstruction>, String)> - Parsed data or None
  */
 pub fn extract_transaction_data(
     update: &SubscribeUpdate,
+) -> Option<(Vec<Pubkey>, Vec<CompiledInstruction>, String)> {
+    extract_transaction_data_with(update, CONFIG.grpc.parse_without_meta)
+}
+
+/// `extract_transaction_data` with the `[grpc].parse_without_meta` setting passed in
+fn extract_transaction_data_with(
+    update: &SubscribeUpdate,
+    parse_without_meta: bool,
 ) -> Option<(Vec<Pubkey>, Vec<CompiledInstruction>, String)> {
     // Extract transaction update from enum
     let transaction_update = match &update.update_oneof {
@@ -54,74 +67,217 @@ pub fn extract_transaction_data(
     // Safely extract nested transaction data with error handling
     let tx_info = transaction_update.transaction.as_ref()?;
     let transaction = tx_info.transaction.as_ref()?;
-    let meta = tx_info.meta.as_ref()?;
     let tx_msg = transaction.message.as_ref()?;
 
+    // Parse transaction signature/ID
+    let signature = &tx_info.signature;
+    let tx_id = bs58::encode(signature).into_string();
+
+    // Resolve the full account key list (static keys + address lookup tables)
+    let account_keys = match tx_info.meta.as_ref() {
+        Some(meta) => resolve_account_keys(tx_msg, meta, &tx_id)?,
+        None => resolve_static_account_keys(tx_msg, &tx_id, parse_without_meta)?,
+    };
+
+    // Extract compiled instructions
+    let ixs: Vec<CompiledInstruction> = tx_msg.instructions.clone();
+
+    Some((account_keys, ixs, tx_id))
+}
+
+/**
+ * Returns the slot a transaction update was produced in
+ * 
+ * @param update - gRPC update
+ * @returns Option<u64> - Slot for transaction updates, None otherwise
+ */
+pub fn update_slot(update: &SubscribeUpdate) -> Option<u64> {
+    match &update.update_oneof {
+        Some(UpdateOneof::Transaction(tx_update)) => Some(tx_update.slot),
+        _ => None,
+    }
+}
+
+/**
+ * Returns whether a transaction update failed on-chain
+ * 
+ * Only seen with `[grpc].include_failed`; such transactions changed
+ * nothing, so nothing in them may be acted on.
+ * 
+ * @param update - gRPC update
+ * @returns bool - True for a transaction update whose meta carries an error
+ */
+pub fn update_failed(update: &SubscribeUpdate) -> bool {
+    match &update.update_oneof {
+        Some(UpdateOneof::Transaction(tx_update)) => tx_update
+            .transaction
+            .as_ref()
+            .and_then(|tx_info| tx_info.meta.as_ref())
+            .is_some_and(|meta| meta.err.is_some()),
+        _ => false,
+    }
+}
+
+/**
+ * Resolves the full account key list referenced by compiled instructions
+ * 
+ * Compiled instruction indices address one flat list, which for v0
+ * transactions is built by the runtime in this exact order:
+ * 1. Static `account_keys` from the message
+ * 2. All writable addresses loaded from lookup tables, in the order the
+ *    `address_table_lookups` appear and then by `writable_indexes`
+ * 3. All readonly addresses loaded from lookup tables, in the same order
+ *    by `readonly_indexes`
+ * 
+ * Geyser fills `meta.loaded_writable_addresses` and
+ * `meta.loaded_readonly_addresses` already expanded in that order, so the
+ * concatenation is correct as long as their lengths match the lookup index
+ * counts in the message. Anything else means the meta doesn't describe this
+ * message and every index past the static keys would map to the wrong pubkey,
+ * so the transaction is rejected instead of guessed at.
+ * 
+ * @param tx_msg - Transaction message
+ * @param meta - Transaction status metadata carrying the loaded addresses
+ * @param tx_id - Transaction ID for logging
+ * @returns Option<Vec<Pubkey>> - Ordered account keys or None
+ */
+pub fn resolve_account_keys(
+    tx_msg: &Message,
+    meta: &TransactionStatusMeta,
+    tx_id: &str,
+) -> Option<Vec<Pubkey>> {
+    let expected_writable: usize = tx_msg
+        .address_table_lookups
+        .iter()
+        .map(|lookup| lookup.writable_indexes.len())
+        .sum();
+    let expected_readonly: usize = tx_msg
+        .address_table_lookups
+        .iter()
+        .map(|lookup| lookup.readonly_indexes.len())
+        .sum();
+
+    if meta.loaded_writable_addresses.len() != expected_writable
+        || meta.loaded_readonly_addresses.len() != expected_readonly
+    {
+        println!(
+            "⚠️ Loaded address mismatch for TX {}: expected {} writable / {} readonly, got {} / {}",
+            tx_id,
+            expected_writable,
+            expected_readonly,
+            meta.loaded_writable_addresses.len(),
+            meta.loaded_readonly_addresses.len()
+        );
+        return None;
+    }
+
+    // A message always lists at least its fee payer, so no static keys means
+    // there's nothing to resolve rather than a decode problem
+    if tx_msg.account_keys.is_empty() {
+        if log_enabled(LogLevel::Debug) {
+            println!("⚠️ Skipping TX {}: message has no static account keys", tx_id);
+        }
+        return None;
+    }
+
     // Parse account keys from transaction message
-    let mut account_keys: Vec<Pubkey> = parse_account_keys(&tx_msg.account_keys)?;
+    let mut account_keys: Vec<Pubkey> = parse_account_keys(&tx_msg.account_keys, tx_id)?;
 
     // Append loaded writable addresses from transaction metadata
-    account_keys.extend(parse_loaded_addresses(&meta.loaded_writable_addresses)?);
+    account_keys.extend(parse_loaded_addresses(&meta.loaded_writable_addresses, tx_id)?);
 
     // Append loaded readonly addresses from transaction metadata
-    account_keys.extend(parse_loaded_addresses(&meta.loaded_readonly_addresses)?);
+    account_keys.extend(parse_loaded_addresses(&meta.loaded_readonly_addresses, tx_id)?);
 
-    // Extract compiled instructions
-    let ixs: Vec<CompiledInstruction> = tx_msg.instructions.clone();
+    Some(account_keys)
+}
 
-    // Parse transaction signature/ID
-    let signature = &tx_info.signature;
-    let tx_id = bs58::encode(signature).into_string();
+/**
+ * Resolves account keys for a transaction update that carries no meta
+ * 
+ * Updates at `processed` can arrive before their meta is filled in. Without
+ * it the loaded addresses are unknown, so only a message with no
+ * `address_table_lookups` can be resolved: its static keys are every key
+ * the instructions can index.
+ * 
+ * @param tx_msg - Transaction message
+ * @param tx_id - Transaction ID for logging
+ * @param parse_without_meta - Whether `[grpc].parse_without_meta` is set
+ * @returns Option<Vec<Pubkey>> - Static account keys or None
+ */
+fn resolve_static_account_keys(tx_msg: &Message, tx_id: &str, parse_without_meta: bool) -> Option<Vec<Pubkey>> {
+    if !parse_without_meta || !tx_msg.address_table_lookups.is_empty() {
+        increment_counter("meta_missing_skipped");
+        if log_enabled(LogLevel::Debug) {
+            println!("⚠️ Skipping TX {}: update has no meta", tx_id);
+        }
+        return None;
+    }
 
-    Some((account_keys, ixs, tx_id))
+    if tx_msg.account_keys.is_empty() {
+        if log_enabled(LogLevel::Debug) {
+            println!("⚠️ Skipping TX {}: message has no static account keys", tx_id);
+        }
+        return None;
+    }
+
+    let account_keys = parse_account_keys(&tx_msg.account_keys, tx_id)?;
+    increment_counter("meta_missing_parsed");
+    if log_enabled(LogLevel::Debug) {
+        println!("🧾 Parsing TX {} without meta from {} static keys", tx_id, account_keys.len());
+    }
+    Some(account_keys)
 }
 
 /**
  * Parses account keys from raw bytes
  * 
+ * An empty list parses to an empty Vec; only a key that fails to decode
+ * yields None.
+ * 
  * @param account_keys_raw - Raw account key bytes
+ * @param tx_id - Transaction ID for logging
  * @returns Option<Vec<Pubkey>> - Parsed public keys or None
  */
-fn parse_account_keys(account_keys_raw: &[Vec<u8>]) -> Option<Vec<Pubkey>> {
-    let mut account_keys = Vec::new();
-    
-    for key_bytes in account_keys_raw {
-        match Pubkey::try_from(key_bytes.as_slice()) {
-            Ok(pubkey) => account_keys.push(pubkey),
-            Err(e) => {
-                eprintln!("⚠️ Failed to parse account key: {}", e);
-                // Continue parsing other keys
-            }
-        }
-    }
-    
-    if account_keys.is_empty() {
-        None
-    } else {
-        Some(account_keys)
-    }
+fn parse_account_keys(account_keys_raw: &[Vec<u8>], tx_id: &str) -> Option<Vec<Pubkey>> {
+    parse_pubkeys(account_keys_raw, "account key", tx_id)
 }
 
 /**
  * Parses loaded addresses from transaction metadata
  * 
+ * Same contract as `parse_account_keys`: empty is fine, undecodable is None.
+ * 
  * @param loaded_addresses - Raw loaded address bytes
+ * @param tx_id - Transaction ID for logging
  * @returns Option<Vec<Pubkey>> - Parsed public keys or None
  */
-fn parse_loaded_addresses(loaded_addresses: &[Vec<u8>]) -> Option<Vec<Pubkey>> {
-    let mut addresses = Vec::new();
-    
-    for address_bytes in loaded_addresses {
-        match Pubkey::try_from(address_bytes.as_slice()) {
-            Ok(pubkey) => addresses.push(pubkey),
+fn parse_loaded_addresses(loaded_addresses: &[Vec<u8>], tx_id: &str) -> Option<Vec<Pubkey>> {
+    parse_pubkeys(loaded_addresses, "loaded address", tx_id)
+}
+
+fn parse_pubkeys(raw: &[Vec<u8>], kind: &str, tx_id: &str) -> Option<Vec<Pubkey>> {
+    let mut keys = Vec::with_capacity(raw.len());
+
+    for (index, key_bytes) in raw.iter().enumerate() {
+        match Pubkey::try_from(key_bytes.as_slice()) {
+            Ok(pubkey) => keys.push(pubkey),
             Err(e) => {
-                eprintln!("⚠️ Failed to parse loaded address: {}", e);
-                // Continue parsing other addresses
+                // Skipping a key would shift every index after it, so drop the whole TX
+                println!(
+                    "⚠️ Skipping TX {}: failed to parse {} {} ({} bytes): {}",
+                    tx_id,
+                    kind,
+                    index,
+                    key_bytes.len(),
+                    e
+                );
+                return None;
             }
         }
     }
-    
-    Some(addresses)
+
+    Some(keys)
 }
 
 /**
@@ -130,11 +286,17 @@ fn parse_loaded_addresses(loaded_addresses: &[Vec<u8>]) -> Option<Vec<Pubkey>> {
  * This function processes compiled instructions to detect:
  * - Bonk.fun token initialization events
  * - Bonk.fun buy transactions
+ * - Bonk.fun pool migrations to an AMM (graduations)
  * - Trading parameters and account structures
  * 
+ * Only the first `[filter].max_instructions_scanned` instructions are
+ * parsed, so a jumbo transaction can't stall the hot path. With
+ * `[filter].require_same_tx_init`, a buy is dropped unless the same
+ * transaction initializes its mint.
+ * 
  * @param ixs - Compiled instructions from transaction
  * @param account_keys - Account keys involved in transaction
- * @returns (Option<BonkfunMIntInfo>, Option<BonkBuy>, Option<BonkBuyParam>) - Trading data
+ * @returns (Option<BonkfunMIntInfo>, Option<BonkBuy>, Option<BonkBuyParam>, Option<BonkMigration>) - Trading data
  */
 pub fn trade_info(
     ixs: Vec<CompiledInstruction>,
@@ -143,41 +305,137 @@ pub fn trade_info(
     Option<BonkfunMIntInfo>,
     Option<BonkBuy>,
     Option<BonkBuyParam>,
+    Option<BonkMigration>,
+) {
+    trade_info_with(
+        ixs,
+        account_keys,
+        CONFIG.filter.require_same_tx_init,
+        CONFIG.filter.max_instructions_scanned,
+    )
+}
+
+/// `trade_info` with `require_same_tx_init` and `max_instructions_scanned` passed in instead of read from the config
+fn trade_info_with(
+    ixs: Vec<CompiledInstruction>,
+    account_keys: Vec<Pubkey>,
+    require_same_tx_init: bool,
+    max_scanned: usize,
+) -> (
+    Option<BonkfunMIntInfo>,
+    Option<BonkBuy>,
+    Option<BonkBuyParam>,
+    Option<BonkMigration>,
 ) {
     let mut bonk_mint: Option<BonkfunMIntInfo> = None;
     let mut bonk_buy: Option<BonkBuy> = None;
     let mut bonk_buy_param: Option<BonkBuyParam> = None;
+    let mut bonk_migration: Option<BonkMigration> = None;
+    let mut init_mint: Option<Pubkey> = None;
 
     // Process each instruction in the transaction
     for (ix_index, ix) in ixs.iter().enumerate() {
+        if max_scanned > 0 && ix_index >= max_scanned {
+            increment_counter("instruction_scan_truncated");
+            if chatter_enabled() {
+                println!(
+                    "✂️ Stopped scanning after {} of {} instructions (max_instructions_scanned)",
+                    max_scanned,
+                    ixs.len()
+                );
+            }
+            break;
+        }
+
         // Validate instruction data length
         if ix.data.len() < 8 {
-            eprintln!("⚠️ Instruction {} has insufficient data length", ix_index);
+            if log_enabled(LogLevel::Debug) {
+                println!("⚠️ Instruction {} has insufficient data length", ix_index);
+            }
             continue;
         }
 
-        // Check if this is a Bonk.fun program instruction
+        // Resolve the program that owns this instruction
         let program_id = match account_keys.get(ix.program_id_index as usize) {
             Some(id) => id,
             None => {
-                eprintln!("⚠️ Invalid program ID index: {}", ix.program_id_index);
+                println!("⚠️ Invalid program ID index: {}", ix.program_id_index);
                 continue;
             }
         };
 
+        // Discriminators are only unique per program (Pump.fun and Moonshot
+        // share their buy bytes), so route by the owning program first
+        match Launchpad::for_program(program_id) {
+            Some(Launchpad::Bonk) => {}
+            // Pump.fun and Moonshot instructions aren't parsed yet
+            Some(Launchpad::Pump) | Some(Launchpad::Moonshot) | None => continue,
+        }
+
         // Process Bonk.fun initialization instruction
-        if ix.data.starts_with(&BONK_INIT_DISC) && (*program_id == RAYDIUM_LAUNCHPAD_PROGRAM_ID) {
+        if ix.data.starts_with(&BONK_INIT_DISC) {
             bonk_mint = parse_bonk_initialization_instruction(ix, ix_index);
+            init_mint = ix
+                .accounts
+                .get(BONK_INIT_BASE_MINT_INDEX)
+                .and_then(|&index| account_keys.get(index as usize))
+                .copied();
         }
         // Process Bonk.fun buy instruction
-        else if ix.data.starts_with(&BONK_BUY_IN_DISC) && (*program_id == RAYDIUM_LAUNCHPAD_PROGRAM_ID) {
-            let (buy, param) = parse_bonk_buy_instruction(ix, &account_keys, ix_index);
-            bonk_buy = buy;
-            bonk_buy_param = param;
+        else if ix.data.starts_with(&BONK_BUY_IN_DISC) {
+            match parse_bonk_buy_instruction(ix, &account_keys) {
+                Ok((buy, param)) => {
+                    if chatter_enabled() {
+                        println!("🎯 Bonk.fun buy instruction detected in instruction {}", ix_index);
+                    }
+                    bonk_buy = Some(buy);
+                    bonk_buy_param = Some(param);
+                }
+                Err(e) => {
+                    eprintln!("❌ Failed to parse Bonk.fun buy in instruction {}: {}", ix_index, e);
+                }
+            }
+        }
+        // Process Bonk.fun migration (graduation) instruction
+        else if let Some(migrate_type) = migrate_type_for(&ix.data) {
+            match extract_bonk_migration_accounts(ix, &account_keys, migrate_type) {
+                Ok(migration) => {
+                    if chatter_enabled() {
+                        println!("🎓 Bonk.fun migration detected in instruction {}", ix_index);
+                    }
+                    bonk_migration = Some(migration);
+                }
+                Err(e) => {
+                    eprintln!("❌ Failed to parse Bonk.fun migration in instruction {}: {}", ix_index, e);
+                }
+            }
+        }
+    }
+
+    // A buy only counts as a first-block snipe if its mint was initialized right here
+    if let Some(buy) = &bonk_buy {
+        if require_same_tx_init && init_mint != Some(buy.base_token_mint) {
+            increment_counter("buy_without_init_skipped");
+            if log_enabled(LogLevel::Debug) {
+                println!("⏭️ Ignoring buy of {}: its mint wasn't initialized in this transaction", buy.base_token_mint);
+            }
+            bonk_buy = None;
+            bonk_buy_param = None;
         }
     }
 
-    (bonk_mint, bonk_buy, bonk_buy_param)
+    (bonk_mint, bonk_buy, bonk_buy_param, bonk_migration)
+}
+
+/// Maps a migrate instruction's discriminator to the `migrate_type` it performs
+fn migrate_type_for(data: &[u8]) -> Option<u8> {
+    if data.starts_with(&BONK_MIGRATE_AMM_DISC) {
+        Some(MIGRATE_TYPE_AMM)
+    } else if data.starts_with(&BONK_MIGRATE_CPSWAP_DISC) {
+        Some(MIGRATE_TYPE_CPSWAP)
+    } else {
+        None
+    }
 }
 
 /**
@@ -193,7 +451,9 @@ fn parse_bonk_initialization_instruction(
 ) -> Option<BonkfunMIntInfo> {
     match parse_bonk_initialize_params(&ix.data) {
         Ok(mint_data) => {
-            println!("🎯 Bonk.fun initialization detected in instruction {}", ix_index);
+            if chatter_enabled() {
+                println!("🎯 Bonk.fun initialization detected in instruction {}", ix_index);
+            }
             Some(mint_data)
         }
         Err(e) => {
@@ -203,48 +463,34 @@ fn parse_bonk_initialization_instruction(
     }
 }
 
+/// Position of the base mint in a Raydium Launchpad `initialize` instruction
+const BONK_INIT_BASE_MINT_INDEX: usize = 6;
+
+/// Accounts in a Raydium Launchpad `buy_exact_in` instruction
+const BONK_BUY_ACCOUNT_COUNT: usize = 15;
+
 /**
  * Parses Bonk.fun buy instruction
  * 
+ * Short account lists and out-of-range account indices come back as
+ * errors, never panics; `trade_info` then reports no buy, so the
+ * transaction is skipped rather than traded on garbage accounts.
+ * 
  * @param ix - Compiled instruction
  * @param account_keys - Account keys involved in transaction
- * @param ix_index - Instruction index for logging
- * @returns (Option<BonkBuy>, Option<BonkBuyParam>) - Parsed buy data
+ * @returns Result<(BonkBuy, BonkBuyParam), ParseError> - Parsed buy data or error
  */
-fn parse_bonk_buy_instruction(
+pub fn parse_bonk_buy_instruction(
     ix: &CompiledInstruction,
     account_keys: &[Pubkey],
-    ix_index: usize,
-) -> (Option<BonkBuy>, Option<BonkBuyParam>) {
-    // Validate account count for Bonk.fun buy instruction
-    if ix.accounts.len() < 15 {
-        eprintln!("❌ Invalid Bonk.fun buy account layout in instruction {}: expected 15, got {}", 
-                 ix_index, ix.accounts.len());
-        return (None, None);
-    }
-
-    // Extract account keys with bounds checking
-    let bonk_fun_buy = match extract_bonk_buy_accounts(ix, account_keys) {
-        Ok(buy) => buy,
-        Err(e) => {
-            eprintln!("❌ Failed to extract Bonk.fun buy accounts in instruction {}: {}", ix_index, e);
-            return (None, None);
-        }
-    };
+) -> Result<(BonkBuy, BonkBuyParam), ParseError> {
+    // Extract account keys with count and bounds checking
+    let bonk_fun_buy = extract_bonk_buy_accounts(ix, account_keys)?;
 
     // Parse buy parameters
-    let bonk_buy_param = match BonkBuyParam::deserialize(&mut &ix.data[8..]) {
-        Ok(param) => {
-            println!("🎯 Bonk.fun buy instruction detected in instruction {}", ix_index);
-            Some(param)
-        }
-        Err(e) => {
-            eprintln!("❌ Failed to parse Bonk.fun buy parameters in instruction {}: {}", ix_index, e);
-            None
-        }
-    };
+    let bonk_buy_param = parse_bonk_buy_params(&ix.data)?;
 
-    (Some(bonk_fun_buy), bonk_buy_param)
+    Ok((bonk_fun_buy, bonk_buy_param))
 }
 
 /**
@@ -252,16 +498,27 @@ fn parse_bonk_buy_instruction(
  * 
  * @param ix - Compiled instruction
  * @param account_keys - All account keys in transaction
- * @returns Result<BonkBuy, String> - Parsed buy structure or error
+ * @returns Result<BonkBuy, ParseError> - Parsed buy structure or error
  */
 fn extract_bonk_buy_accounts(
     ix: &CompiledInstruction,
     account_keys: &[Pubkey],
-) -> Result<BonkBuy, String> {
+) -> Result<BonkBuy, ParseError> {
+    // Validate account count, so the fixed positions below always exist
+    if ix.accounts.len() < BONK_BUY_ACCOUNT_COUNT {
+        return Err(ParseError::TooFewAccounts {
+            expected: BONK_BUY_ACCOUNT_COUNT,
+            actual: ix.accounts.len(),
+        });
+    }
+
     // Validate account indices
-    for (i, &account_index) in ix.accounts.iter().enumerate() {
+    for &account_index in &ix.accounts {
         if account_index as usize >= account_keys.len() {
-            return Err(format!("Account index {} out of bounds (max: {})", account_index, account_keys.len() - 1));
+            return Err(ParseError::AccountIndexOutOfBounds {
+                index: account_index as usize,
+                len: account_keys.len(),
+            });
         }
     }
 
@@ -283,3 +540,254 @@ fn extract_bonk_buy_accounts(
         program: account_keys[ix.accounts[14] as usize],
     })
 }
+
+/**
+ * Extracts account keys for a Bonk.fun migrate instruction
+ * 
+ * Both `migrate_to_amm` and `migrate_to_cpswap` start with the payer,
+ * base mint and quote mint, which is all we need to match a position.
+ * 
+ * @param ix - Compiled instruction
+ * @param account_keys - All account keys in transaction
+ * @param migrate_type - Target the pool migrates to
+ * @returns Result<BonkMigration, ParseError> - Parsed migration or error
+ */
+fn extract_bonk_migration_accounts(
+    ix: &CompiledInstruction,
+    account_keys: &[Pubkey],
+    migrate_type: u8,
+) -> Result<BonkMigration, ParseError> {
+    if ix.accounts.len() < 3 {
+        return Err(ParseError::TooFewAccounts {
+            expected: 3,
+            actual: ix.accounts.len(),
+        });
+    }
+
+    let key = |position: usize| {
+        let index = ix.accounts[position] as usize;
+        account_keys.get(index).copied().ok_or(ParseError::AccountIndexOutOfBounds {
+            index,
+            len: account_keys.len(),
+        })
+    };
+
+    Ok(BonkMigration {
+        payer: key(0)?,
+        base_mint: key(1)?,
+        quote_mint: key(2)?,
+        migrate_type,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MOON_BUY_DISC, MOONSHOT_PROGRAM_ID, PUMP_BUY_DISC, PUMP_FUN_PROGRAM_ID, RAYDIUM_LAUNCHPAD_PROGRAM_ID};
+    use crate::test_support::TransactionUpdateBuilder;
+
+    fn unique_keys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    fn buy_ix(program_id_index: u32, accounts: Vec<u8>) -> CompiledInstruction {
+        let mut data = BONK_BUY_IN_DISC.to_vec();
+        for value in [1_000_000u64, 0, 0] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        CompiledInstruction {
+            program_id_index,
+            accounts,
+            data,
+        }
+    }
+
+    #[test]
+    fn buy_with_fourteen_accounts_is_rejected() {
+        let account_keys = unique_keys(20);
+        let ix = buy_ix(0, (0..14).collect());
+
+        assert_eq!(
+            parse_bonk_buy_instruction(&ix, &account_keys).unwrap_err(),
+            ParseError::TooFewAccounts { expected: 15, actual: 14 }
+        );
+    }
+
+    #[test]
+    fn buy_with_out_of_range_account_index_is_rejected() {
+        let account_keys = unique_keys(20);
+        let mut accounts: Vec<u8> = (0..15).collect();
+        accounts[9] = 200;
+        let ix = buy_ix(0, accounts);
+
+        assert_eq!(
+            parse_bonk_buy_instruction(&ix, &account_keys).unwrap_err(),
+            ParseError::AccountIndexOutOfBounds { index: 200, len: 20 }
+        );
+    }
+
+    #[test]
+    fn malformed_buys_produce_no_opportunity() {
+        let mut account_keys = unique_keys(20);
+        account_keys[19] = RAYDIUM_LAUNCHPAD_PROGRAM_ID;
+        let mut out_of_range: Vec<u8> = (0..15).collect();
+        out_of_range[9] = 200;
+
+        for ix in [buy_ix(19, (0..14).collect()), buy_ix(19, out_of_range)] {
+            let (mint, buy, buy_param, migration) = trade_info(vec![ix], account_keys.clone());
+            assert!(mint.is_none());
+            assert!(buy.is_none());
+            assert!(buy_param.is_none());
+            assert!(migration.is_none());
+        }
+    }
+
+    #[test]
+    fn legacy_update_without_meta_parses_with_the_flag_on() {
+        let account_keys = unique_keys(3);
+        let update = TransactionUpdateBuilder::new()
+            .account_keys(account_keys.clone())
+            .instruction(2, vec![0, 1], vec![9])
+            .without_meta()
+            .build();
+
+        let (keys, ixs, _) = extract_transaction_data_with(&update, true).unwrap();
+        assert_eq!(keys, account_keys);
+        assert_eq!(ixs.len(), 1);
+    }
+
+    #[test]
+    fn update_without_meta_is_skipped_with_the_flag_off() {
+        let update = TransactionUpdateBuilder::new()
+            .account_keys(unique_keys(3))
+            .instruction(2, vec![0, 1], vec![9])
+            .without_meta()
+            .build();
+
+        assert!(extract_transaction_data_with(&update, false).is_none());
+    }
+
+    #[test]
+    fn update_without_meta_using_lookup_tables_is_skipped() {
+        let update = TransactionUpdateBuilder::new()
+            .account_keys(unique_keys(3))
+            .loaded_addresses(unique_keys(1), unique_keys(1))
+            .instruction(2, vec![0, 3, 4], vec![9])
+            .without_meta()
+            .build();
+
+        assert!(extract_transaction_data_with(&update, true).is_none());
+    }
+
+    #[test]
+    fn message_with_zero_static_keys_resolves_to_nothing() {
+        let message = Message::default();
+        let meta = TransactionStatusMeta::default();
+
+        assert!(resolve_account_keys(&message, &meta, "tx").is_none());
+    }
+
+    #[test]
+    fn empty_key_lists_parse_to_empty() {
+        assert_eq!(parse_account_keys(&[], "tx"), Some(Vec::new()));
+        assert_eq!(parse_loaded_addresses(&[], "tx"), Some(Vec::new()));
+    }
+
+    #[test]
+    fn undecodable_key_drops_the_whole_list() {
+        let raw = vec![Pubkey::new_unique().to_bytes().to_vec(), vec![7; 31]];
+
+        assert!(parse_account_keys(&raw, "tx").is_none());
+        assert!(parse_loaded_addresses(&raw, "tx").is_none());
+    }
+
+    #[test]
+    fn shared_buy_discriminator_routes_by_program() {
+        assert_eq!(PUMP_BUY_DISC, MOON_BUY_DISC);
+        assert_eq!(Launchpad::for_program(&PUMP_FUN_PROGRAM_ID), Some(Launchpad::Pump));
+        assert_eq!(Launchpad::for_program(&MOONSHOT_PROGRAM_ID), Some(Launchpad::Moonshot));
+        assert_eq!(Launchpad::for_program(&Pubkey::new_unique()), None);
+    }
+
+    #[test]
+    fn pump_buy_is_not_taken_for_another_launchpad() {
+        let mut account_keys = unique_keys(20);
+        account_keys[19] = PUMP_FUN_PROGRAM_ID;
+        let mut data = PUMP_BUY_DISC.to_vec();
+        data.extend_from_slice(&[0; 16]);
+        let pump_buy = CompiledInstruction { program_id_index: 19, accounts: (0..12).collect(), data };
+        // Launchpad buy bytes under the Pump.fun program id don't make it a launchpad buy either
+        let disguised = buy_ix(19, (0..15).collect());
+
+        let (mint, buy, buy_param, migration) = trade_info(vec![pump_buy, disguised], account_keys);
+        assert!(mint.is_none());
+        assert!(buy.is_none());
+        assert!(buy_param.is_none());
+        assert!(migration.is_none());
+    }
+
+    #[test]
+    fn buy_without_an_initialize_is_dropped_when_required() {
+        let mut account_keys = unique_keys(20);
+        account_keys[19] = RAYDIUM_LAUNCHPAD_PROGRAM_ID;
+        let ix = buy_ix(19, (0..15).collect());
+
+        let (mint, buy, buy_param, _) = trade_info_with(vec![ix.clone()], account_keys.clone(), true, 0);
+        assert!(mint.is_none());
+        assert!(buy.is_none());
+        assert!(buy_param.is_none());
+
+        let (_, buy, buy_param, _) = trade_info_with(vec![ix], account_keys.clone(), false, 0);
+        assert_eq!(buy.map(|buy| buy.base_token_mint), Some(account_keys[9]));
+        assert_eq!(buy_param.map(|param| param.amount_in), Some(1_000_000));
+    }
+
+    #[test]
+    fn buy_past_the_scan_limit_is_not_parsed() {
+        let mut account_keys = unique_keys(20);
+        account_keys[19] = RAYDIUM_LAUNCHPAD_PROGRAM_ID;
+        let filler = CompiledInstruction { program_id_index: 0, accounts: Vec::new(), data: vec![0; 8] };
+        let ixs = vec![filler.clone(), filler, buy_ix(19, (0..15).collect())];
+
+        let (_, buy, buy_param, _) = trade_info_with(ixs.clone(), account_keys.clone(), false, 2);
+        assert!(buy.is_none());
+        assert!(buy_param.is_none());
+
+        // One more scanned instruction reaches it
+        let (_, buy, _, _) = trade_info_with(ixs, account_keys.clone(), false, 3);
+        assert_eq!(buy.map(|buy| buy.base_token_mint), Some(account_keys[9]));
+    }
+
+    #[test]
+    fn v0_keys_resolve_static_then_writable_then_readonly() {
+        let static_keys = unique_keys(3);
+        let writable = unique_keys(2);
+        let readonly = unique_keys(2);
+        let update = TransactionUpdateBuilder::new()
+            .account_keys(static_keys.clone())
+            .loaded_addresses(writable.clone(), readonly.clone())
+            .instruction(2, vec![0, 3, 5], vec![9])
+            .build();
+
+        let (keys, _, _) = extract_transaction_data_with(&update, false).unwrap();
+        let expected: Vec<Pubkey> = [static_keys, writable.clone(), readonly.clone()].concat();
+        assert_eq!(keys, expected);
+        // Index 3 is the first lookup-table writable, index 5 the first readonly
+        assert_eq!(keys[3], writable[0]);
+        assert_eq!(keys[5], readonly[0]);
+    }
+
+    #[test]
+    fn loaded_addresses_not_matching_the_lookups_are_rejected() {
+        let message = Message {
+            account_keys: unique_keys(3).iter().map(|key| key.to_bytes().to_vec()).collect(),
+            ..Default::default()
+        };
+        let meta = TransactionStatusMeta {
+            loaded_writable_addresses: vec![Pubkey::new_unique().to_bytes().to_vec()],
+            ..Default::default()
+        };
+
+        assert!(resolve_account_keys(&message, &meta, "tx").is_none());
+    }
+}
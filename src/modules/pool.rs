@@ -0,0 +1,180 @@
+/**
+ * 🏊 Pool Reserves Module - Bonk.fun Trading Sniper Bot
+ * 
+ * This module reads live Raydium Launchpad pool reserves from the base and
+ * quote vault token accounts, with a short per-pool cache so monitoring
+ * loops don't hammer the RPC. It also derives the pool PDAs so observed
+ * accounts can be checked before trading against them.
+ * 
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use once_cell::sync::Lazy;
+use solana_client::client_error::ClientError;
+use solana_sdk::{program_pack::Pack, pubkey::Pubkey};
+use spl_token::state::Account as TokenAccount;
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::{BONK_POOL_SEED, BONK_POOL_VAULT_SEED, BonkBuy, RAYDIUM_LAUNCHPAD_PROGRAM_ID, RPC_CLIENT};
+
+/// How long fetched reserves are reused before hitting the RPC again
+const POOL_RESERVES_TTL: Duration = Duration::from_millis(400);
+
+static POOL_RESERVES_CACHE: Lazy<Mutex<HashMap<Pubkey, (Instant, (u64, u64))>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Errors raised while reading pool reserves
+#[derive(Debug)]
+pub enum PoolError {
+    /// A vault account doesn't exist yet (pool not initialized)
+    NotReady { vault: Pubkey },
+    /// A vault account exists but isn't a token account
+    InvalidVault { vault: Pubkey },
+    Rpc(ClientError),
+}
+
+impl fmt::Display for PoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PoolError::NotReady { vault } => write!(f, "pool not ready: vault {} does not exist yet", vault),
+            PoolError::InvalidVault { vault } => write!(f, "vault {} is not a token account", vault),
+            PoolError::Rpc(e) => write!(f, "rpc error reading pool reserves: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PoolError {}
+
+impl From<ClientError> for PoolError {
+    fn from(e: ClientError) -> Self {
+        PoolError::Rpc(e)
+    }
+}
+
+/**
+ * Fetches the pool's (base, quote) vault balances
+ * 
+ * Both vaults are read in a single `get_multiple_accounts` call. Results are
+ * cached per pool for a short TTL.
+ * 
+ * @param bonk_buy - Buy accounts identifying the pool and its vaults
+ * @returns Result<(u64, u64), PoolError> - (base_reserve, quote_reserve)
+ */
+pub async fn fetch_pool_reserves(bonk_buy: &BonkBuy) -> Result<(u64, u64), PoolError> {
+    if let Some(reserves) = cached_pool_reserves(&bonk_buy.pool_state) {
+        return Ok(reserves);
+    }
+
+    let vaults = [bonk_buy.base_vault, bonk_buy.quote_vault];
+    let accounts = RPC_CLIENT.get_multiple_accounts(&vaults).await?;
+
+    let mut amounts = [0u64; 2];
+    for (i, (vault, account)) in vaults.iter().zip(accounts).enumerate() {
+        let account = account.ok_or(PoolError::NotReady { vault: *vault })?;
+        amounts[i] = token_account_amount(vault, &account.data)?;
+    }
+
+    let reserves = (amounts[0], amounts[1]);
+    POOL_RESERVES_CACHE
+        .lock()
+        .unwrap()
+        .insert(bonk_buy.pool_state, (Instant::now(), reserves));
+
+    Ok(reserves)
+}
+
+/**
+ * Reads the token amount from raw token account data
+ * 
+ * Only the base SPL layout is unpacked, so Token-2022 accounts with
+ * extensions past it are handled too.
+ * 
+ * @param vault - Vault address for error reporting
+ * @param data - Raw account data
+ * @returns Result<u64, PoolError> - Token amount
+ */
+pub fn token_account_amount(vault: &Pubkey, data: &[u8]) -> Result<u64, PoolError> {
+    if data.len() < TokenAccount::LEN {
+        return Err(PoolError::InvalidVault { vault: *vault });
+    }
+
+    TokenAccount::unpack_from_slice(&data[..TokenAccount::LEN])
+        .map(|account| account.amount)
+        .map_err(|_| PoolError::InvalidVault { vault: *vault })
+}
+
+/**
+ * Derives the Raydium Launchpad pool state address for a mint pair
+ * 
+ * The pool is seeded by the two mints only; `global_config` picks the
+ * curve settings but isn't part of the address.
+ * 
+ * @param base_mint - Launched token mint
+ * @param quote_mint - Quote mint (wSOL)
+ * @returns Pubkey - Expected pool state PDA
+ */
+pub fn derive_bonk_pool_state(base_mint: &Pubkey, quote_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[BONK_POOL_SEED, base_mint.as_ref(), quote_mint.as_ref()],
+        &RAYDIUM_LAUNCHPAD_PROGRAM_ID,
+    )
+    .0
+}
+
+/**
+ * Derives a pool's vault address for one of its mints
+ * 
+ * @param pool_state - Pool state PDA
+ * @param mint - Base or quote mint
+ * @returns Pubkey - Expected vault PDA
+ */
+pub fn derive_bonk_pool_vault(pool_state: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[BONK_POOL_VAULT_SEED, pool_state.as_ref(), mint.as_ref()],
+        &RAYDIUM_LAUNCHPAD_PROGRAM_ID,
+    )
+    .0
+}
+
+/**
+ * Checks the observed pool and vault accounts against their derived PDAs
+ * 
+ * A spoofed or injected instruction could point a buy at an attacker's
+ * accounts; anything that doesn't match the derivation is rejected.
+ * 
+ * @param bonk_buy - Buy accounts taken from the observed transaction
+ * @returns Result<(), String> - Ok if all match, or which account didn't
+ */
+pub fn verify_bonk_pool_accounts(bonk_buy: &BonkBuy) -> Result<(), String> {
+    let expected_pool = derive_bonk_pool_state(&bonk_buy.base_token_mint, &bonk_buy.quote_token_mint);
+    if bonk_buy.pool_state != expected_pool {
+        return Err(format!("pool_state {} != derived {}", bonk_buy.pool_state, expected_pool));
+    }
+
+    let expected_base_vault = derive_bonk_pool_vault(&expected_pool, &bonk_buy.base_token_mint);
+    if bonk_buy.base_vault != expected_base_vault {
+        return Err(format!("base_vault {} != derived {}", bonk_buy.base_vault, expected_base_vault));
+    }
+
+    let expected_quote_vault = derive_bonk_pool_vault(&expected_pool, &bonk_buy.quote_token_mint);
+    if bonk_buy.quote_vault != expected_quote_vault {
+        return Err(format!("quote_vault {} != derived {}", bonk_buy.quote_vault, expected_quote_vault));
+    }
+
+    Ok(())
+}
+
+fn cached_pool_reserves(pool_state: &Pubkey) -> Option<(u64, u64)> {
+    let cache = POOL_RESERVES_CACHE.lock().unwrap();
+    cache
+        .get(pool_state)
+        .filter(|(fetched_at, _)| fetched_at.elapsed() < POOL_RESERVES_TTL)
+        .map(|(_, reserves)| *reserves)
+}
@@ -0,0 +1,1057 @@
+/**
+ * 📒 Position Tracking Module - Bonk.fun Trading Sniper Bot
+ *
+ * This module keeps the set of tokens the bot currently holds, so they
+ * can be listed and sold without restarting.
+ *
+ * Key Features:
+ * - In-memory registry of landed buys, keyed by mint
+ * - Sell execution for a tracked position, tagged with its exit reason
+ * - Monitoring loop that force-sells positions past `max_hold_seconds`
+ * - Take-profit ladder and trailing stop, priced off the polled curve state
+ * - Batched pool reserve polling, one `get_multiple_accounts` per tick
+ * - JSON rendering for the control endpoint
+ *
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use futures::future::join_all;
+use once_cell::sync::Lazy;
+use serde_json::{Value, json};
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::{
+    commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature, signer::Signer,
+};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use spl_token::instruction::close_account;
+use solana_transaction_status_client_types::{
+    UiLoadedAddresses, UiTransactionEncoding, option_serializer::OptionSerializer,
+};
+use spl_token_2022::{extension::StateWithExtensions, state::Account as TokenAccount};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    ops::Range,
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    BonkBuy, BonkBuyParam, BonkCurveParams, CONFIG, CONFIRM_SERVICE, ConfirmOutcome, CurveReserves,
+    LADDER_PCT_EPSILON, LadderRung, MintSupport, RPC_CLIENT, SLIPPAGE, STATS, SniperError, TradeRole,
+    backoff_delay_ms, build_bonk_sell_ix, compile_message, compute_budget_ixs, confirm_signature,
+    current_blockhash, fee_payer_pubkey, fetch_curve_reserves, forget_known_ata, increment_counter,
+    is_dry_run, jito_tip_ix, load_lookup_tables, parse_curve_reserves, quote_sol_out, sign_message,
+    slippage_min_out, submit_with_retry, transaction_signers, wallet_for,
+};
+
+/// Most accounts `get_multiple_accounts` accepts per call
+const MAX_ACCOUNTS_PER_CALL: usize = 100;
+
+/// Longest wait between retries of a failed exit sell
+const SELL_RETRY_CAP: Duration = Duration::from_secs(60);
+
+/// Open positions keyed by token account, so the primary and shadow wallets can hold the same mint
+static POSITIONS: Lazy<Mutex<HashMap<Pubkey, Position>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Mints whose buy landed without delivering any tokens
+static FLAGGED_MINTS: Lazy<Mutex<HashSet<Pubkey>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Token accounts with a sell currently being submitted or confirmed
+static SELLS_IN_FLIGHT: Lazy<Mutex<HashSet<Pubkey>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Token accounts working through ladder rungs or a trailing stop, which may span several sells
+static EXITS_IN_PROGRESS: Lazy<Mutex<HashSet<Pubkey>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Token accounts whose last automatic sell failed, and when to try them again
+static SELL_RETRIES: Lazy<Mutex<HashMap<Pubkey, SellRetry>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Backoff state of a position whose automatic sell keeps failing
+#[derive(Debug, Clone, Copy)]
+struct SellRetry {
+    failures: u32,
+    next_attempt_at: Instant,
+}
+
+/// Why a position is being sold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitReason {
+    /// Requested through the control endpoint
+    Manual,
+    /// Held longer than `[trade].max_hold_seconds`
+    MaxHold,
+    /// Crossed a `[trade].sell_ladder` gain target
+    Ladder,
+    /// Fell `[trade].trailing_stop_pct` below its peak value
+    TrailingStop,
+}
+
+impl fmt::Display for ExitReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExitReason::Manual => write!(f, "manual"),
+            ExitReason::MaxHold => write!(f, "max_hold"),
+            ExitReason::Ladder => write!(f, "ladder"),
+            ExitReason::TrailingStop => write!(f, "trailing_stop"),
+        }
+    }
+}
+
+/// How much of a position a sell disposes of
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SellPortion {
+    /// The whole token balance, closing the position
+    All,
+    /// A raw token amount, leaving the rest open; more than the balance sells it all
+    Tokens(u64),
+}
+
+/// Vault balances of a position's pool, in raw units
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolReserves {
+    pub base: u64,
+    pub quote: u64,
+}
+
+/// A token bought by the bot and not yet sold
+#[derive(Debug, Clone)]
+pub struct Position {
+    pub mint: Pubkey,
+    /// Accounts of the buy, reused to build the sell
+    pub bonk_buy: BonkBuy,
+    pub sol_spent_lamports: u64,
+    pub buy_signature: Signature,
+    pub opened_at_ms: u64,
+    /// Base tokens the buy delivered, once verified on-chain
+    pub tokens_received: Option<u64>,
+    /// Pool vault balances from the latest reserve poll
+    pub reserves: Option<PoolReserves>,
+    /// Whether the primary strategy or the shadow wallet bought it
+    pub role: TradeRole,
+    /// Curve the pool was launched with, for pricing exits
+    pub curve: BonkCurveParams,
+    /// Base mint verdict at buy time; a Token-2022 transfer fee is withheld again on every sell
+    pub mint_support: MintSupport,
+    /// Curve state from the latest poll; only polled while an exit strategy is configured
+    pub curve_reserves: Option<CurveReserves>,
+    /// Base tokens still held, from the latest poll
+    pub tokens_held: Option<u64>,
+    /// Ladder rungs already sold, in order
+    pub rungs_filled: usize,
+    /// Highest value per token seen since the trailing stop armed, in lamports
+    pub peak_value_per_token: Option<f64>,
+    /// SOL returned by partial sells so far, None once one couldn't be priced
+    pub partial_proceeds_lamports: Option<u64>,
+}
+
+impl Position {
+    /// Creates a position for a buy that just landed
+    pub fn opened(
+        bonk_buy: &BonkBuy,
+        curve: &BonkCurveParams,
+        mint_support: &MintSupport,
+        sol_spent_lamports: u64,
+        buy_signature: Signature,
+        role: TradeRole,
+    ) -> Self {
+        let opened_at_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+
+        Position {
+            mint: bonk_buy.base_token_mint,
+            bonk_buy: *bonk_buy,
+            sol_spent_lamports,
+            buy_signature,
+            opened_at_ms,
+            tokens_received: None,
+            reserves: None,
+            role,
+            curve: curve.clone(),
+            mint_support: mint_support.clone(),
+            curve_reserves: None,
+            tokens_held: None,
+            rungs_filled: 0,
+            peak_value_per_token: None,
+            partial_proceeds_lamports: Some(0),
+        }
+    }
+
+    /// Wallet that owns the tokens
+    pub fn owner(&self) -> Pubkey {
+        self.bonk_buy.payer
+    }
+
+    /// Token account holding the position, which identifies it
+    pub fn token_account(&self) -> Pubkey {
+        self.bonk_buy.user_base_token
+    }
+
+    /// Seconds since the buy landed
+    pub fn held_secs(&self) -> u64 {
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        now_ms.saturating_sub(self.opened_at_ms) / 1000
+    }
+
+    /// Lamports the tokens still held would sell for at the polled curve state
+    pub fn value_lamports(&self) -> Option<u64> {
+        Some(self.quote_sell(self.tokens_held?, self.curve_reserves.as_ref()?))
+    }
+
+    /// Lamports selling `amount` tokens returns, after the transfer fee into the pool
+    pub fn quote_sell(&self, amount: u64, reserves: &CurveReserves) -> u64 {
+        quote_sol_out(&self.curve, self.mint_support.net_amount(amount), reserves)
+    }
+
+    /// Sell value per token still held, in lamports
+    pub fn value_per_token(&self) -> Option<f64> {
+        let held = self.tokens_held.filter(|held| *held > 0)?;
+        Some(self.value_lamports()? as f64 / held as f64)
+    }
+
+    /**
+     * Gain of the tokens still held over what they cost, in %
+     *
+     * Measured on what they would actually sell for now, price impact
+     * included, against the entry price of the original buy.
+     *
+     * @returns Option<f64> - Gain in % (100 = 2x), None until both are polled
+     */
+    pub fn gain_pct(&self) -> Option<f64> {
+        let bought = self.tokens_received.filter(|bought| *bought > 0)?;
+        let entry_per_token = self.sol_spent_lamports as f64 / bought as f64;
+        if entry_per_token <= 0.0 {
+            return None;
+        }
+        Some((self.value_per_token()? / entry_per_token - 1.0) * 100.0)
+    }
+
+    /// Raises the trailing stop's peak to the current value, once the stop is armed
+    fn refresh_peak(&mut self) {
+        if !self.trailing_stop_armed() {
+            return;
+        }
+        if let Some(value) = self.value_per_token() {
+            self.peak_value_per_token = Some(self.peak_value_per_token.map_or(value, |peak| peak.max(value)));
+        }
+    }
+
+    /// The trailing stop guards what the ladder leaves, so it arms with the first rung
+    fn trailing_stop_armed(&self) -> bool {
+        CONFIG.trade.trailing_stop_pct > 0.0 && (CONFIG.trade.sell_ladder.is_empty() || self.rungs_filled > 0)
+    }
+}
+
+/// Returns whether a sell ladder or trailing stop is configured
+pub fn exit_strategy_enabled() -> bool {
+    !CONFIG.trade.sell_ladder.is_empty() || CONFIG.trade.trailing_stop_pct > 0.0
+}
+
+/// Starts tracking a position, replacing any earlier one in the same token account
+pub fn record_position(position: Position) {
+    POSITIONS.lock().unwrap().insert(position.token_account(), position);
+}
+
+/// Stops tracking the position held in `token_account`
+pub fn remove_position(token_account: &Pubkey) -> Option<Position> {
+    clear_sell_failures(token_account);
+    POSITIONS.lock().unwrap().remove(token_account)
+}
+
+/// Stops tracking every position in `mint`, returning how many were dropped
+pub fn remove_positions_for_mint(mint: &Pubkey) -> usize {
+    let mut positions = POSITIONS.lock().unwrap();
+    let before = positions.len();
+    positions.retain(|_, position| position.mint != *mint);
+    before - positions.len()
+}
+
+/// Marks a mint whose landed buy delivered no tokens (a honeypot symptom)
+pub fn flag_mint(mint: Pubkey) {
+    FLAGGED_MINTS.lock().unwrap().insert(mint);
+}
+
+/// Returns true if a landed buy of `mint` delivered no tokens
+pub fn is_mint_flagged(mint: &Pubkey) -> bool {
+    FLAGGED_MINTS.lock().unwrap().contains(mint)
+}
+
+/// Returns a snapshot of every open position
+pub fn open_positions() -> Vec<Position> {
+    POSITIONS.lock().unwrap().values().cloned().collect()
+}
+
+/// Renders open positions as a JSON array
+pub fn positions_json() -> Value {
+    let positions = open_positions()
+        .iter()
+        .map(|position| {
+            json!({
+                "mint": position.mint.to_string(),
+                "owner": position.owner().to_string(),
+                "wallet": position.role.label(),
+                "token_account": position.bonk_buy.user_base_token.to_string(),
+                "sol_spent_lamports": position.sol_spent_lamports,
+                "buy_signature": position.buy_signature.to_string(),
+                "opened_at_ms": position.opened_at_ms,
+                "tokens_received": position.tokens_received,
+                "tokens_held": position.tokens_held,
+                "rungs_filled": position.rungs_filled,
+                "gain_pct": position.gain_pct(),
+                "reserves": position.reserves.map(|r| json!({ "base": r.base, "quote": r.quote })),
+            })
+        })
+        .collect();
+
+    Value::Array(positions)
+}
+
+/**
+ * Sells all or part of a position's token balance
+ *
+ * The position is dropped once a sell of the whole balance lands, or when
+ * the token account is already empty; a partial sell leaves it open. Only
+ * one sell per position runs at a time. Landed exits are counted per
+ * reason in `position_exits{reason="..."}`.
+ *
+ * @param position - Position to sell from
+ * @param reason - Why the position is being sold
+ * @param portion - Whole balance or a raw token amount
+ * @param minimum_amount_out - Least SOL (lamports) to accept, 0 for any price
+ * @returns Result<Signature, SniperError> - Signature of the landed sell or error
+ */
+pub async fn execute_sell_transaction(
+    position: &Position,
+    reason: ExitReason,
+    portion: SellPortion,
+    minimum_amount_out: u64,
+) -> Result<Signature, SniperError> {
+    if !SELLS_IN_FLIGHT.lock().unwrap().insert(position.token_account()) {
+        return Err(SniperError::TransactionBuild(format!(
+            "a sell of {} is already in flight",
+            position.mint
+        )));
+    }
+
+    let result = submit_sell(position, reason, portion, minimum_amount_out).await;
+    SELLS_IN_FLIGHT.lock().unwrap().remove(&position.token_account());
+    result
+}
+
+async fn submit_sell(
+    position: &Position,
+    reason: ExitReason,
+    portion: SellPortion,
+    minimum_amount_out: u64,
+) -> Result<Signature, SniperError> {
+    let bonk_buy = &position.bonk_buy;
+    let wallet = wallet_for(&position.owner()).ok_or_else(|| {
+        SniperError::Config(format!("no loaded wallet owns the position in {}", position.mint))
+    })?;
+
+    let balance = RPC_CLIENT
+        .get_token_account_balance_with_commitment(&bonk_buy.user_base_token, CommitmentConfig::processed())
+        .await?
+        .value;
+    let held = balance.amount.parse::<u64>().map_err(|e| {
+        SniperError::TransactionBuild(format!("invalid token balance {}: {}", balance.amount, e))
+    })?;
+
+    if held == 0 {
+        remove_position(&position.token_account());
+        return Err(SniperError::TransactionBuild(format!(
+            "no {} tokens left to sell",
+            position.mint
+        )));
+    }
+
+    let amount_in = match portion {
+        SellPortion::All => held,
+        SellPortion::Tokens(amount) => amount.min(held),
+    };
+    let closes_position = amount_in == held;
+
+    if closes_position {
+        println!("💱 Selling {} of {} ({} exit)...", balance.ui_amount_string, position.mint, reason);
+    } else {
+        println!(
+            "💱 Selling {} of {} raw {} tokens ({} exit)...",
+            amount_in, held, position.mint, reason
+        );
+    }
+
+    // Proceeds arrive as wSOL, so the quote ATA has to exist
+    let mut ixs = compute_budget_ixs();
+    ixs.push(create_associated_token_account_idempotent(
+        &bonk_buy.payer,
+        &bonk_buy.payer,
+        &bonk_buy.quote_token_mint,
+        &bonk_buy.quote_token_program,
+    ));
+
+    let sell_param = BonkBuyParam {
+        amount_in,
+        minimum_amount_out,
+        share_fee_rate: 0,
+    };
+    ixs.push(build_bonk_sell_ix(bonk_buy, &sell_param));
+
+    if CONFIG.trade.close_wsol_after_buy {
+        ixs.push(close_account(
+            &bonk_buy.quote_token_program,
+            &bonk_buy.user_quote_token,
+            &bonk_buy.payer,
+            &bonk_buy.payer,
+            &[],
+        )?);
+    }
+
+    if CONFIRM_SERVICE.as_str() == "JITO" {
+        ixs.push(jito_tip_ix(&fee_payer_pubkey(&bonk_buy.payer)));
+    }
+
+    let lookup_tables = if CONFIG.trade.use_versioned_tx {
+        load_lookup_tables().await
+    } else {
+        Vec::new()
+    };
+    let message = compile_message(&ixs, &fee_payer_pubkey(&wallet.pubkey()), &lookup_tables)?;
+
+    if is_dry_run() {
+        println!("🧪 Dry run: skipping submission of sell for {}", position.mint);
+        return Err(SniperError::Config("dry run, sell not submitted".to_string()));
+    }
+
+    let build_tx = || sign_message(message.clone(), &transaction_signers(&wallet), current_blockhash());
+
+    let signature = submit_with_retry(
+        build_tx,
+        CONFIG.trade.submit_max_attempts,
+        Duration::from_millis(CONFIG.trade.submit_base_delay_ms),
+        Duration::from_millis(CONFIG.trade.submit_retry_budget_ms),
+    )
+    .await?;
+
+    println!("🚀 Sell transaction submitted: {}", signature);
+
+    // The sell closes the wSOL account, so the next buy has to recreate it
+    if CONFIG.trade.close_wsol_after_buy {
+        forget_known_ata(&bonk_buy.user_quote_token);
+    }
+
+    let timeout = Duration::from_millis(CONFIG.trade.confirm_timeout_ms);
+    match confirm_signature(&signature, timeout).await? {
+        ConfirmOutcome::Landed { slot } => {
+            increment_counter("sells_landed");
+            increment_counter(&format!("position_exits{{reason=\"{}\"}}", reason));
+            if !closes_position {
+                println!("✅ Partial sell {} ({} exit) landed in slot {}", signature, reason, slot);
+                let proceeds = sell_proceeds(&signature, &bonk_buy.payer).await;
+                record_partial_sell(&position.token_account(), amount_in, proceeds);
+                return Ok(signature);
+            }
+
+            // Earlier partial sells count towards what the position returned
+            let earlier = remove_position(&position.token_account())
+                .map_or(position.partial_proceeds_lamports, |closed| closed.partial_proceeds_lamports);
+            println!("✅ Sell transaction {} ({} exit) landed in slot {}", signature, reason, slot);
+            let proceeds = sell_proceeds(&signature, &bonk_buy.payer).await;
+            STATS.record_position_closed(
+                position.sol_spent_lamports,
+                proceeds.zip(earlier).map(|(last, earlier)| last + earlier),
+            );
+            Ok(signature)
+        }
+        ConfirmOutcome::Failed { error, .. } => {
+            increment_counter("sells_failed");
+            Err(SniperError::TransactionFailed { signature, error })
+        }
+        ConfirmOutcome::Timeout => {
+            increment_counter("sells_confirm_timeout");
+            Err(SniperError::ConfirmTimeout { signature })
+        }
+    }
+}
+
+/// Books a landed partial sell against the position it came out of
+fn record_partial_sell(token_account: &Pubkey, sold: u64, proceeds: Option<u64>) {
+    if let Some(tracked) = POSITIONS.lock().unwrap().get_mut(token_account) {
+        tracked.tokens_held = tracked.tokens_held.map(|held| held.saturating_sub(sold));
+        tracked.partial_proceeds_lamports = tracked
+            .partial_proceeds_lamports
+            .zip(proceeds)
+            .map(|(earlier, last)| earlier + last);
+    }
+}
+
+/**
+ * Reads the SOL a landed sell returned to the trading wallet
+ *
+ * Only known when the wSOL account is closed in the same transaction;
+ * otherwise the proceeds stay wrapped and the sell is left unpriced. The
+ * wallet isn't always the fee payer, so its balance is looked up by its
+ * position in the transaction's resolved account keys.
+ *
+ * @param signature - Signature of the landed sell
+ * @param wallet - Wallet that received the proceeds
+ * @returns Option<u64> - Net lamports gained by the wallet, None if unknown
+ */
+async fn sell_proceeds(signature: &Signature, wallet: &Pubkey) -> Option<u64> {
+    if !CONFIG.trade.close_wsol_after_buy {
+        return None;
+    }
+
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        commitment: Some(CommitmentConfig::confirmed()),
+        max_supported_transaction_version: Some(0),
+    };
+    let tx = match RPC_CLIENT.get_transaction_with_config(signature, config).await {
+        Ok(tx) => tx,
+        Err(e) => {
+            eprintln!("⚠️ Could not fetch sell {} to price it: {}", signature, e);
+            return None;
+        }
+    };
+
+    let static_keys = tx.transaction.transaction.decode()?.message.static_account_keys().to_vec();
+    let meta = tx.transaction.meta?;
+    let index = resolved_account_index(&static_keys, &meta.loaded_addresses, wallet)?;
+    let pre = *meta.pre_balances.get(index)?;
+    let post = *meta.post_balances.get(index)?;
+    Some(post.saturating_sub(pre))
+}
+
+/**
+ * Finds an account among a transaction's resolved keys
+ *
+ * Balances are indexed like the resolved keys: static keys first, then
+ * the lookup-table writable addresses, then the readonly ones.
+ *
+ * @param static_keys - Static account keys of the message
+ * @param loaded - Addresses loaded through lookup tables
+ * @param account - Account to find
+ * @returns Option<usize> - Index of the account, None if it isn't referenced
+ */
+fn resolved_account_index(
+    static_keys: &[Pubkey],
+    loaded: &OptionSerializer<UiLoadedAddresses>,
+    account: &Pubkey,
+) -> Option<usize> {
+    if let Some(index) = static_keys.iter().position(|key| key == account) {
+        return Some(index);
+    }
+
+    let OptionSerializer::Some(loaded) = loaded else {
+        return None;
+    };
+    let account = account.to_string();
+    loaded
+        .writable
+        .iter()
+        .chain(&loaded.readonly)
+        .position(|key| *key == account)
+        .map(|index| static_keys.len() + index)
+}
+
+/**
+ * Sells every open position concurrently, at any price
+ *
+ * @returns Vec<(Pubkey, Result<Signature, SniperError>)> - Outcome per mint
+ */
+pub async fn sell_all_positions() -> Vec<(Pubkey, Result<Signature, SniperError>)> {
+    let positions = open_positions();
+    println!("🧯 Selling all {} open positions", positions.len());
+
+    let sells = positions.iter().map(|position| async move {
+        let result = execute_sell_transaction(position, ExitReason::Manual, SellPortion::All, 0).await;
+        if let Err(e) = &result {
+            eprintln!("❌ Sell of {} failed: {}", position.mint, e);
+        }
+        (position.mint, result)
+    });
+
+    join_all(sells).await
+}
+
+/**
+ * Refreshes the pool reserves of every open position
+ *
+ * All vaults are fetched with `get_multiple_accounts` in as few calls as
+ * the 100-account limit allows, so RPC load doesn't grow per position. A
+ * position whose vaults no longer exist (pool migrated or rugged) is
+ * dropped from monitoring instead of erroring every tick. With an exit
+ * strategy configured, the pool state and the position's token account
+ * ride along in the same calls so exits can be priced.
+ */
+pub async fn poll_position_reserves() {
+    let positions = open_positions();
+    if positions.is_empty() {
+        return;
+    }
+
+    let track_exits = exit_strategy_enabled();
+    let per_position = if track_exits { 4 } else { 2 };
+    let watched: Vec<Pubkey> = positions
+        .iter()
+        .flat_map(|position| {
+            let bonk_buy = &position.bonk_buy;
+            [bonk_buy.base_vault, bonk_buy.quote_vault, bonk_buy.pool_state, bonk_buy.user_base_token]
+                .into_iter()
+                .take(per_position)
+        })
+        .collect();
+
+    let mut accounts = Vec::with_capacity(watched.len());
+    for chunk in watched.chunks(MAX_ACCOUNTS_PER_CALL) {
+        match RPC_CLIENT
+            .get_multiple_accounts_with_commitment(chunk, CommitmentConfig::processed())
+            .await
+        {
+            Ok(response) => accounts.extend(response.value),
+            Err(e) => {
+                println!("⚠️ Failed to poll position reserves: {}", e);
+                return;
+            }
+        }
+    }
+
+    let vault_amount = |data: &[u8]| {
+        StateWithExtensions::<TokenAccount>::unpack(data)
+            .ok()
+            .map(|token| token.base.amount)
+    };
+
+    for (position, fetched) in positions.iter().zip(accounts.chunks(per_position)) {
+        let reserves = match &fetched[..2] {
+            [Some(base), Some(quote)] => vault_amount(&base.data)
+                .zip(vault_amount(&quote.data))
+                .map(|(base, quote)| PoolReserves { base, quote }),
+            _ => None,
+        };
+
+        match reserves {
+            Some(reserves) => {
+                if let Some(tracked) = POSITIONS.lock().unwrap().get_mut(&position.token_account()) {
+                    tracked.reserves = Some(reserves);
+                    if track_exits {
+                        tracked.curve_reserves = fetched[2].as_ref().and_then(|pool| parse_curve_reserves(&pool.data));
+                        tracked.tokens_held = fetched[3].as_ref().and_then(|account| vault_amount(&account.data));
+                        // Before any sell, the balance is what the buy delivered
+                        if tracked.tokens_received.is_none() && tracked.rungs_filled == 0 {
+                            tracked.tokens_received = tracked.tokens_held.filter(|held| *held > 0);
+                        }
+                        tracked.refresh_peak();
+                    }
+                }
+            }
+            None => {
+                if SELLS_IN_FLIGHT.lock().unwrap().contains(&position.token_account()) {
+                    continue;
+                }
+                increment_counter("positions_vault_closed");
+                remove_position(&position.token_account());
+                println!(
+                    "🕳️ Pool vaults of {} are gone (migrated or rugged), no longer monitoring it",
+                    position.mint
+                );
+            }
+        }
+    }
+}
+
+/// Returns true unless a failed automatic sell of this position is still backing off
+fn sell_retry_due(token_account: &Pubkey) -> bool {
+    SELL_RETRIES
+        .lock()
+        .unwrap()
+        .get(token_account)
+        .map_or(true, |retry| Instant::now() >= retry.next_attempt_at)
+}
+
+/**
+ * Books a failed automatic sell and schedules the next try
+ *
+ * The wait starts at `[trade].position_poll_interval_ms` and doubles per
+ * consecutive failure, up to a minute.
+ *
+ * @param token_account - Token account identifying the position
+ * @returns Duration - How long until the position is tried again
+ */
+fn record_sell_failure(token_account: &Pubkey) -> Duration {
+    let mut retries = SELL_RETRIES.lock().unwrap();
+    let failures = retries.get(token_account).map_or(0, |retry| retry.failures);
+    let delay = Duration::from_millis(backoff_delay_ms(
+        CONFIG.trade.position_poll_interval_ms,
+        SELL_RETRY_CAP.as_millis() as u64,
+        failures,
+        false,
+    ));
+    retries.insert(
+        *token_account,
+        SellRetry {
+            failures: failures.saturating_add(1),
+            next_attempt_at: Instant::now() + delay,
+        },
+    );
+    delay
+}
+
+/// Forgets the backoff of a position once one of its sells lands
+fn clear_sell_failures(token_account: &Pubkey) {
+    SELL_RETRIES.lock().unwrap().remove(token_account);
+}
+
+/// What the exit strategy wants done with a position this tick
+#[derive(Debug, Clone)]
+enum ExitAction {
+    /// The gain crossed these ladder rungs, to be sold in order
+    Ladder { rungs: Range<usize>, gain_pct: f64 },
+    /// The value per token fell `trailing_stop_pct` below its peak
+    TrailingStop { peak: f64, value: f64 },
+}
+
+/**
+ * Decides whether a position's ladder or trailing stop should sell now
+ *
+ * A gap up past several rungs returns all of them, so none is skipped.
+ *
+ * @param position - Open position with polled curve state
+ * @param ladder - Take-profit rungs, `[trade].sell_ladder`
+ * @param trailing_stop_pct - Drop from the peak that sells the rest, `[trade].trailing_stop_pct`
+ * @returns Option<ExitAction> - Exit to run, None to keep holding
+ */
+fn next_exit(position: &Position, ladder: &[LadderRung], trailing_stop_pct: f64) -> Option<ExitAction> {
+    let gain_pct = position.gain_pct()?;
+    let from = position.rungs_filled.min(ladder.len());
+    let crossed = ladder[from..].iter().take_while(|rung| gain_pct >= rung.at_gain).count();
+    if crossed > 0 {
+        return Some(ExitAction::Ladder { rungs: from..from + crossed, gain_pct });
+    }
+
+    let peak = position.peak_value_per_token?;
+    let value = position.value_per_token()?;
+    let stop = peak * (1.0 - trailing_stop_pct / 100.0);
+    (value <= stop).then_some(ExitAction::TrailingStop { peak, value })
+}
+
+/**
+ * Works out how much a ladder rung sells
+ *
+ * Rung sizes are shares of the original position. The rung that brings
+ * the ladder to 100%, or one asking for more than is left, sells the whole
+ * remaining balance so rounding can't strand dust.
+ *
+ * @param position - Position the rung sells from
+ * @param ladder - Take-profit rungs, `[trade].sell_ladder`
+ * @param index - Rung in `ladder`
+ * @returns Option<SellPortion> - Amount to sell, None if the position isn't sized yet
+ */
+fn ladder_portion(position: &Position, ladder: &[LadderRung], index: usize) -> Option<SellPortion> {
+    let sold_pct: f64 = ladder[..=index].iter().map(|rung| rung.pct_of_position).sum();
+    if sold_pct >= 100.0 - LADDER_PCT_EPSILON {
+        return Some(SellPortion::All);
+    }
+
+    let bought = position.tokens_received?;
+    let held = position.tokens_held?;
+    let amount = ((bought as f64 * ladder[index].pct_of_position / 100.0).floor() as u64).max(1);
+    Some(if amount >= held { SellPortion::All } else { SellPortion::Tokens(amount) })
+}
+
+/**
+ * Sells for the exit strategy at the live curve price
+ *
+ * The pool is read again right before each sell, so rungs sold back to
+ * back are bounded by the price after the previous one, within
+ * `[trade].slippage`.
+ *
+ * @param position - Position to sell from
+ * @param reason - Ladder or trailing stop
+ * @param portion - Whole balance or a raw token amount
+ * @returns Result<Signature, SniperError> - Signature of the landed sell or error
+ */
+async fn sell_for_exit(
+    position: &Position,
+    reason: ExitReason,
+    portion: SellPortion,
+) -> Result<Signature, SniperError> {
+    let reserves = fetch_curve_reserves(&position.bonk_buy.pool_state).await?;
+    let amount = match portion {
+        SellPortion::All => position.tokens_held.unwrap_or_default(),
+        SellPortion::Tokens(amount) => amount,
+    };
+    let expected_out = position.quote_sell(amount, &reserves);
+    execute_sell_transaction(position, reason, portion, slippage_min_out(expected_out, *SLIPPAGE)).await
+}
+
+/**
+ * Runs one exit decision to completion
+ *
+ * Ladder rungs go out one at a time, lowest first, each sized from the
+ * position as the previous sell left it. A failed rung stops the run;
+ * a later tick picks it up again once its retry backoff has passed.
+ *
+ * @param token_account - Token account identifying the position
+ * @param action - Exit decided by `next_exit`
+ */
+async fn run_exit(token_account: Pubkey, action: ExitAction) {
+    match action {
+        ExitAction::Ladder { rungs, gain_pct } => {
+            let ladder = &CONFIG.trade.sell_ladder;
+            for index in rungs {
+                let Some(position) = POSITIONS.lock().unwrap().get(&token_account).cloned() else {
+                    return;
+                };
+                let Some(portion) = ladder_portion(&position, ladder, index) else {
+                    return;
+                };
+
+                println!(
+                    "🪜 {} is up {:.1}%: rung {} of {} sells {}% of the position",
+                    position.mint,
+                    gain_pct,
+                    index + 1,
+                    ladder.len(),
+                    ladder[index].pct_of_position
+                );
+                if let Err(e) = sell_for_exit(&position, ExitReason::Ladder, portion).await {
+                    let wait = record_sell_failure(&token_account);
+                    eprintln!(
+                        "❌ Ladder sell of {} (rung {}) failed, retrying in {:?}: {}",
+                        position.mint,
+                        index + 1,
+                        wait,
+                        e
+                    );
+                    return;
+                }
+                clear_sell_failures(&token_account);
+                if let Some(tracked) = POSITIONS.lock().unwrap().get_mut(&token_account) {
+                    tracked.rungs_filled = index + 1;
+                }
+            }
+        }
+        ExitAction::TrailingStop { peak, value } => {
+            let Some(position) = POSITIONS.lock().unwrap().get(&token_account).cloned() else {
+                return;
+            };
+
+            println!(
+                "📉 Trailing stop hit for {}: {:.2} lamports per token, {:.1}% below the peak of {:.2}",
+                position.mint,
+                value,
+                (1.0 - value / peak) * 100.0,
+                peak
+            );
+            match sell_for_exit(&position, ExitReason::TrailingStop, SellPortion::All).await {
+                Ok(_) => clear_sell_failures(&token_account),
+                Err(e) => {
+                    let wait = record_sell_failure(&token_account);
+                    eprintln!("❌ Trailing stop sell of {} failed, retrying in {:?}: {}", position.mint, wait, e);
+                }
+            }
+        }
+    }
+}
+
+/**
+ * Checks open positions once and runs their exits
+ *
+ * Reserves are refreshed first, then the loop waits
+ * `[trade].position_poll_interval_ms`. Force-sells go out with a floor of `force_sell_min_out_pct` of the SOL
+ * spent, so a position in a drained pool is kept rather than dumped for
+ * nothing; a later check retries it. A floor of 0 accepts any price.
+ * Ladder rungs and trailing stops are checked against the freshly polled
+ * curve state. Sells run on their own tasks so one slow confirmation
+ * doesn't hold up the rest. A position whose sell failed is skipped until
+ * its retry backoff has passed, and nothing is sold during a dry run.
+ */
+pub async fn position_monitor_handler() {
+    poll_position_reserves().await;
+
+    let max_hold_secs = CONFIG.trade.max_hold_seconds;
+    let selling = !is_dry_run();
+
+    if selling && max_hold_secs > 0 {
+        for position in open_positions() {
+            let held_secs = position.held_secs();
+            if held_secs < max_hold_secs
+                || SELLS_IN_FLIGHT.lock().unwrap().contains(&position.token_account())
+                || !sell_retry_due(&position.token_account())
+            {
+                continue;
+            }
+
+            let floor = (position.sol_spent_lamports as f64 * CONFIG.trade.force_sell_min_out_pct / 100.0) as u64;
+            println!(
+                "⏰ Force-selling {} after {}s (max hold {}s), min out {} lamports",
+                position.mint, held_secs, max_hold_secs, floor
+            );
+
+            tokio::spawn(async move {
+                let token_account = position.token_account();
+                match execute_sell_transaction(&position, ExitReason::MaxHold, SellPortion::All, floor).await {
+                    Ok(_) => clear_sell_failures(&token_account),
+                    Err(e) => {
+                        let wait = record_sell_failure(&token_account);
+                        eprintln!("❌ Max-hold sell of {} failed, retrying in {:?}: {}", position.mint, wait, e);
+                    }
+                }
+            });
+        }
+    }
+
+    if selling && exit_strategy_enabled() {
+        for position in open_positions() {
+            let token_account = position.token_account();
+            if SELLS_IN_FLIGHT.lock().unwrap().contains(&token_account)
+                || EXITS_IN_PROGRESS.lock().unwrap().contains(&token_account)
+                || !sell_retry_due(&token_account)
+            {
+                continue;
+            }
+            let Some(action) = next_exit(&position, &CONFIG.trade.sell_ladder, CONFIG.trade.trailing_stop_pct) else {
+                continue;
+            };
+
+            EXITS_IN_PROGRESS.lock().unwrap().insert(token_account);
+            tokio::spawn(async move {
+                run_exit(token_account, action).await;
+                EXITS_IN_PROGRESS.lock().unwrap().remove(&token_account);
+            });
+        }
+    }
+
+    tokio::time::sleep(Duration::from_millis(CONFIG.trade.position_poll_interval_ms)).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BonkFixedCurve;
+
+    fn rung(pct_of_position: f64, at_gain: f64) -> LadderRung {
+        LadderRung { pct_of_position, at_gain }
+    }
+
+    /// A position that bought 1M tokens for 1_000 lamports, its holding now worth `value_lamports`
+    fn position_worth(value_lamports: u64) -> Position {
+        let bonk_buy = BonkBuy {
+            payer: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+            global_config: Pubkey::new_unique(),
+            platform_config: Pubkey::new_unique(),
+            pool_state: Pubkey::new_unique(),
+            user_base_token: Pubkey::new_unique(),
+            user_quote_token: Pubkey::new_unique(),
+            base_vault: Pubkey::new_unique(),
+            quote_vault: Pubkey::new_unique(),
+            base_token_mint: Pubkey::new_unique(),
+            quote_token_mint: Pubkey::new_unique(),
+            base_token_program: spl_token::ID,
+            quote_token_program: spl_token::ID,
+            event_authority: Pubkey::new_unique(),
+            program: Pubkey::new_unique(),
+        };
+        // A fixed curve prices every token the same, so value scales with the quote reserve
+        let curve = BonkCurveParams::Fixed(BonkFixedCurve { supply: 0, total_quote_fund_raising: 0, migrate_type: 0 });
+        let mut position = Position::opened(
+            &bonk_buy,
+            &curve,
+            &MintSupport::Standard,
+            1_000,
+            Signature::default(),
+            TradeRole::Primary,
+        );
+        position.tokens_received = Some(1_000_000);
+        position.tokens_held = Some(1_000_000);
+        position.curve_reserves = Some(CurveReserves {
+            virtual_base: 1_000_000,
+            virtual_quote: value_lamports,
+            real_base: 0,
+            real_quote: u64::MAX,
+        });
+        position
+    }
+
+    fn loaded(writable: &[Pubkey], readonly: &[Pubkey]) -> OptionSerializer<UiLoadedAddresses> {
+        OptionSerializer::Some(UiLoadedAddresses {
+            writable: writable.iter().map(Pubkey::to_string).collect(),
+            readonly: readonly.iter().map(Pubkey::to_string).collect(),
+        })
+    }
+
+    #[test]
+    fn wallet_behind_a_separate_fee_payer_is_found() {
+        let fee_payer = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+
+        assert_eq!(resolved_account_index(&[fee_payer, wallet], &OptionSerializer::None, &wallet), Some(1));
+    }
+
+    #[test]
+    fn loaded_addresses_follow_the_static_keys() {
+        let static_keys = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let writable = [Pubkey::new_unique()];
+        let readonly = [Pubkey::new_unique()];
+        let loaded = loaded(&writable, &readonly);
+
+        assert_eq!(resolved_account_index(&static_keys, &loaded, &writable[0]), Some(2));
+        assert_eq!(resolved_account_index(&static_keys, &loaded, &readonly[0]), Some(3));
+        assert_eq!(resolved_account_index(&static_keys, &loaded, &Pubkey::new_unique()), None);
+    }
+
+    #[test]
+    fn ladder_rounds_its_last_rung_up_to_the_whole_balance() {
+        let ladder = [rung(50.0, 50.0), rung(50.0, 100.0)];
+        let mut position = position_worth(2_000);
+
+        assert_eq!(ladder_portion(&position, &ladder, 0), Some(SellPortion::Tokens(500_000)));
+        position.tokens_held = Some(500_000);
+        // Reaching 100% sells whatever is left, not the rung's nominal share
+        assert_eq!(ladder_portion(&position, &ladder, 1), Some(SellPortion::All));
+
+        // A rung asking for more than is held sells it all too
+        let ladder = [rung(40.0, 50.0), rung(40.0, 100.0)];
+        position.tokens_held = Some(300_000);
+        assert_eq!(ladder_portion(&position, &ladder, 1), Some(SellPortion::All));
+    }
+
+    #[test]
+    fn gap_up_sells_every_crossed_rung_in_order() {
+        let ladder = [rung(25.0, 50.0), rung(25.0, 100.0), rung(25.0, 200.0), rung(25.0, 400.0)];
+        // Up 250% in one tick: past the first three rungs
+        let mut position = position_worth(3_500);
+
+        let Some(ExitAction::Ladder { rungs, .. }) = next_exit(&position, &ladder, 0.0) else {
+            panic!("expected a ladder sell");
+        };
+        assert_eq!(rungs, 0..3);
+
+        // Rungs already sold aren't sold again
+        position.rungs_filled = 2;
+        let Some(ExitAction::Ladder { rungs, .. }) = next_exit(&position, &ladder, 0.0) else {
+            panic!("expected a ladder sell");
+        };
+        assert_eq!(rungs, 2..3);
+    }
+
+    #[test]
+    fn trailing_stop_sells_after_a_drop_from_the_peak() {
+        // Worth 0.0015 lamports per token now
+        let mut position = position_worth(1_500);
+
+        position.peak_value_per_token = Some(0.0018);
+        assert!(next_exit(&position, &[], 20.0).is_none());
+
+        position.peak_value_per_token = Some(0.002);
+        assert!(matches!(next_exit(&position, &[], 20.0), Some(ExitAction::TrailingStop { .. })));
+    }
+}
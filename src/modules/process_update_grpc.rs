@@ -16,32 +16,63 @@
  * @version 2.0.0
  */
 
-use futures::{SinkExt, StreamExt};
+use futures::{FutureExt, SinkExt, StreamExt};
 use serde_json::json;
-use solana_client::client_error::reqwest;
-use solana_relayer_adapter_rust::Tips;
+use solana_client::rpc_config::RpcTransactionConfig;
 use solana_sdk::{
-    commitment_config::CommitmentConfig, instruction::Instruction, pubkey::Pubkey,
+    commitment_config::CommitmentConfig,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    packet::PACKET_DATA_SIZE,
+    signature::Signature,
+    signer::{Signer, keypair::Keypair},
     system_instruction,
+    transaction::Transaction,
 };
 use spl_associated_token_account::{
     get_associated_token_address, get_associated_token_address_with_program_id,
     instruction::create_associated_token_account_idempotent,
 };
-use spl_token::instruction::sync_native;
+use spl_token::instruction::close_account;
+use solana_transaction_status_client_types::{UiTransactionTokenBalance, option_serializer::OptionSerializer};
+use once_cell::sync::Lazy;
 use std::{
-    collections::HashMap,
+    any::Any,
+    collections::{HashMap, HashSet},
     ops::{Div, Mul},
-    sync::Arc,
+    panic::AssertUnwindSafe,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
 };
 use yellowstone_grpc_client::{ClientTlsConfig, GeyserGrpcClient, Interceptor};
 use yellowstone_grpc_proto::{
-    geyser::{SubscribeUpdate, subscribe_update::UpdateOneof},
+    geyser::{SlotStatus, SubscribeUpdate, subscribe_update::UpdateOneof},
     tonic::Status,
 };
 
 use crate::*;
 
+/// ATAs confirmed to exist on-chain
+static KNOWN_ATAS: Lazy<Mutex<HashSet<Pubkey>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Derived ATAs keyed by (owner, mint, token program)
+static DERIVED_ATAS: Lazy<Mutex<HashMap<(Pubkey, Pubkey, Pubkey), Pubkey>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Entries kept in `DERIVED_ATAS` before it's cleared; every launch adds a new base mint
+const MAX_DERIVED_ATAS: usize = 4096;
+
+/// Highest slot seen on the stream, used to resume after a reconnect
+static LAST_SEEN_SLOT: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the highest slot seen on the stream so far (0 if none)
+pub fn last_seen_slot() -> u64 {
+    LAST_SEEN_SLOT.load(Ordering::Relaxed)
+}
+
 /**
  * Main transaction processing function
  * 
@@ -59,12 +90,63 @@ where
     
     let mut processed_count = 0u64;
     let mut error_count = 0u64;
+    let mut stale_count = 0u64;
+    let mut launch_count = 0u64;
+    
+    // Counts at the start of the current stats window, for per-interval rates
+    let mut window_started = Instant::now();
+    let mut window_processed = 0u64;
+    let mut window_errors = 0u64;
+    let mut window_launches = 0u64;
+    let mut window_attempted = counter_value("trades_attempted");
+    
+    // Launches held until their slot is confirmed, when [trade].require_confirmed is set
+    let mut confirm_gate = CONFIG
+        .trade
+        .require_confirmed
+        .then(|| ConfirmGate::new(Duration::from_millis(CONFIG.trade.confirm_window_ms)));
+    
+    // Signatures already handled, so a replayed transaction isn't traded twice
+    let mut seen_signatures = SeenSignatures::new(CONFIG.grpc.seen_signatures_capacity);
     
     while let Some(result) = stream.next().await {
         match result {
             Ok(update) => {
+                STREAM_HEALTH.mark_activity();
+                forward_raw_update(&update);
+                STATS.record_transaction();
                 processed_count += 1;
+                if let Some(slot) = update_slot(&update) {
+                    LAST_SEEN_SLOT.fetch_max(slot, Ordering::Relaxed);
+                    maybe_save_slot_state(last_seen_slot());
+                }
                 
+                // Drop stale updates (e.g. a backlog after reconnect) before any parsing
+                if let Some(age_ms) = stale_update_age_ms(&update) {
+                    stale_count += 1;
+                    increment_counter("stale_updates_skipped");
+                    if log_enabled(LogLevel::Debug) {
+                        println!("⏱️ Skipping stale update ({}ms old)", age_ms);
+                    }
+                    continue;
+                }
+
+                // Slot status updates only drive the confirmation gate
+                if let Some(UpdateOneof::Slot(slot_update)) = &update.update_oneof {
+                    if let Some(gate) = confirm_gate.as_mut() {
+                        match SlotStatus::try_from(slot_update.status) {
+                            Ok(SlotStatus::SlotConfirmed) => {
+                                for event in gate.confirm_slot(slot_update.slot) {
+                                    enqueue_trade(event);
+                                }
+                            }
+                            Ok(SlotStatus::SlotDead) => gate.drop_slot(slot_update.slot),
+                            _ => gate.expire(),
+                        }
+                    }
+                    continue;
+                }
+
                 // Extract transaction data with error handling
                 let (account_keys, ixs, tx_id) = match extract_transaction_data(&update) {
                     Some(data) => data,
@@ -74,33 +156,114 @@ where
                     }
                 };
 
+                // Skip transactions the stream delivered before (resume overlap, hiccups)
+                if !seen_signatures.insert(&tx_id) {
+                    increment_counter("duplicate_signatures_skipped");
+                    if log_enabled(LogLevel::Debug) {
+                        println!("🔁 Skipping duplicate delivery of TX {}", tx_id);
+                    }
+                    continue;
+                }
+
                 // Analyze transaction for Bonk.fun trading opportunities
-                let (bonk_raw_mint, bonk_raw_buy, bonk_raw_buy_param) = trade_info(ixs, account_keys);
+                let (bonk_raw_mint, bonk_raw_buy, bonk_raw_buy_param, bonk_raw_migration) =
+                    trade_info(ixs, account_keys);
 
+                // A failed transaction (only streamed with include_failed) never launched anything
+                if update_failed(&update) {
+                    if let (Some(mint_info), Some(buy)) = (&bonk_raw_mint, &bonk_raw_buy) {
+                        increment_counter("failed_launches_seen");
+                        println!(
+                            "💀 Failed launch attempt: {} ({}) mint {} TX {}",
+                            mint_info.base_mint_param.name, mint_info.base_mint_param.symbol, buy.base_token_mint, tx_id
+                        );
+                    }
+                }
                 // Process valid Bonk.fun trading opportunities
-                if let (Some(bonk_mint), Some(bonk_buy), Some(bonk_buy_param)) =
+                else if let (Some(mint_info), Some(buy), Some(buy_param)) =
                     (bonk_raw_mint, bonk_raw_buy, bonk_raw_buy_param)
                 {
-                    // Spawn async task for trading execution
-                    tokio::spawn(async move {
-                        if let Err(e) = execute_trading_strategy(bonk_mint, bonk_buy, bonk_buy_param, tx_id).await {
-                            eprintln!("❌ Trading execution failed for TX {}: {}", tx_id, e);
+                    let event = LaunchEvent::Bonk {
+                        mint_info,
+                        buy,
+                        buy_param,
+                        tx_id,
+                    };
+
+                    launch_count += 1;
+                    STATS.record_launch(event.launchpad());
+
+                    if CONFIG.output.emit_launches {
+                        emit_launch_event(&event);
+                    }
+                    emit_sniper_event(SniperEvent::Launch(event.clone()));
+
+                    // Queue for the trade workers, or hold it until its slot confirms;
+                    // launchpads missing from enabled_programs are only monitored
+                    if !program_enabled(event.launchpad()) {
+                        increment_counter(&format!(
+                            "launches_monitor_only{{launchpad=\"{}\"}}",
+                            event.launchpad().name()
+                        ));
+                        if chatter_enabled() {
+                            println!(
+                                "👀 Monitor-only launch on {}: mint {} TX {}",
+                                event.launchpad().name(),
+                                event.mint(),
+                                event.tx_id()
+                            );
                         }
+                    } else {
+                        match (confirm_gate.as_mut(), update_slot(&update)) {
+                            (Some(gate), Some(slot)) => gate.hold(buy.base_token_mint, slot, event),
+                            _ => enqueue_trade(event),
+                        }
+                    }
+                } else if let Some(migration) = bonk_raw_migration {
+                    handle_migration(LaunchEvent::Migration {
+                        mint: migration.base_mint,
+                        quote_mint: migration.quote_mint,
+                        migrate_type: migration.migrate_type,
+                        tx_id,
                     });
                 }
                 
-                // Log processing statistics periodically
+                // Log processing statistics periodically, with rates over the window since the last line
                 if processed_count % 100 == 0 {
-                    println!("📊 Processed {} transactions, {} errors", processed_count, error_count);
+                    let attempted = counter_value("trades_attempted");
+                    if chatter_enabled() {
+                        let window_secs = window_started.elapsed().as_secs_f64().max(0.001);
+                        println!(
+                            "📊 Processed {} transactions, {} errors, {} stale skipped | last {:.1}s: {:.1} tx/s, {:.2} errors/s, {} launches, {} buys attempted",
+                            processed_count,
+                            error_count,
+                            stale_count,
+                            window_secs,
+                            (processed_count - window_processed) as f64 / window_secs,
+                            (error_count - window_errors) as f64 / window_secs,
+                            launch_count - window_launches,
+                            attempted.saturating_sub(window_attempted)
+                        );
+                    }
+                    
+                    window_started = Instant::now();
+                    window_processed = processed_count;
+                    window_errors = error_count;
+                    window_launches = launch_count;
+                    window_attempted = attempted;
                 }
             }
             Err(e) => {
                 error_count += 1;
-                eprintln!("❌ Stream error: {}", e);
+                if is_keepalive_timeout(&e) {
+                    eprintln!("💔 Stream keepalive timed out, connection considered dead: {}", e);
+                } else {
+                    eprintln!("❌ Stream error: {}", e);
+                }
                 
                 // Log error statistics
                 if error_count % 10 == 0 {
-                    eprintln!("⚠️ High error rate detected: {} errors in {} transactions", error_count, processed_count);
+                    println!("⚠️ High error rate detected: {} errors in {} transactions", error_count, processed_count);
                 }
             }
         }
@@ -110,175 +273,662 @@ where
     Ok(())
 }
 
+/**
+ * Handles a pool graduating from the launchpad to an AMM
+ * 
+ * Migrations are logged and published like launches. A position held in
+ * the migrated mint stops being tracked, since launchpad sells no longer
+ * work once the pool has moved.
+ * 
+ * @param event - Detected migration event
+ */
+fn handle_migration(event: LaunchEvent) {
+    let LaunchEvent::Migration { mint, migrate_type, .. } = &event else {
+        return;
+    };
+    let target = if *migrate_type == MIGRATE_TYPE_CPSWAP { "CPSwap" } else { "AMM" };
+
+    increment_counter("migrations_detected");
+    println!("🎓 {} migrated to Raydium {} in TX {}", mint, target, event.tx_id());
+
+    let dropped = remove_positions_for_mint(mint);
+    if dropped > 0 {
+        println!(
+            "📒 Stopped tracking {} position(s) in {}: pool migrated, sell on the {} pool",
+            dropped, mint, target
+        );
+    }
+
+    if CONFIG.output.emit_launches {
+        emit_launch_event(&event);
+    }
+    emit_sniper_event(SniperEvent::Launch(event));
+}
+
+/**
+ * Runs trade execution for an event, catching panics
+ * 
+ * Called by the trade workers. A panic inside the trade would otherwise
+ * kill the worker silently. It is caught here and logged with the
+ * transaction and mint that triggered it.
+ * 
+ * @param event - Detected launch event
+ */
+pub async fn run_trade_task(event: LaunchEvent) {
+    let tx_id = event.tx_id().to_string();
+    let mint = event.mint();
+
+    let disposition = match AssertUnwindSafe(execute_trading_strategy(event.clone())).catch_unwind().await {
+        Ok(Ok(disposition)) => disposition,
+        Ok(Err(e)) => {
+            eprintln!("❌ Trading execution failed for TX {}: {}", tx_id, e);
+            Disposition::Errored { message: e.to_string() }
+        }
+        Err(payload) => {
+            let message = panic_message(payload.as_ref()).to_string();
+            increment_counter("trade_task_panics");
+            eprintln!("💥 Trade task panicked for TX {} (mint {}): {}", tx_id, mint, message);
+            Disposition::Errored { message: format!("trade task panicked: {}", message) }
+        }
+    };
+
+    record_disposition(&event, TradeRole::Primary, &disposition);
+    let error = match disposition {
+        Disposition::Errored { message } => Some(message),
+        _ => None,
+    };
+    emit_sniper_event(SniperEvent::TradeCompleted { tx_id, mint, error });
+}
+
+/// Extracts a readable message from a panic payload
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "non-string panic payload"
+    }
+}
+
+/**
+ * Checks whether an update is too old to act on
+ * 
+ * Compares the update's slot against the cluster slot cached by the
+ * blockhash handler, at roughly 400ms per slot.
+ * 
+ * @param update - gRPC update
+ * @returns Option<u64> - Age in ms if older than `max_update_age_ms`, None otherwise
+ */
+fn stale_update_age_ms(update: &SubscribeUpdate) -> Option<u64> {
+    let max_age_ms = CONFIG.trade.max_update_age_ms;
+    let current_slot = get_current_slot();
+    if max_age_ms == 0 || current_slot == 0 {
+        return None;
+    }
+
+    let age_ms = current_slot.saturating_sub(update_slot(update)?) * MS_PER_SLOT;
+    (age_ms > max_age_ms).then_some(age_ms)
+}
+
 /**
  * Executes trading strategy for detected Bonk.fun opportunities
  * 
  * This function implements the complete trading strategy:
  * 1. Validates trading opportunity against filters
  * 2. Performs risk assessment
- * 3. Executes buy transaction
- * 4. Monitors position and manages exit
+ * 3. Checks the cached wallet balance covers the buy and its fees
+ * 4. Executes buy transaction
+ * 5. Monitors position and manages exit
  * 
- * @param bonk_mint - Token mint information
- * @param bonk_buy - Buy transaction parameters
- * @param bonk_buy_param - Buy parameters
- * @param tx_id - Transaction ID for logging
- * @returns Result<(), Box<dyn std::error::Error>> - Success or error
+ * @param event - Detected launch event
+ * @returns Result<Disposition, SniperError> - Traded, or why it was skipped; errors otherwise
  */
-async fn execute_trading_strategy(
-    bonk_mint: BonkfunMIntInfo,
-    mut bonk_buy: BonkBuy,
-    bonk_buy_param: BonkBuyParam,
-    tx_id: String,
-) -> Result<(), Box<dyn std::error::Error>> {
-    println!("🎯 Processing trading opportunity for TX: {}", tx_id);
+async fn execute_trading_strategy(event: LaunchEvent) -> Result<Disposition, SniperError> {
+    let launchpad = event.launchpad();
+    let tx_id = event.tx_id().to_string();
+
+    if chatter_enabled() {
+        println!("🎯 Processing trading opportunity for TX: {}", tx_id);
+    }
+    increment_counter("trade_opportunities");
+    
+    // Sends would fail on a frozen blockhash, so don't spend the opportunity checks
+    if !is_trading_ready() {
+        increment_counter("trades_skipped_not_ready");
+        if chatter_enabled() {
+            println!("⏸️ Skipping TX {}: trading paused, blockhash is stale", tx_id);
+        }
+        return Ok(skipped("trading paused: blockhash is stale".to_string()));
+    }
+    
+    // Emergency stop: keep streaming and detecting, but don't buy
+    if trading_paused() {
+        increment_counter("trades_paused_skipped");
+        if chatter_enabled() {
+            println!("⛔ Trading paused by kill switch, skipping TX {}", tx_id);
+        }
+        return Ok(skipped("trading paused by kill switch".to_string()));
+    }
+    
+    // Scheduled trading hours: keep streaming and detecting, but only buy inside them
+    if !active_window_open() {
+        increment_counter("outside_active_window_skipped");
+        if chatter_enabled() {
+            println!(
+                "🕰️ Skipping TX {}: outside the active windows ({} UTC)",
+                tx_id,
+                CONFIG.trade.active_windows.join(", ")
+            );
+        }
+        return Ok(skipped("outside the active windows".to_string()));
+    }
     
     // Step 1: Apply trading filters
-    if !apply_trading_filters(&bonk_mint, &bonk_buy_param, &tx_id).await? {
-        println!("🚫 Trading opportunity filtered out for TX: {}", tx_id);
-        return Ok(());
+    if let Err(reason) = apply_trading_filters(&event).await {
+        count_filter_reject(&reason);
+        if CONFIG.filter.log_rejects && chatter_enabled() {
+            println!("🚫 Trading opportunity filtered out for TX {}: {}", tx_id, reason);
+        }
+        return Ok(skipped(reason.to_string()));
+    }
+    
+    let LaunchEvent::Bonk {
+        mint_info: bonk_mint,
+        buy,
+        buy_param,
+        ..
+    } = &event
+    else {
+        return Ok(skipped("not a tradable launch".to_string()));
+    };
+    let mut bonk_buy = *buy;
+    let bonk_buy_param = *buy_param;
+    
+    // The buy path wraps SOL, so a launch quoted in anything else would build a broken transaction
+    if !is_supported_quote_mint(&bonk_buy.quote_token_mint) {
+        increment_counter("unsupported_quote_mint_skipped");
+        if chatter_enabled() {
+            println!(
+                "🚫 Skipping TX {}: quote mint {} is not allowed or not wSOL",
+                tx_id, bonk_buy.quote_token_mint
+            );
+        }
+        return Ok(skipped(format!("quote mint {} is not allowed or not wSOL", bonk_buy.quote_token_mint)));
+    }
+    
+    // An earlier buy of this mint landed without delivering tokens, so don't buy it again
+    if is_mint_flagged(&bonk_buy.base_token_mint) {
+        increment_counter("flagged_mint_skipped");
+        if chatter_enabled() {
+            println!(
+                "🚫 Skipping TX {}: mint {} is flagged, an earlier buy delivered no tokens",
+                tx_id, bonk_buy.base_token_mint
+            );
+        }
+        return Ok(skipped("mint is flagged".to_string()));
+    }
+    
+    // Some launches use Token-2022 hooks to block sells; only trade programs the user allows
+    if !ALLOWED_TOKEN_PROGRAMS.contains(&bonk_buy.base_token_program) {
+        increment_counter("token_program_not_allowed_skipped");
+        if chatter_enabled() {
+            println!(
+                "🚫 Skipping TX {}: base mint {} uses token program {}, which is not allowed",
+                tx_id, bonk_buy.base_token_mint, bonk_buy.base_token_program
+            );
+        }
+        return Ok(skipped(format!("token program {} is not allowed", bonk_buy.base_token_program)));
+    }
+    
+    // Reject instructions whose pool accounts don't match the program's PDAs
+    if let Err(reason) = verify_bonk_pool_accounts(&bonk_buy) {
+        increment_counter("pool_pda_mismatch_skipped");
+        println!("🚫 Skipping TX {}: pool accounts don't match derivation ({})", tx_id, reason);
+        return Ok(skipped(format!("pool accounts don't match derivation ({})", reason)));
+    }
+    
+    // Token-2022 launches need their extensions checked before we commit to a buy
+    let mint_support = match inspect_base_mint(&bonk_buy.base_token_mint, &bonk_buy.base_token_program).await {
+        Ok(MintSupport::Unsupported(reason)) => {
+            increment_counter("unsupported_mint_skipped");
+            if chatter_enabled() {
+                println!("🚫 Skipping TX {}: mint {} is {}", tx_id, bonk_buy.base_token_mint, reason);
+            }
+            return Ok(skipped(format!("mint is {}", reason)));
+        }
+        Ok(support) => {
+            if let MintSupport::Token2022 { transfer_fee: Some(fee) } = &support {
+                if chatter_enabled() {
+                    println!(
+                        "🪙 Token-2022 mint {} charges a {} bps transfer fee (max {} units)",
+                        bonk_buy.base_token_mint,
+                        u16::from(fee.transfer_fee_basis_points),
+                        u64::from(fee.maximum_fee)
+                    );
+                }
+            }
+            support
+        }
+        Err(e) => {
+            if chatter_enabled() {
+                println!("🚫 Skipping TX {}: failed to inspect mint {}: {}", tx_id, bonk_buy.base_token_mint, e);
+            }
+            return Ok(skipped(format!("failed to inspect mint: {}", e)));
+        }
+    };
+    
+    // Opt-in gate for established pools; a fresh launch's vault only holds the dev buy
+    let min_quote_liquidity = CONFIG.filter.min_quote_liquidity_sol;
+    if min_quote_liquidity > 0.0 {
+        let quote_liquidity = match fetch_pool_reserves(&bonk_buy).await {
+            Ok((_, quote_reserve)) => quote_reserve,
+            // A vault that doesn't exist yet holds nothing
+            Err(PoolError::NotReady { .. }) => 0,
+            Err(e) => {
+                if chatter_enabled() {
+                    println!("🚫 Skipping TX {}: failed to read pool liquidity: {}", tx_id, e);
+                }
+                return Ok(skipped(format!("failed to read pool liquidity: {}", e)));
+            }
+        };
+        let quote_liquidity_sol = quote_liquidity as f64 / 10_f64.powi(9);
+        
+        if chatter_enabled() {
+            println!(
+                "💧 Pool {} holds {} SOL of quote liquidity (min {} SOL)",
+                bonk_buy.pool_state, quote_liquidity_sol, min_quote_liquidity
+            );
+        }
+        
+        if quote_liquidity_sol < min_quote_liquidity {
+            increment_counter("low_quote_liquidity_skipped");
+            if chatter_enabled() {
+                println!(
+                    "🚫 Skipping TX {}: pool quote liquidity {} SOL is below {} SOL",
+                    tx_id, quote_liquidity_sol, min_quote_liquidity
+                );
+            }
+            return Ok(skipped(format!(
+                "pool quote liquidity {} SOL is below {} SOL",
+                quote_liquidity_sol, min_quote_liquidity
+            )));
+        }
     }
     
     // Step 2: Log trading opportunity
     log_trading_opportunity(&bonk_mint, &bonk_buy, &bonk_buy_param, &tx_id);
     
-    // Step 3: Prepare transaction parameters
-    prepare_transaction_parameters(&mut bonk_buy)?;
+    // Step 3: Make sure the selected wallet can actually cover the buy
+    let wallet = next_wallet();
+    let wallet_pubkey = wallet.pubkey();
+    let wallet_balance = get_wallet_balance(&wallet_pubkey);
+    let buy_amount = if CONFIG.trade.copy_dev_buy {
+        let (amount, clamped) = copy_buy_amount(bonk_buy_param.sol_in());
+        if clamped {
+            increment_counter("copy_buy_clamped");
+            println!(
+                "📏 Dev bought {} lamports in TX {}, copying {} lamports (clamped to min/max_buy_sol)",
+                bonk_buy_param.sol_in(), tx_id, amount
+            );
+        }
+        amount
+    } else if CONFIG.trade.buy_mode == "percent" {
+        match percent_buy_amount(wallet_balance) {
+            Some(amount) => amount,
+            None => {
+                increment_counter("low_balance_skipped");
+                if chatter_enabled() {
+                    println!(
+                        "🚫 Skipping trade for TX {}: {}% of {} lamports in {} is below the minimum buy",
+                        tx_id, CONFIG.trade.buy_percent, wallet_balance, wallet_pubkey
+                    );
+                }
+                return Ok(skipped("percent buy is below the minimum buy".to_string()));
+            }
+        }
+    } else {
+        buy_amount_for(launchpad)
+    };
+    // The CU limit isn't sized yet, so hold enough for the largest it can become
+    let required_amount = calculate_total_cost(buy_amount, compute_unit_limit_ceiling());
+    if !validate_wallet_balance(required_amount, wallet_balance) {
+        if chatter_enabled() {
+            println!("🚫 Skipping trade for TX {}: insufficient balance in {}", tx_id, wallet_pubkey);
+        }
+        return Ok(skipped(format!("insufficient balance in {}", wallet_pubkey)));
+    }
     
-    // Step 4: Execute buy transaction
-    execute_buy_transaction(&bonk_buy, &bonk_buy_param).await?;
+    // Step 4: Prepare transaction parameters
+    prepare_transaction_parameters(&mut bonk_buy, &wallet_pubkey)?;
+    
+    // Bound the fill by slippage and by [trade].min_tokens_out, whichever is stricter
+    let minimum_amount_out =
+        match resolve_minimum_amount_out(&bonk_buy, &bonk_mint.curve_param, &mint_support, buy_amount, *SLIPPAGE)
+            .await
+        {
+            Ok(minimum_amount_out) => minimum_amount_out,
+            Err(reason) => {
+                increment_counter("min_tokens_out_unreachable_skipped");
+                println!("🚫 Skipping TX {}: {}", tx_id, reason);
+                return Ok(skipped(reason));
+            }
+        };
+    
+    // Opt-in: refuse tokens whose sell simulation fails
+    if CONFIG.filter.honeypot_check {
+        match simulate_sellability(&bonk_buy).await {
+            Ok(true) => {}
+            Ok(false) => {
+                increment_counter("honeypot_rejected");
+                if chatter_enabled() {
+                    println!("🍯 Skipping TX {}: {} failed the sell simulation", tx_id, bonk_buy.base_token_mint);
+                }
+                return Ok(skipped("failed the sell simulation".to_string()));
+            }
+            Err(e) => {
+                if chatter_enabled() {
+                    println!("🚫 Skipping TX {}: honeypot check errored: {}", tx_id, e);
+                }
+                return Ok(skipped(format!("honeypot check errored: {}", e)));
+            }
+        }
+    }
+    
+    // Governor for trial runs: stop buying after [trade].max_trades
+    let Some(remaining) = try_take_trade_budget() else {
+        if chatter_enabled() {
+            println!("🛑 Skipping trade for TX {}: trade limit of {} reached", tx_id, CONFIG.trade.max_trades);
+        }
+        return Ok(skipped(format!("trade limit of {} reached", CONFIG.trade.max_trades)));
+    };
+    
+    // Throttle launch storms so a burst of opportunities can't drain the wallet
+    if let Err(rejection) = try_acquire_trade_slot(
+        CONFIG.trade.max_trades_per_minute,
+        Duration::from_millis(CONFIG.trade.cooldown_ms),
+    ) {
+        refund_trade_budget();
+        if chatter_enabled() {
+            println!("⏳ Skipping trade for TX {}: {}", tx_id, rejection);
+        }
+        return Ok(skipped(rejection.to_string()));
+    }
+    if CONFIG.trade.max_trades > 0 && chatter_enabled() {
+        println!("🎟️ {} of {} trades left after this one", remaining, CONFIG.trade.max_trades);
+    }
+    
+    // Step 5: Execute buy transaction, alongside the shadow buy so neither can hold up or abort the other
+    increment_counter("trades_attempted");
+    debit_wallet_balance(&wallet_pubkey, required_amount);
+    let mut submitted = false;
+    let primary_buy = execute_buy_transaction(
+        &bonk_buy,
+        &bonk_buy_param,
+        &bonk_mint.curve_param,
+        &mint_support,
+        buy_amount,
+        minimum_amount_out,
+        &wallet,
+        TradeRole::Primary,
+        &mut submitted,
+    );
+    // A/B testing: the shadow wallet's mirror is sized only once every guard passed, its pool
+    // read overlapping the primary buy instead of delaying it
+    let mirror_buy = async {
+        let Some(shadow) = shadow_config() else {
+            return;
+        };
+        let curve = &bonk_mint.curve_param;
+        if let Some(shadow_buy) = prepare_shadow_buy(&event, &bonk_buy, curve, &mint_support, shadow).await {
+            execute_shadow_buy(&event, &shadow_buy, &bonk_buy_param, curve, &mint_support).await;
+        }
+    };
+    let (buy_result, ()) = tokio::join!(primary_buy, mirror_buy);
+    
+    // Only buys handed to the network count against [trade].max_trades
+    if !submitted {
+        refund_trade_budget();
+    }
+    
+    // Re-sync the cached balance so rapid consecutive snipes don't overcommit
+    if let Err(e) = refresh_wallet_balance(RPC_CLIENT.clone(), &wallet_pubkey).await {
+        println!("⚠️ Failed to refresh wallet balance after TX {}: {}", tx_id, e);
+    }
+    let signature = buy_result?;
     
     println!("✅ Trading strategy executed successfully for TX: {}", tx_id);
-    Ok(())
+    Ok(Disposition::Traded { signature })
 }
 
 /**
- * Applies trading filters to validate opportunities
+ * Works out the least base tokens the buy will accept
  * 
- * This function implements various filters:
- * - Twitter/X social media validation
- * - Token name filtering
- * - Developer buy amount validation
- * - Risk assessment filters
+ * The slippage floor comes from a curve quote against the live pool
+ * reserves, net of any Token-2022 transfer fee so both floors are in
+ * tokens that actually reach the wallet; `[trade].min_tokens_out` is an
+ * absolute floor on top of it, and the stricter of the two is used. The
+ * pool is only read when one of them can bind.
  * 
- * @param bonk_mint - Token mint information
- * @param bonk_buy_param - Buy parameters
- * @param tx_id - Transaction ID
- * @returns Result<bool, Box<dyn std::error::Error>> - True if passes filters
+ * @param bonk_buy - Buy accounts
+ * @param curve - Curve the pool was launched with
+ * @param mint_support - Base mint verdict, carrying its transfer fee
+ * @param buy_amount - Amount of SOL to spend, in lamports
+ * @param slippage - Tolerated slippage as a fraction (0.01 = 1%)
+ * @returns Result<u64, String> - Minimum tokens out (0 if unbounded), or why the trade can't fill
  */
-async fn apply_trading_filters(
-    bonk_mint: &BonkfunMIntInfo,
-    bonk_buy_param: &BonkBuyParam,
-    tx_id: &str,
-) -> Result<bool, Box<dyn std::error::Error>> {
-    // Twitter/X social media filter
-    if CONFIG.filter.x_check {
-        if !validate_social_media(bonk_mint, tx_id).await? {
-            return Ok(false);
-        }
+async fn resolve_minimum_amount_out(
+    bonk_buy: &BonkBuy,
+    curve: &BonkCurveParams,
+    mint_support: &MintSupport,
+    buy_amount: u64,
+    slippage: f64,
+) -> Result<u64, String> {
+    let min_tokens_out = CONFIG.trade.min_tokens_out;
+    if slippage >= 1.0 && min_tokens_out == 0 {
+        return Ok(0);
     }
 
-    // Token name filter
-    if CONFIG.filter.token_name_check {
-        if !validate_token_name(&bonk_mint.base_mint_param.name)? {
-            return Ok(false);
+    let reserves = match fetch_curve_reserves(&bonk_buy.pool_state).await {
+        Ok(reserves) => reserves,
+        Err(e) => {
+            println!("⚠️ Failed to read pool reserves for {}, using min_tokens_out only: {}", bonk_buy.base_token_mint, e);
+            return Ok(min_tokens_out);
         }
+    };
+
+    let expected_out = mint_support.net_amount(quote_base_out(curve, buy_amount, &reserves));
+    if min_tokens_out > expected_out {
+        return Err(format!(
+            "min_tokens_out {} exceeds the {} tokens the curve delivers for {} lamports",
+            min_tokens_out, expected_out, buy_amount
+        ));
     }
 
-    // Developer buy amount filter
-    if CONFIG.filter.dev_buy_check {
-        if !validate_dev_buy_amount(bonk_buy_param, tx_id)? {
-            return Ok(false);
-        }
+    let slippage_min = slippage_min_out(expected_out, slippage);
+    let (minimum_amount_out, bound) = if min_tokens_out > slippage_min {
+        (min_tokens_out, "min_tokens_out")
+    } else {
+        (slippage_min, "slippage")
+    };
+
+    if chatter_enabled() {
+        println!(
+            "📐 Minimum out {} of {} quoted tokens, bound by {} (slippage floor {}, absolute floor {})",
+            minimum_amount_out, expected_out, bound, slippage_min, min_tokens_out
+        );
     }
+    Ok(minimum_amount_out)
+}
 
-    Ok(true)
+/// A mirror buy sized for the shadow wallet
+struct ShadowBuy {
+    bonk_buy: BonkBuy,
+    buy_amount: u64,
+    required_amount: u64,
+    minimum_amount_out: u64,
+    wallet: Arc<Keypair>,
 }
 
 /**
- * Validates social media presence for token
+ * Sizes the shadow wallet's mirror of a buy
  * 
- * @param bonk_mint - Token mint information
- * @param tx_id - Transaction ID
- * @returns Result<bool, Box<dyn std::error::Error>> - True if validation passes
+ * The mirror reuses the launch's accounts with the shadow wallet as payer
+ * and `[trade.shadow]`'s amount and slippage. A mirror that can't go out
+ * is audited as filtered under the shadow role.
+ * 
+ * @param event - Detected launch event
+ * @param bonk_buy - Buy accounts of the launch
+ * @param curve - Curve the pool was launched with
+ * @param mint_support - Base mint verdict, carrying its transfer fee
+ * @param shadow - Shadow strategy settings
+ * @returns Option<ShadowBuy> - The mirror buy, or None if it should be skipped
  */
-async fn validate_social_media(
-    bonk_mint: &BonkfunMIntInfo,
-    tx_id: &str,
-) -> Result<bool, Box<dyn std::error::Error>> {
-    let response_text = match reqwest::get(bonk_mint.base_mint_param.uri.clone()).await {
-        Ok(response) => match response.text().await {
-            Ok(text) => text,
-            Err(e) => {
-                eprintln!("❌ Failed to get response text for TX {}: {}", tx_id, e);
-                return Ok(false);
-            }
-        },
-        Err(e) => {
-            eprintln!("❌ Failed to fetch social media for TX {}: {}", tx_id, e);
-            return Ok(false);
-        }
+async fn prepare_shadow_buy(
+    event: &LaunchEvent,
+    bonk_buy: &BonkBuy,
+    curve: &BonkCurveParams,
+    mint_support: &MintSupport,
+    shadow: &ShadowConfig,
+) -> Option<ShadowBuy> {
+    let role = TradeRole::Shadow;
+    let wallet = SHADOW_WALLET.clone()?;
+    let wallet_pubkey = wallet.pubkey();
+    let buy_amount = *SHADOW_BUY_AMOUNT;
+    let required_amount = calculate_total_cost(buy_amount, compute_unit_limit_ceiling());
+
+    let skip = |reason: String| {
+        println!("{}🚫 Skipping mirror buy for TX {}: {}", role.log_tag(), event.tx_id(), reason);
+        record_disposition(event, role, &skipped(reason));
+        None
     };
 
-    let is_match = CONFIG
-        .filter
-        .x_filter_list
-        .iter()
-        .any(|filter| response_text.contains(filter));
+    if !validate_wallet_balance(required_amount, get_wallet_balance(&wallet_pubkey)) {
+        return skip(format!("insufficient balance in {}", wallet_pubkey));
+    }
 
-    if !is_match {
-        println!("🚫 Twitter/X validation failed for TX: {}", tx_id);
-        return Ok(false);
+    let mut shadow_bonk_buy = *bonk_buy;
+    if let Err(e) = prepare_transaction_parameters(&mut shadow_bonk_buy, &wallet_pubkey) {
+        return skip(e.to_string());
     }
 
-    Ok(true)
+    let minimum_amount_out =
+        match resolve_minimum_amount_out(&shadow_bonk_buy, curve, mint_support, buy_amount, shadow.slippage / 100.0)
+            .await
+        {
+            Ok(minimum_amount_out) => minimum_amount_out,
+            Err(reason) => return skip(reason),
+        };
+
+    Some(ShadowBuy {
+        bonk_buy: shadow_bonk_buy,
+        buy_amount,
+        required_amount,
+        minimum_amount_out,
+        wallet,
+    })
 }
 
 /**
- * Validates token name against filter list
+ * Submits the shadow wallet's mirror buy and audits its outcome
  * 
- * @param token_name - Token name to validate
- * @returns Result<bool, Box<dyn std::error::Error>> - True if validation passes
+ * Errors are logged and recorded, never returned, so the primary buy
+ * is unaffected by anything that happens here.
+ * 
+ * @param event - Detected launch event
+ * @param shadow_buy - Mirror buy from `prepare_shadow_buy`
+ * @param bonk_buy_param - Buy parameters of the launch
+ * @param curve - Curve the pool was launched with
+ * @param mint_support - Base mint verdict, carrying its transfer fee
  */
-fn validate_token_name(token_name: &str) -> Result<bool, Box<dyn std::error::Error>> {
-    let is_match = CONFIG
-        .filter
-        .token_name_filter_list
-        .iter()
-        .any(|filter| filter == token_name);
+async fn execute_shadow_buy(
+    event: &LaunchEvent,
+    shadow_buy: &ShadowBuy,
+    bonk_buy_param: &BonkBuyParam,
+    curve: &BonkCurveParams,
+    mint_support: &MintSupport,
+) {
+    let role = TradeRole::Shadow;
+    let wallet_pubkey = shadow_buy.wallet.pubkey();
 
-    if !is_match {
-        println!("🚫 Token name validation failed: {}", token_name);
-        return Ok(false);
+    increment_counter(&role.counter("trades_attempted"));
+    debit_wallet_balance(&wallet_pubkey, shadow_buy.required_amount);
+    let mut submitted = false;
+    let result = execute_buy_transaction(
+        &shadow_buy.bonk_buy,
+        bonk_buy_param,
+        curve,
+        mint_support,
+        shadow_buy.buy_amount,
+        shadow_buy.minimum_amount_out,
+        &shadow_buy.wallet,
+        role,
+        &mut submitted,
+    )
+    .await;
+
+    if let Err(e) = refresh_wallet_balance(RPC_CLIENT.clone(), &wallet_pubkey).await {
+        println!("{}⚠️ Failed to refresh wallet balance after TX {}: {}", role.log_tag(), event.tx_id(), e);
     }
 
-    Ok(true)
+    let disposition = match result {
+        Ok(signature) => Disposition::Traded { signature },
+        Err(e) => {
+            eprintln!("{}❌ Mirror buy failed for TX {}: {}", role.log_tag(), event.tx_id(), e);
+            Disposition::Errored { message: e.to_string() }
+        }
+    };
+    record_disposition(event, role, &disposition);
+}
+
+/// Disposition for a launch a filter or guard decided not to buy
+fn skipped(reason: String) -> Disposition {
+    Disposition::Filtered { reason }
+}
+
+/// True if the quote mint is allowed by config and buyable by the wSOL path
+fn is_supported_quote_mint(quote_mint: &Pubkey) -> bool {
+    ALLOWED_QUOTE_MINTS.contains(quote_mint) && *quote_mint == spl_token::native_mint::ID
 }
 
 /**
- * Validates developer buy amount
+ * Applies the registered launch filters
  * 
- * @param bonk_buy_param - Buy parameters
- * @param tx_id - Transaction ID
- * @returns Result<bool, Box<dyn std::error::Error>> - True if validation passes
+ * Filters are combined per `[filter].mode`: `all` requires every filter
+ * to pass, `any` requires at least one. Evaluation stops once the outcome
+ * is decided, so later (more expensive) filters are skipped when they
+ * can't change the result.
+ * 
+ * @param event - Detected launch event
+ * @returns Result<(), FilterRejectReason> - Ok if the launch passes, or why it was rejected
  */
-fn validate_dev_buy_amount(
-    bonk_buy_param: &BonkBuyParam,
-    tx_id: &str,
-) -> Result<bool, Box<dyn std::error::Error>> {
-    let dev_buy_limit_lamports = (CONFIG.filter.dev_buy_limit * 10_f64.powi(9)) as u64;
-    
-    if bonk_buy_param.amount_in <= dev_buy_limit_lamports {
-        println!(
-            "🚫 Developer buy amount validation failed for TX: {} (Limit: {} SOL, Current: {} SOL)",
-            tx_id,
-            CONFIG.filter.dev_buy_limit,
-            (bonk_buy_param.amount_in as f64) / 10_f64.powi(9)
-        );
-        return Ok(false);
+async fn apply_trading_filters(event: &LaunchEvent) -> Result<(), FilterRejectReason> {
+    let require_all = CONFIG.filter.mode != "any";
+    let filters = launch_filters();
+    let mut rejections = Vec::new();
+
+    for filter in &filters {
+        match filter.check(event).await {
+            Ok(()) if !require_all => return Ok(()),
+            Ok(()) => {}
+            Err(reason) if require_all => return Err(reason),
+            Err(reason) => rejections.push(reason),
+        }
     }
 
-    Ok(true)
+    if require_all || filters.is_empty() {
+        Ok(())
+    } else {
+        Err(FilterRejectReason::NonePassed(rejections))
+    }
+}
+
+/// Counts a rejection under `filter_rejects{reason="..."}`, once per failed check in `any` mode
+fn count_filter_reject(reason: &FilterRejectReason) {
+    match reason {
+        FilterRejectReason::NonePassed(reasons) => reasons.iter().for_each(count_filter_reject),
+        reason => increment_counter(&format!("filter_rejects{{reason=\"{}\"}}", reason.label())),
+    }
 }
 
 /**
@@ -295,53 +945,550 @@ fn log_trading_opportunity(
     bonk_buy_param: &BonkBuyParam,
     tx_id: &str,
 ) {
+    // Launches are always logged, as a single line in quiet mode
+    if !chatter_enabled() {
+        println!(
+            "🎯 Launch detected: {} ({}) mint {} TX {}",
+            bonk_mint.base_mint_param.name, bonk_mint.base_mint_param.symbol, bonk_buy.base_token_mint, tx_id
+        );
+        return;
+    }
+
     println!("🎯 BONKFUN TRADING OPPORTUNITY DETECTED");
     println!("📋 Transaction ID: {}", tx_id);
     println!("🪙 Token: {} ({})", bonk_mint.base_mint_param.name, bonk_mint.base_mint_param.symbol);
-    println!("💰 Buy Amount: {} SOL", (bonk_buy_param.amount_in as f64) / 10_f64.powi(9));
+    println!("💰 Buy Amount: {} SOL", (bonk_buy_param.sol_in() as f64) / 10_f64.powi(9));
+    println!(
+        "🤝 Share Fee Rate: {} ({}%)",
+        bonk_buy_param.share_fee_rate,
+        share_fee_percent(bonk_buy_param.share_fee_rate)
+    );
     println!("📊 Token Mint: {}", bonk_buy.base_token_mint);
     println!("🔗 URI: {}", bonk_mint.base_mint_param.uri);
+    log_curve_params(bonk_mint);
+}
+
+/**
+ * Logs the bonding curve and vesting parameters of a launch
+ * 
+ * Supply and sell amounts are in raw base units; the fundraising target
+ * is shown in SOL.
+ * 
+ * @param bonk_mint - Token mint information
+ */
+fn log_curve_params(bonk_mint: &BonkfunMIntInfo) {
+    let migrate_target = |migrate_type: u8| if migrate_type == MIGRATE_TYPE_CPSWAP { "CPSwap" } else { "AMM" };
+    let sol = |lamports: u64| lamports as f64 / 10_f64.powi(9);
+
+    match &bonk_mint.curve_param {
+        BonkCurveParams::Constant(curve) => println!(
+            "📈 Curve: constant | supply {} | base sell {} | raise {} SOL | migrates to {}",
+            curve.supply,
+            curve.total_base_sell,
+            sol(curve.total_quote_fund_raising),
+            migrate_target(curve.migrate_type)
+        ),
+        BonkCurveParams::Fixed(curve) => println!(
+            "📈 Curve: fixed | supply {} | raise {} SOL | migrates to {}",
+            curve.supply,
+            sol(curve.total_quote_fund_raising),
+            migrate_target(curve.migrate_type)
+        ),
+        BonkCurveParams::Linear(curve) => println!(
+            "📈 Curve: linear | supply {} | raise {} SOL | migrates to {}",
+            curve.supply,
+            sol(curve.total_quote_fund_raising),
+            migrate_target(curve.migrate_type)
+        ),
+    }
+
+    let vesting = &bonk_mint.vesting_param;
+    println!(
+        "🔐 Vesting: {} locked | cliff {}s | unlock {}s",
+        vesting.total_locked_amount, vesting.cliff_period, vesting.unlock_period
+    );
 }
 
 /**
  * Prepares transaction parameters for execution
  * 
  * @param bonk_buy - Buy transaction parameters to prepare
- * @returns Result<(), Box<dyn std::error::Error>> - Success or error
+ * @param payer - Wallet that pays for and owns the buy
+ * @returns Result<(), SniperError> - Success or error
  */
-fn prepare_transaction_parameters(bonk_buy: &mut BonkBuy) -> Result<(), Box<dyn std::error::Error>> {
-    // Set payer to our wallet
-    bonk_buy.payer = *PUBKEY;
+fn prepare_transaction_parameters(
+    bonk_buy: &mut BonkBuy,
+    payer: &Pubkey,
+) -> Result<(), SniperError> {
+    // Set payer to the selected wallet
+    bonk_buy.payer = *payer;
     
     // Calculate associated token addresses
-    bonk_buy.user_base_token = get_associated_token_address_with_program_id(
-        &bonk_buy.payer,
-        &bonk_buy.base_token_mint,
-        &bonk_buy.base_token_program,
-    );
-    
-    bonk_buy.user_quote_token = get_associated_token_address_with_program_id(
-        &bonk_buy.payer,
-        &bonk_buy.quote_token_mint,
-        &bonk_buy.quote_token_program,
-    );
+    let (base_ata, quote_ata) = derive_user_atas(payer, bonk_buy);
+    bonk_buy.user_base_token = base_ata;
+    bonk_buy.user_quote_token = quote_ata;
 
     Ok(())
 }
 
+/**
+ * Derives the payer's base and quote token accounts for a buy
+ * 
+ * The quote (wSOL) ATA is the same for every buy of a wallet, so
+ * derivations are cached per (owner, mint, token program) to skip the
+ * repeated PDA search during bursts.
+ * 
+ * @param payer - Wallet that owns the token accounts
+ * @param bonk_buy - Buy accounts supplying the mints and token programs
+ * @returns (Pubkey, Pubkey) - Base token ATA and quote token ATA
+ */
+pub fn derive_user_atas(payer: &Pubkey, bonk_buy: &BonkBuy) -> (Pubkey, Pubkey) {
+    (
+        cached_ata(payer, &bonk_buy.base_token_mint, &bonk_buy.base_token_program),
+        cached_ata(payer, &bonk_buy.quote_token_mint, &bonk_buy.quote_token_program),
+    )
+}
+
+/// Looks up or derives the ATA of `owner` for `mint` under `token_program`
+fn cached_ata(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    let key = (*owner, *mint, *token_program);
+    if let Some(ata) = DERIVED_ATAS.lock().unwrap().get(&key) {
+        return *ata;
+    }
+
+    let ata = get_associated_token_address_with_program_id(owner, mint, token_program);
+    let mut derived = DERIVED_ATAS.lock().unwrap();
+    if derived.len() >= MAX_DERIVED_ATAS {
+        derived.clear();
+    }
+    derived.insert(key, ata);
+    ata
+}
+
 /**
  * Executes the buy transaction
  * 
  * @param bonk_buy - Buy transaction parameters
  * @param bonk_buy_param - Buy parameters
- * @returns Result<(), Box<dyn std::error::Error>> - Success or error
+ * @param curve - Curve the pool was launched with, kept on the position for exit pricing
+ * @param mint_support - Base mint verdict, kept on the position so exits net out its transfer fee
+ * @param buy_amount - Amount of SOL to spend, in lamports
+ * @param minimum_amount_out - Least base tokens to accept, 0 if unbounded
+ * @param wallet - Wallet that signs the transaction
+ * @param role - Primary or shadow buy, which tags its logs and metrics
+ * @param submitted - Set once the buy is handed to the network, whatever happens after
+ * @returns Result<Option<Signature>, SniperError> - Signature of the landed buy (None in dry run) or error
  */
 async fn execute_buy_transaction(
     bonk_buy: &BonkBuy,
     bonk_buy_param: &BonkBuyParam,
-) -> Result<(), Box<dyn std::error::Error>> {
-    println!("💸 Executing buy transaction...");
-    
+    curve: &BonkCurveParams,
+    mint_support: &MintSupport,
+    buy_amount: u64,
+    minimum_amount_out: u64,
+    wallet: &Keypair,
+    role: TradeRole,
+    submitted: &mut bool,
+) -> Result<Option<Signature>, SniperError> {
+    let tag = role.log_tag();
+    println!("{}💸 Executing buy transaction...", tag);
+
+    let use_bundle = CONFIRM_SERVICE.as_str() == "JITO" && CONFIG.services.use_jito_bundles;
+    let mut close_wsol = CONFIG.trade.close_wsol_after_buy;
+    let (mut ixs, mut compute_unit_limit) =
+        build_buy_instructions(bonk_buy, buy_amount, minimum_amount_out, close_wsol).await?;
+
+    // v0 with lookup tables shrinks the packet; without usable tables fall back to legacy.
+    // Bundles are always legacy.
+    let lookup_tables = if CONFIG.trade.use_versioned_tx && !use_bundle {
+        let tables = load_lookup_tables().await;
+        if tables.is_empty() {
+            println!("⚠️ use_versioned_tx is set but no lookup table is available, sending legacy");
+        }
+        tables
+    } else {
+        Vec::new()
+    };
+
+    // The fee payer signs first; the wallet still owns the ATAs and funds the buy
+    let fee_payer = fee_payer_for(wallet).pubkey();
+    let signers = transaction_signers(wallet);
+
+    // The network drops anything over the packet limit, so trim or fail here instead
+    let mut message = compile_message(&ixs, &fee_payer, &lookup_tables)?;
+    let account_count = message.static_account_keys().len();
+    let max_accounts = CONFIG.trade.max_tx_accounts;
+    if lookup_tables.is_empty() && max_accounts > 0 && account_count > max_accounts {
+        increment_counter(&role.counter("buys_too_many_accounts_skipped"));
+        println!(
+            "{}🚫 Skipping buy of {}: legacy transaction references {} accounts (max_tx_accounts {})",
+            tag, bonk_buy.base_token_mint, account_count, max_accounts
+        );
+        return Err(SniperError::TransactionBuild(format!(
+            "buy references {} accounts, over max_tx_accounts {}",
+            account_count, max_accounts
+        )));
+    }
+    let mut tx_size = transaction_size(&message, &signers)?;
+    if tx_size > PACKET_DATA_SIZE && close_wsol {
+        println!(
+            "⚠️ Buy transaction is {} bytes (limit {}), dropping the wSOL close and its wrap buffer",
+            tx_size, PACKET_DATA_SIZE
+        );
+        // Without the close nothing would unwrap the buffer, so rebuild rather than just pop it
+        close_wsol = false;
+        (ixs, compute_unit_limit) =
+            build_buy_instructions(bonk_buy, buy_amount, minimum_amount_out, close_wsol).await?;
+        message = compile_message(&ixs, &fee_payer, &lookup_tables)?;
+        tx_size = transaction_size(&message, &signers)?;
+    }
+    if tx_size > PACKET_DATA_SIZE {
+        return Err(SniperError::TransactionBuild(format!(
+            "buy transaction is {} bytes, over the {}-byte packet limit",
+            tx_size, PACKET_DATA_SIZE
+        )));
+    }
+
+    // Refuse to submit if a misconfiguration makes fees dwarf the buy; priced on the
+    // CU limit actually requested, which auto_cu may have sized above the static one
+    let fees = match check_fee_cap(buy_amount, compute_unit_limit) {
+        Ok(fees) => fees,
+        Err(e) => {
+            eprintln!("{}🛑 FEE CAP EXCEEDED, aborting buy of {}: {}", tag, bonk_buy.base_token_mint, e);
+            return Err(SniperError::Config(e));
+        }
+    };
+
+    if chatter_enabled() {
+        println!("✅ Buy transaction prepared successfully");
+    }
+
+    if is_dry_run() {
+        println!("{}🧪 Dry run: skipping submission of buy for {}", tag, bonk_buy.base_token_mint);
+        return Ok(None);
+    }
+
+    *submitted = true;
+    let result: Result<Option<Signature>, SniperError> = async {
+        if use_bundle {
+            let signature = submit_buy_bundle(&ixs, wallet).await?;
+            STATS.record_buy_landed(buy_amount, fees);
+            open_position(bonk_buy, curve, mint_support, buy_amount, minimum_amount_out, signature, role).await;
+            return Ok(Some(signature));
+        }
+
+        // Re-sign with the freshest cached blockhash on every attempt
+        let build_tx = || sign_message(message.clone(), &signers, current_blockhash());
+
+        let signature = submit_with_retry(
+            build_tx,
+            CONFIG.trade.submit_max_attempts,
+            Duration::from_millis(CONFIG.trade.submit_base_delay_ms),
+            Duration::from_millis(CONFIG.trade.submit_retry_budget_ms),
+        )
+        .await?;
+
+        println!("{}🚀 Buy transaction submitted: {}", tag, signature);
+
+        // Submission isn't success: wait for the buy to land and surface on-chain failures
+        let timeout = Duration::from_millis(CONFIG.trade.confirm_timeout_ms);
+        match confirm_signature(&signature, timeout).await? {
+            ConfirmOutcome::Landed { slot } => {
+                increment_counter(&role.counter("buys_landed"));
+                println!("{}✅ Buy transaction {} landed in slot {}", tag, signature, slot);
+                STATS.record_buy_landed(buy_amount, fees);
+                open_position(bonk_buy, curve, mint_support, buy_amount, minimum_amount_out, signature, role).await;
+                Ok(Some(signature))
+            }
+            ConfirmOutcome::Failed { slot, error } => {
+                increment_counter(&role.counter("buys_failed"));
+                eprintln!("{}❌ Buy transaction {} failed in slot {}: {}", tag, signature, slot, error);
+                Err(SniperError::TransactionFailed { signature, error })
+            }
+            ConfirmOutcome::Timeout => {
+                increment_counter(&role.counter("buys_confirm_timeout"));
+                eprintln!("{}⌛ Buy transaction {} not confirmed within {}ms", tag, signature, timeout.as_millis());
+                Err(SniperError::ConfirmTimeout { signature })
+            }
+        }
+    }
+    .await;
+
+    // The buy closed the wSOL account, or may have failed around creating it;
+    // either way the cached existence can't be trusted for the next buy
+    if close_wsol || result.is_err() {
+        forget_known_ata(&bonk_buy.user_quote_token);
+    }
+    result
+}
+
+/**
+ * Records the position of a landed buy
+ * 
+ * With `[trade].verify_buy_balance`, the landed transaction is read first
+ * so the position carries what the buy actually delivered. A buy that
+ * delivered nothing is flagged, so later launches of the mint are
+ * skipped, and not tracked.
+ * 
+ * @param bonk_buy - Buy accounts
+ * @param curve - Curve the pool was launched with
+ * @param mint_support - Base mint verdict, carrying its transfer fee
+ * @param buy_amount - SOL spent, in lamports
+ * @param minimum_amount_out - Least tokens the buy accepted, 0 if unbounded
+ * @param signature - Signature of the landed buy
+ * @param role - Primary or shadow buy
+ */
+async fn open_position(
+    bonk_buy: &BonkBuy,
+    curve: &BonkCurveParams,
+    mint_support: &MintSupport,
+    buy_amount: u64,
+    minimum_amount_out: u64,
+    signature: Signature,
+    role: TradeRole,
+) {
+    let mut position = Position::opened(bonk_buy, curve, mint_support, buy_amount, signature, role);
+
+    if CONFIG.trade.verify_buy_balance {
+        match verify_received_tokens(bonk_buy, &signature, buy_amount, minimum_amount_out).await {
+            Ok(0) => {
+                increment_counter("buys_zero_balance");
+                flag_mint(bonk_buy.base_token_mint);
+                eprintln!(
+                    "🚨 Buy {} landed but delivered no {} tokens to {}, flagging mint",
+                    signature, bonk_buy.base_token_mint, bonk_buy.user_base_token
+                );
+                return;
+            }
+            Ok(received) => position.tokens_received = Some(received),
+            Err(e) => eprintln!("⚠️ Failed to verify tokens received by buy {}: {}", signature, e),
+        }
+    }
+
+    record_position(position);
+}
+
+/**
+ * Reads how many base tokens a landed buy delivered
+ * 
+ * Taken from the buy's own pre- and post-transaction token balances, so
+ * tokens already in the account from an earlier buy aren't counted.
+ * Logs the amount and the effective price, and how the fill compares to
+ * `minimum_amount_out` when one was set.
+ * 
+ * @param bonk_buy - Buy accounts
+ * @param signature - Signature of the landed buy
+ * @param buy_amount - SOL spent, in lamports
+ * @param minimum_amount_out - Least tokens the buy accepted, 0 if unbounded
+ * @returns Result<u64, SniperError> - Raw token amount the buy added or error
+ */
+async fn verify_received_tokens(
+    bonk_buy: &BonkBuy,
+    signature: &Signature,
+    buy_amount: u64,
+    minimum_amount_out: u64,
+) -> Result<u64, SniperError> {
+    let config = RpcTransactionConfig {
+        encoding: None,
+        commitment: Some(CommitmentConfig::confirmed()),
+        max_supported_transaction_version: Some(0),
+    };
+    let tx = RPC_CLIENT.get_transaction_with_config(signature, config).await?;
+    let meta = tx
+        .transaction
+        .meta
+        .ok_or_else(|| SniperError::TransactionBuild(format!("buy {} has no status meta", signature)))?;
+
+    let pre = payer_token_balance(&meta.pre_token_balances, bonk_buy);
+    let post = payer_token_balance(&meta.post_token_balances, bonk_buy);
+    let received = post.map_or(0, |(amount, _)| amount).saturating_sub(pre.map_or(0, |(amount, _)| amount));
+
+    if received == 0 {
+        return Ok(0);
+    }
+
+    let decimals = post.map_or(0, |(_, decimals)| decimals);
+    let sol_spent = buy_amount as f64 / 10_f64.powi(9);
+    let ui_received = received as f64 / 10_f64.powi(decimals as i32);
+    println!(
+        "🧾 Received {} {} for {} SOL ({:.12} SOL per token)",
+        ui_received,
+        bonk_buy.base_token_mint,
+        sol_spent,
+        sol_spent / ui_received
+    );
+
+    if minimum_amount_out > 0 {
+        let fill_pct = received as f64 / minimum_amount_out as f64 * 100.0;
+        println!("📐 Fill is {:.1}% of the {} token minimum", fill_pct, minimum_amount_out);
+    }
+
+    Ok(received)
+}
+
+/// Raw amount and decimals of the base mint the payer holds, from a transaction's token balances
+fn payer_token_balance(
+    balances: &OptionSerializer<Vec<UiTransactionTokenBalance>>,
+    bonk_buy: &BonkBuy,
+) -> Option<(u64, u8)> {
+    let OptionSerializer::Some(balances) = balances else {
+        return None;
+    };
+    let mint = bonk_buy.base_token_mint.to_string();
+    let owner = bonk_buy.payer.to_string();
+    let balance = balances
+        .iter()
+        .find(|balance| balance.mint == mint && matches!(&balance.owner, OptionSerializer::Some(o) if *o == owner))?;
+    Some((balance.ui_token_amount.amount.parse().ok()?, balance.ui_token_amount.decimals))
+}
+
+/**
+ * Submits a buy as a 2-transaction Jito bundle (buy, then tip)
+ * 
+ * Bundling keeps the buy and the tip atomic, so nothing can land between
+ * them and the tip is never paid for a buy that didn't land.
+ * 
+ * @param buy_ixs - Buy instructions, without a tip
+ * @param wallet - Wallet that signs the buy; the fee payer signs both
+ * @returns Result<Signature, SniperError> - Signature of the buy once the bundle landed
+ */
+async fn submit_buy_bundle(
+    buy_ixs: &[Instruction],
+    wallet: &Keypair,
+) -> Result<Signature, SniperError> {
+    let blockhash = current_blockhash();
+    let fee_payer = fee_payer_for(wallet);
+    let payer = fee_payer.pubkey();
+
+    let buy_tx = Transaction::new_signed_with_payer(
+        buy_ixs,
+        Some(&payer),
+        &transaction_signers(wallet),
+        blockhash,
+    );
+    let tip_tx = Transaction::new_signed_with_payer(
+        &[jito_tip_ix(&payer)],
+        Some(&payer),
+        &[fee_payer],
+        blockhash,
+    );
+
+    let buy_signature = buy_tx.signatures[0];
+
+    let bundle_id = match submit_bundle(vec![buy_tx, tip_tx]).await {
+        Ok(bundle_id) => bundle_id,
+        Err(e) => {
+            eprintln!("❌ Buy bundle not accepted: {}", e);
+            return Err(e.into());
+        }
+    };
+
+    let timeout = Duration::from_millis(CONFIG.services.bundle_timeout_ms);
+    match wait_for_bundle(&bundle_id, timeout).await {
+        Ok(_) => {
+            println!("🚀 Buy bundle landed: {}", bundle_id);
+            Ok(buy_signature)
+        }
+        Err(e) => {
+            eprintln!("❌ Buy bundle {} failed: {}", bundle_id, e);
+            Err(e.into())
+        }
+    }
+}
+
+/// Optional parts of a buy, resolved from config and chain state before assembly
+#[derive(Debug, Clone, Copy)]
+pub struct BuyIxOptions {
+    /// Include the idempotent create of the quote (wSOL) ATA
+    pub create_quote_ata: bool,
+    /// Payer of an in-transaction Jito tip; None without Jito or when bundled
+    pub jito_tip_payer: Option<Pubkey>,
+    /// Close the wSOL account after the buy
+    pub close_wsol: bool,
+    /// Lamports wrapped on top of the buy amount; only wrapped when the close returns them
+    pub wrap_buffer_lamports: u64,
+}
+
+/**
+ * Builds the instructions of a buy, in submission order
+ * 
+ * Resolves the optional parts from config (and the quote ATA lookup),
+ * assembles them with `assemble_buy_instructions`, then sizes the compute
+ * unit limit when `[priority_fee].auto_cu` is on.
+ * 
+ * @param bonk_buy - Buy accounts, with the payer and ATAs filled in
+ * @param buy_amount - Amount of SOL to spend, in lamports
+ * @param minimum_amount_out - Least base tokens to accept, 0 if unbounded
+ * @param close_wsol - Close the wSOL account after the buy
+ * @returns Result<(Vec<Instruction>, u64), SniperError> - Ordered instructions and the CU limit they request, or error
+ */
+pub async fn build_buy_instructions(
+    bonk_buy: &BonkBuy,
+    buy_amount: u64,
+    minimum_amount_out: u64,
+    close_wsol: bool,
+) -> Result<(Vec<Instruction>, u64), SniperError> {
+    // The quote (wSOL) ATA usually already exists, so only create it when missing.
+    // When it's closed after every buy it never survives, so skip the lookup.
+    let create_quote_ata = close_wsol || !quote_ata_exists(&bonk_buy.user_quote_token).await;
+
+    // Jito only lands transactions that pay a tip; bundles carry it in their own transaction
+    let use_bundle = CONFIRM_SERVICE.as_str() == "JITO" && CONFIG.services.use_jito_bundles;
+    let jito_tip_payer = jito_tip_payer(CONFIRM_SERVICE.as_str(), use_bundle, fee_payer_pubkey(&bonk_buy.payer));
+
+    let options = BuyIxOptions {
+        create_quote_ata,
+        jito_tip_payer,
+        close_wsol,
+        wrap_buffer_lamports: CONFIG.trade.wrap_buffer_lamports,
+    };
+    let mut ixs = assemble_buy_instructions(bonk_buy, buy_amount, minimum_amount_out, &options)?;
+
+    // Size the CU limit from a simulation of the full buy
+    let mut compute_unit_limit = PRIORITY_FEE.0;
+    if CONFIG.priority_fee.auto_cu {
+        if let Some(limit) = apply_auto_compute_unit_limit(
+            &mut ixs,
+            &fee_payer_pubkey(&bonk_buy.payer),
+            &bonk_buy.base_token_program,
+        )
+        .await
+        {
+            compute_unit_limit = limit as u64;
+        }
+    }
+
+    Ok((ixs, compute_unit_limit))
+}
+
+/// Payer of the tip carried in the buy itself: only Jito needs one, and a bundle tips in its own transaction
+fn jito_tip_payer(confirm_service: &str, use_bundle: bool, fee_payer: Pubkey) -> Option<Pubkey> {
+    (confirm_service == "JITO" && !use_bundle).then_some(fee_payer)
+}
+
+/**
+ * Assembles the instructions of a buy from already resolved options
+ * 
+ * Order: compute budget, base ATA create, quote (wSOL) ATA create when
+ * needed, SOL transfer, sync_native, launchpad buy, then the Jito tip
+ * and the wSOL close when enabled. With the close, the transfer wraps
+ * `buy_amount` plus the wrap buffer while the buy spends exactly
+ * `buy_amount`, and the close returns the remainder; without it nothing
+ * would unwrap the buffer, so only `buy_amount` is wrapped. Makes no RPC
+ * calls, so the exact list can be checked without sending anything.
+ * 
+ * @param bonk_buy - Buy accounts, with the payer and ATAs filled in
+ * @param buy_amount - Amount of SOL to spend, in lamports
+ * @param minimum_amount_out - Least base tokens to accept, 0 if unbounded
+ * @param options - Optional parts of the buy
+ * @returns Result<Vec<Instruction>, SniperError> - Ordered instructions or error
+ */
+pub fn assemble_buy_instructions(
+    bonk_buy: &BonkBuy,
+    buy_amount: u64,
+    minimum_amount_out: u64,
+    options: &BuyIxOptions,
+) -> Result<Vec<Instruction>, SniperError> {
     // Create associated token account instructions
     let create_base_ata = create_associated_token_account_idempotent(
         &bonk_buy.payer,
@@ -350,33 +1497,442 @@ async fn execute_buy_transaction(
         &bonk_buy.base_token_program,
     );
 
-    let create_quote_ata = create_associated_token_account_idempotent(
-        &bonk_buy.payer,
-        &bonk_buy.payer,
-        &bonk_buy.quote_token_mint,
-        &bonk_buy.quote_token_program,
-    );
+    let create_quote_ata = options.create_quote_ata.then(|| {
+        create_associated_token_account_idempotent(
+            &bonk_buy.payer,
+            &bonk_buy.payer,
+            &bonk_buy.quote_token_mint,
+            &bonk_buy.quote_token_program,
+        )
+    });
 
     // Create transfer and wrap instructions
+    let wrap_buffer = if options.close_wsol { options.wrap_buffer_lamports } else { 0 };
     let transfer_ix = system_instruction::transfer(
-        &PUBKEY,
+        &bonk_buy.payer,
         &bonk_buy.user_quote_token,
-        *BUY_SOL_AMOUNT,
+        buy_amount + wrap_buffer,
     );
     
-    let wrap_ix = sync_native(&spl_token::ID, &bonk_buy.user_quote_token)?;
+    let wrap_ix = sync_native_ix(&bonk_buy.quote_token_program, &bonk_buy.user_quote_token)?;
 
     // Create buy parameters
     let buy_param = BonkBuyParam {
-        amount_in: *BUY_SOL_AMOUNT,
-        minimum_amount_out: 0,
+        amount_in: buy_amount,
+        minimum_amount_out,
         share_fee_rate: 0,
     };
+    let buy_ix = build_bonk_buy_ix(bonk_buy, &buy_param);
 
-    // TODO: Implement actual transaction submission
-    // This would involve creating and sending the transaction
-    // with proper error handling and confirmation
-    
-    println!("✅ Buy transaction prepared successfully");
-    Ok(())
+    let mut ixs = compute_budget_ixs();
+    ixs.push(create_base_ata);
+    ixs.extend(create_quote_ata);
+    ixs.extend([transfer_ix, wrap_ix, buy_ix]);
+
+    if let Some(tip_payer) = &options.jito_tip_payer {
+        ixs.push(jito_tip_ix(tip_payer));
+    }
+
+    // Unwrap leftover SOL and reclaim the wSOL account rent
+    if options.close_wsol {
+        ixs.push(close_account(
+            &bonk_buy.quote_token_program,
+            &bonk_buy.user_quote_token,
+            &bonk_buy.payer,
+            &bonk_buy.payer,
+            &[],
+        )?);
+    }
+
+    Ok(ixs)
+}
+
+/**
+ * Checks whether the quote token ATA already exists on-chain
+ * 
+ * Existing ATAs are remembered so the RPC is only queried until the account
+ * is first seen, and again after a trade closes it or a buy fails. With `[trade].skip_ata_check` set, this always returns
+ * false so the idempotent create is included without a round trip.
+ * 
+ * @param ata - Associated token account address
+ * @returns bool - True if the account is known to exist
+ */
+async fn quote_ata_exists(ata: &Pubkey) -> bool {
+    if CONFIG.trade.skip_ata_check {
+        return false;
+    }
+
+    if KNOWN_ATAS.lock().unwrap().contains(ata) {
+        return true;
+    }
+
+    match RPC_CLIENT
+        .get_account_with_commitment(ata, CommitmentConfig::processed())
+        .await
+    {
+        Ok(response) if response.value.is_some() => {
+            KNOWN_ATAS.lock().unwrap().insert(*ata);
+            true
+        }
+        Ok(_) => false,
+        Err(e) => {
+            println!("⚠️ ATA existence check failed for {}: {}", ata, e);
+            false
+        }
+    }
+}
+
+/// Drops an ATA from the known-to-exist cache so the next buy checks it again
+pub fn forget_known_ata(ata: &Pubkey) {
+    KNOWN_ATAS.lock().unwrap().remove(ata);
+}
+
+/**
+ * Serialized size of a compiled message once signed
+ * 
+ * @param message - Compiled legacy or v0 message
+ * @param signers - Fee payer first, then any other signers
+ * @returns Result<usize, SniperError> - Size on the wire in bytes
+ */
+fn transaction_size(message: &VersionedMessage, signers: &[&Keypair]) -> Result<usize, SniperError> {
+    let tx = sign_message(message.clone(), signers, current_blockhash())?;
+    bincode::serialized_size(&tx)
+        .map(|size| size as usize)
+        .map_err(|e| SniperError::TransactionBuild(format!("failed to serialize transaction: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::hash::Hash;
+    use crate::test_support::{
+        FixedBlockhash, TransactionUpdateBuilder, bonk_buy_data, bonk_initialize_data, update_stream,
+    };
+
+    const BUY_AMOUNT: u64 = 1_000_000;
+    const MINIMUM_AMOUNT_OUT: u64 = 500;
+
+    fn test_buy() -> BonkBuy {
+        test_buy_for(Pubkey::new_unique())
+    }
+
+    fn test_buy_for(payer: Pubkey) -> BonkBuy {
+        let base_token_mint = Pubkey::new_unique();
+        let quote_token_mint = spl_token::native_mint::ID;
+
+        BonkBuy {
+            payer,
+            authority: Pubkey::new_unique(),
+            global_config: Pubkey::new_unique(),
+            platform_config: Pubkey::new_unique(),
+            pool_state: Pubkey::new_unique(),
+            user_base_token: get_associated_token_address(&payer, &base_token_mint),
+            user_quote_token: get_associated_token_address(&payer, &quote_token_mint),
+            base_vault: Pubkey::new_unique(),
+            quote_vault: Pubkey::new_unique(),
+            base_token_mint,
+            quote_token_mint,
+            base_token_program: spl_token::ID,
+            quote_token_program: spl_token::ID,
+            event_authority: Pubkey::new_unique(),
+            program: RAYDIUM_LAUNCHPAD_PROGRAM_ID,
+        }
+    }
+
+    fn options(create_quote_ata: bool, jito_tip_payer: Option<Pubkey>, close_wsol: bool) -> BuyIxOptions {
+        BuyIxOptions {
+            create_quote_ata,
+            jito_tip_payer,
+            close_wsol,
+            wrap_buffer_lamports: 0,
+        }
+    }
+
+    fn wrapped_lamports(ixs: &[Instruction], bonk_buy: &BonkBuy) -> u64 {
+        let transfer = ixs
+            .iter()
+            .find(|ix| ix.program_id == solana_sdk::system_program::ID && ix.accounts[1].pubkey == bonk_buy.user_quote_token)
+            .expect("buy wraps SOL");
+        // System transfer data: u32 instruction tag, then the u64 amount
+        u64::from_le_bytes(transfer.data[4..12].try_into().unwrap())
+    }
+
+    /// Compute budget through the launchpad buy, which every buy starts with
+    fn expected_through_buy(bonk_buy: &BonkBuy, create_quote_ata: bool) -> Vec<Instruction> {
+        let mut ixs = compute_budget_ixs();
+        ixs.push(create_associated_token_account_idempotent(
+            &bonk_buy.payer,
+            &bonk_buy.payer,
+            &bonk_buy.base_token_mint,
+            &spl_token::ID,
+        ));
+        if create_quote_ata {
+            ixs.push(create_associated_token_account_idempotent(
+                &bonk_buy.payer,
+                &bonk_buy.payer,
+                &bonk_buy.quote_token_mint,
+                &spl_token::ID,
+            ));
+        }
+        ixs.push(system_instruction::transfer(&bonk_buy.payer, &bonk_buy.user_quote_token, BUY_AMOUNT));
+        ixs.push(spl_token::instruction::sync_native(&spl_token::ID, &bonk_buy.user_quote_token).unwrap());
+        ixs.push(build_bonk_buy_ix(
+            bonk_buy,
+            &BonkBuyParam {
+                amount_in: BUY_AMOUNT,
+                minimum_amount_out: MINIMUM_AMOUNT_OUT,
+                share_fee_rate: 0,
+            },
+        ));
+        ixs
+    }
+
+    fn expected_close(bonk_buy: &BonkBuy) -> Instruction {
+        close_account(&spl_token::ID, &bonk_buy.user_quote_token, &bonk_buy.payer, &bonk_buy.payer, &[]).unwrap()
+    }
+
+    #[test]
+    fn buy_closing_wsol_ends_with_the_close() {
+        let bonk_buy = test_buy();
+        let ixs = assemble_buy_instructions(&bonk_buy, BUY_AMOUNT, MINIMUM_AMOUNT_OUT, &options(true, None, true))
+            .unwrap();
+
+        let mut expected = expected_through_buy(&bonk_buy, true);
+        expected.push(expected_close(&bonk_buy));
+        assert_eq!(ixs, expected);
+    }
+
+    #[test]
+    fn buy_keeping_wsol_skips_the_quote_create_and_close() {
+        let bonk_buy = test_buy();
+        let ixs = assemble_buy_instructions(&bonk_buy, BUY_AMOUNT, MINIMUM_AMOUNT_OUT, &options(false, None, false))
+            .unwrap();
+
+        assert_eq!(ixs, expected_through_buy(&bonk_buy, false));
+    }
+
+    #[test]
+    fn unbundled_jito_buy_tips_between_the_buy_and_the_close() {
+        let bonk_buy = test_buy();
+        let tip_payer = bonk_buy.payer;
+        let ixs = assemble_buy_instructions(
+            &bonk_buy,
+            BUY_AMOUNT,
+            MINIMUM_AMOUNT_OUT,
+            &options(true, Some(tip_payer), true),
+        )
+        .unwrap();
+
+        let expected = expected_through_buy(&bonk_buy, true);
+        assert_eq!(ixs.len(), expected.len() + 2);
+        assert_eq!(ixs[..expected.len()], expected[..]);
+
+        // The tip account rotates per call, so check the tip by shape
+        let tip = &ixs[expected.len()];
+        assert_eq!(tip.program_id, solana_sdk::system_program::ID);
+        assert_eq!(tip.accounts[0].pubkey, tip_payer);
+        assert!(JITO_TIP_ACCOUNTS.contains(&tip.accounts[1].pubkey));
+
+        assert_eq!(ixs[expected.len() + 1], expected_close(&bonk_buy));
+    }
+
+    #[test]
+    fn only_unbundled_jito_buys_carry_a_tip() {
+        let fee_payer = Pubkey::new_unique();
+
+        assert_eq!(jito_tip_payer("JITO", false, fee_payer), Some(fee_payer));
+        // The bundle's own tip transaction pays instead
+        assert_eq!(jito_tip_payer("JITO", true, fee_payer), None);
+        assert_eq!(jito_tip_payer("NOZOMI", false, fee_payer), None);
+        assert_eq!(jito_tip_payer("ZERO_SLOT", false, fee_payer), None);
+    }
+
+    #[test]
+    fn wrap_buffer_is_wrapped_and_closed_back() {
+        let bonk_buy = test_buy();
+        let with_buffer = BuyIxOptions {
+            wrap_buffer_lamports: 5_000,
+            ..options(true, None, true)
+        };
+        let ixs = assemble_buy_instructions(&bonk_buy, BUY_AMOUNT, MINIMUM_AMOUNT_OUT, &with_buffer).unwrap();
+
+        assert_eq!(wrapped_lamports(&ixs, &bonk_buy), BUY_AMOUNT + 5_000);
+
+        // The buy itself spends exactly the buy amount
+        let buy_ix = ixs.iter().find(|ix| ix.program_id == RAYDIUM_LAUNCHPAD_PROGRAM_ID).unwrap();
+        assert_eq!(parse_bonk_buy_params(&buy_ix.data).unwrap().amount_in, BUY_AMOUNT);
+
+        // Closing the wSOL account hands every remaining lamport back to the payer
+        assert_eq!(ixs.last(), Some(&expected_close(&bonk_buy)));
+    }
+
+    #[test]
+    fn wrap_buffer_is_skipped_without_a_close() {
+        let bonk_buy = test_buy();
+        let with_buffer = BuyIxOptions {
+            wrap_buffer_lamports: 5_000,
+            ..options(false, None, false)
+        };
+        let ixs = assemble_buy_instructions(&bonk_buy, BUY_AMOUNT, MINIMUM_AMOUNT_OUT, &with_buffer).unwrap();
+
+        assert_eq!(wrapped_lamports(&ixs, &bonk_buy), BUY_AMOUNT);
+        assert_eq!(ixs, expected_through_buy(&bonk_buy, false));
+    }
+
+    /// Wallet the ATA vectors below were derived for
+    const ATA_OWNER: Pubkey = Pubkey::from_str_const("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM");
+
+    #[test]
+    fn user_atas_match_known_spl_token_vector() {
+        let mut bonk_buy = test_buy();
+        bonk_buy.base_token_mint = Pubkey::from_str_const("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+
+        let (base_ata, quote_ata) = derive_user_atas(&ATA_OWNER, &bonk_buy);
+        assert_eq!(base_ata, Pubkey::from_str_const("FGETo8T8wMcN2wCjav8VK6eh3dLk63evNDPxzLSJra8B"));
+        assert_eq!(quote_ata, Pubkey::from_str_const("8LjUgMjzZuHj8VdyxzkmLLQVmW4C3gd56md1nLd76TNW"));
+    }
+
+    #[test]
+    fn user_atas_match_known_token_2022_vector() {
+        let mut bonk_buy = test_buy();
+        bonk_buy.base_token_mint = Pubkey::from_str_const("2b1kV6DkPAnxd5ixfnxCpjxmKwqjjaYmCZfHsFu24GXo");
+        bonk_buy.base_token_program = spl_token_2022::ID;
+
+        let (base_ata, _) = derive_user_atas(&ATA_OWNER, &bonk_buy);
+        assert_eq!(base_ata, Pubkey::from_str_const("897krAvWH3RbymaCYE3o9emopUwocieHuKTUk9nySpq6"));
+    }
+
+    #[test]
+    fn forgotten_ata_is_checked_again() {
+        let ata = Pubkey::new_unique();
+        KNOWN_ATAS.lock().unwrap().insert(ata);
+
+        forget_known_ata(&ata);
+        assert!(!KNOWN_ATAS.lock().unwrap().contains(&ata));
+    }
+
+    #[test]
+    fn cached_atas_are_keyed_by_token_program() {
+        let mint = spl_token::native_mint::ID;
+        let classic = cached_ata(&ATA_OWNER, &mint, &spl_token::ID);
+        let token_2022 = cached_ata(&ATA_OWNER, &mint, &spl_token_2022::ID);
+
+        assert_eq!(classic, Pubkey::from_str_const("8LjUgMjzZuHj8VdyxzkmLLQVmW4C3gd56md1nLd76TNW"));
+        assert_eq!(token_2022, Pubkey::from_str_const("DSEcUmCSeNX75D53mJSj2kxvbJYSoAxcLfVcEifzuTV1"));
+    }
+
+    /// A launch transaction: Bonk initialize plus the creator's buy, returning the base mint.
+    fn launch_update(signature: [u8; 64], buy_param: &BonkBuyParam) -> (SubscribeUpdate, Pubkey) {
+        // Keys 0-14 are the buy's accounts in order, with the base mint at 9 and wSOL at 10
+        let mut account_keys: Vec<Pubkey> = (0..16).map(|_| Pubkey::new_unique()).collect();
+        account_keys[10] = spl_token::native_mint::ID;
+        account_keys[15] = RAYDIUM_LAUNCHPAD_PROGRAM_ID;
+        let base_mint = account_keys[9];
+
+        let update = TransactionUpdateBuilder::new()
+            .account_keys(account_keys)
+            // initialize takes the base mint at position 6
+            .instruction(
+                15,
+                vec![0, 1, 2, 3, 4, 5, 9, 10, 7, 8],
+                bonk_initialize_data("Test", "TST", "https://ipfs.io/ipfs/x"),
+            )
+            .instruction(15, (0..15).collect(), bonk_buy_data(buy_param))
+            .signature(signature)
+            .build();
+        (update, base_mint)
+    }
+
+    #[test]
+    fn launch_update_becomes_a_bonk_launch_event() {
+        let buy_param = BonkBuyParam {
+            amount_in: 2_000_000_000,
+            minimum_amount_out: 0,
+            share_fee_rate: 0,
+        };
+        let (update, base_mint) = launch_update([7; 64], &buy_param);
+
+        let (account_keys, ixs, tx_id) = extract_transaction_data(&update).expect("launch update should parse");
+        assert_eq!(tx_id, bs58::encode([7u8; 64]).into_string());
+
+        let (mint_info, buy, parsed_param, migration) = trade_info(ixs, account_keys);
+        assert!(migration.is_none());
+        let event = LaunchEvent::Bonk {
+            mint_info: mint_info.expect("initialize should parse"),
+            buy: buy.expect("buy should parse"),
+            buy_param: parsed_param.expect("buy params should parse"),
+            tx_id: tx_id.clone(),
+        };
+
+        assert_eq!(event.launchpad(), Launchpad::Bonk);
+        assert_eq!(event.mint(), base_mint);
+        assert_eq!(event.tx_id(), tx_id);
+        let LaunchEvent::Bonk { mint_info, buy_param: parsed_param, .. } = &event else {
+            panic!("expected a Bonk launch");
+        };
+        assert_eq!(mint_info.base_mint_param.symbol, "TST");
+        assert_eq!(parsed_param.amount_in, buy_param.amount_in);
+    }
+
+    #[tokio::test]
+    async fn streamed_launch_reaches_a_trade_worker() {
+        set_dry_run(true);
+        let buy_param = BonkBuyParam {
+            amount_in: 2_000_000_000,
+            minimum_amount_out: 0,
+            share_fee_rate: 0,
+        };
+        let (update, _) = launch_update([8; 64], &buy_param);
+        let before = counter_value("trade_opportunities");
+
+        let _workers = start_trade_workers();
+        process_updates_grpc(update_stream(vec![update])).await.unwrap();
+
+        // The stream loop only queues the launch; a worker picks it up asynchronously
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while counter_value("trade_opportunities") == before {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("a trade worker should pick up the streamed launch");
+    }
+
+    #[tokio::test]
+    async fn dry_run_buy_is_built_but_not_submitted() {
+        set_dry_run(true);
+        // Sign the size check against a fixed hash instead of whatever the blockhash handler cached
+        set_blockhash_source(Arc::new(FixedBlockhash(Hash::new_unique())));
+        let wallet = Keypair::new();
+        let bonk_buy = test_buy_for(wallet.pubkey());
+        let curve = BonkCurveParams::Constant(BonkConstantCurve {
+            supply: 1_000_000_000_000_000,
+            total_base_sell: 793_100_000_000_000,
+            total_quote_fund_raising: 85_000_000_000,
+            migrate_type: 1,
+        });
+        let buy_param = BonkBuyParam {
+            amount_in: BUY_AMOUNT,
+            minimum_amount_out: MINIMUM_AMOUNT_OUT,
+            share_fee_rate: 0,
+        };
+        let mut submitted = false;
+
+        let signature = execute_buy_transaction(
+            &bonk_buy,
+            &buy_param,
+            &curve,
+            &MintSupport::Standard,
+            BUY_AMOUNT,
+            MINIMUM_AMOUNT_OUT,
+            &wallet,
+            TradeRole::Primary,
+            &mut submitted,
+        )
+        .await
+        .expect("dry-run buy should build");
+
+        assert!(signature.is_none());
+        assert!(!submitted);
+    }
 }
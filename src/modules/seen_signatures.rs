@@ -0,0 +1,107 @@
+/**
+ * 🧾 Seen Signatures Module - Bonk.fun Trading Sniper Bot
+ *
+ * A resumed or hiccuping stream can deliver the same transaction more
+ * than once. The processing loop remembers the most recent signatures
+ * and skips any it has already handled, before launch detection runs.
+ *
+ * Key Features:
+ * - Bounded by `[grpc].seen_signatures_capacity`, so memory stays flat
+ * - Oldest signature evicted first once full
+ * - Suppressed repeats counted in `duplicate_signatures_skipped`
+ *
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use std::collections::{HashSet, VecDeque};
+
+/// Recently processed transaction signatures, oldest first
+#[derive(Debug)]
+pub struct SeenSignatures {
+    capacity: usize,
+    seen: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl SeenSignatures {
+    pub fn new(capacity: usize) -> Self {
+        SeenSignatures {
+            capacity,
+            seen: HashSet::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /**
+     * Records a signature, reporting whether it was new
+     *
+     * A repeat doesn't refresh its position: replays arrive shortly after
+     * the original, well inside the window, so eviction order stays by
+     * first sighting.
+     *
+     * @param tx_id - Base58 transaction signature
+     * @returns bool - False if the signature was already recorded
+     */
+    pub fn insert(&mut self, tx_id: &str) -> bool {
+        if self.capacity == 0 {
+            return true;
+        }
+        if self.seen.contains(tx_id) {
+            return false;
+        }
+
+        if self.order.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        self.seen.insert(tx_id.to_string());
+        self.order.push_back(tx_id.to_string());
+        true
+    }
+
+    /// Number of signatures remembered
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeat_signature_is_rejected() {
+        let mut seen = SeenSignatures::new(4);
+        assert!(seen.insert("a"));
+        assert!(!seen.insert("a"));
+        assert_eq!(seen.len(), 1);
+    }
+
+    #[test]
+    fn oldest_signature_is_evicted_at_capacity() {
+        let mut seen = SeenSignatures::new(2);
+        seen.insert("a");
+        seen.insert("b");
+        assert!(seen.insert("c"));
+        assert_eq!(seen.len(), 2);
+
+        // "a" fell out, so it counts as new again while "c" is still remembered
+        assert!(seen.insert("a"));
+        assert!(!seen.insert("c"));
+    }
+
+    #[test]
+    fn zero_capacity_disables_dedup() {
+        let mut seen = SeenSignatures::new(0);
+        assert!(seen.insert("a"));
+        assert!(seen.insert("a"));
+        assert!(seen.is_empty());
+    }
+}
@@ -0,0 +1,137 @@
+/**
+ * 🪙 Token Program Module - Bonk.fun Trading Sniper Bot
+ * 
+ * This module inspects a launch's base mint to decide whether the buy path
+ * can handle it. Legacy SPL Token mints are always supported; Token-2022
+ * mints are read on-chain so transfer fees can be accounted for and mints
+ * with extensions we don't handle (transfer hooks, non-transferable, ...)
+ * can be skipped instead of bought blind.
+ * 
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use solana_sdk::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
+use spl_token_2022::{
+    extension::{
+        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+        transfer_fee::{TransferFee, TransferFeeConfig},
+    },
+    state::Mint,
+};
+
+use crate::RPC_CLIENT;
+
+/// Token-2022 extensions the buy path can safely handle
+const SUPPORTED_MINT_EXTENSIONS: &[ExtensionType] = &[
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+    ExtensionType::MintCloseAuthority,
+];
+
+/// How the buy path should treat a base mint
+#[derive(Debug, Clone)]
+pub enum MintSupport {
+    /// Legacy SPL Token mint
+    Standard,
+    /// Token-2022 mint, with the transfer fee in effect this epoch if any
+    Token2022 { transfer_fee: Option<TransferFee> },
+    /// Mint the buy path can't handle, with the reason
+    Unsupported(String),
+}
+
+impl MintSupport {
+    /// Amount actually received after any transfer fee is withheld
+    pub fn net_amount(&self, gross: u64) -> u64 {
+        match self {
+            MintSupport::Token2022 { transfer_fee: Some(fee) } => {
+                gross.saturating_sub(fee.calculate_fee(gross).unwrap_or(gross))
+            }
+            _ => gross,
+        }
+    }
+}
+
+/**
+ * Determines whether a base mint is supported by the buy path
+ * 
+ * @param mint - Base token mint
+ * @param token_program - Token program owning the mint
+ * @returns Result<MintSupport, Box<dyn std::error::Error>> - Support verdict
+ */
+pub async fn inspect_base_mint(
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<MintSupport, Box<dyn std::error::Error>> {
+    if *token_program == spl_token::ID {
+        return Ok(MintSupport::Standard);
+    }
+    if *token_program != spl_token_2022::ID {
+        return Ok(MintSupport::Unsupported(format!("unknown token program {}", token_program)));
+    }
+
+    let data = RPC_CLIENT.get_account_data(mint).await?;
+    let state = StateWithExtensions::<Mint>::unpack(&data)?;
+
+    let extensions = state.get_extension_types()?;
+    if let Some(extension) = extensions
+        .iter()
+        .find(|extension| !SUPPORTED_MINT_EXTENSIONS.contains(extension))
+    {
+        return Ok(MintSupport::Unsupported(format!("unsupported extension {:?}", extension)));
+    }
+
+    let transfer_fee = match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => {
+            let epoch = RPC_CLIENT.get_epoch_info().await?.epoch;
+            Some(*config.get_epoch_fee(epoch))
+        }
+        Err(_) => None,
+    };
+
+    Ok(MintSupport::Token2022 { transfer_fee })
+}
+
+/**
+ * Builds a `sync_native` instruction for the given token program
+ * 
+ * @param token_program - Token program owning the wrapped SOL account
+ * @param account - Wrapped SOL token account
+ * @returns Result<Instruction, ProgramError> - Sync instruction
+ */
+pub fn sync_native_ix(token_program: &Pubkey, account: &Pubkey) -> Result<Instruction, ProgramError> {
+    if *token_program == spl_token_2022::ID {
+        spl_token_2022::instruction::sync_native(token_program, account)
+    } else {
+        spl_token::instruction::sync_native(token_program, account)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_fee(basis_points: u16, maximum_fee: u64) -> MintSupport {
+        MintSupport::Token2022 {
+            transfer_fee: Some(TransferFee {
+                epoch: 0.into(),
+                maximum_fee: maximum_fee.into(),
+                transfer_fee_basis_points: basis_points.into(),
+            }),
+        }
+    }
+
+    #[test]
+    fn net_amount_withholds_the_transfer_fee() {
+        assert_eq!(with_fee(100, u64::MAX).net_amount(1_000_000), 990_000);
+        assert_eq!(with_fee(100, 500).net_amount(1_000_000), 999_500);
+    }
+
+    #[test]
+    fn net_amount_is_gross_without_a_fee() {
+        assert_eq!(MintSupport::Standard.net_amount(1_000_000), 1_000_000);
+        assert_eq!(MintSupport::Token2022 { transfer_fee: None }.net_amount(1_000_000), 1_000_000);
+    }
+}
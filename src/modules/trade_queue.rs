@@ -0,0 +1,204 @@
+/**
+ * 📬 Trade Queue Module - Bonk.fun Trading Sniper Bot
+ *
+ * This module decouples the stream loop from trade execution. The loop
+ * only parses and enqueues launches; a fixed pool of workers drains the
+ * queue and runs the trades, so slow submissions can't hold up parsing.
+ *
+ * Key Features:
+ * - Bounded queue sized by `[trade].queue_capacity`
+ * - `[trade].trade_workers` workers executing trades concurrently
+ * - `[trade].queue_full_policy` decides which launch to drop when full
+ * - Drops counted in `trade_queue_dropped`, depth in `trade_queue_depth`
+ *
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use once_cell::sync::Lazy;
+use std::{collections::VecDeque, sync::Mutex};
+use tokio::{sync::Notify, task::JoinHandle};
+
+use crate::{CONFIG, LaunchEvent, increment_counter, run_trade_task, set_gauge};
+
+static TRADE_QUEUE: Lazy<TradeQueue> = Lazy::new(|| {
+    TradeQueue::new(
+        CONFIG.trade.queue_capacity.max(1),
+        CONFIG.trade.queue_full_policy == "drop_oldest",
+    )
+});
+
+/// Bounded FIFO of launches waiting for a trade worker
+pub struct TradeQueue {
+    events: Mutex<VecDeque<LaunchEvent>>,
+    ready: Notify,
+    capacity: usize,
+    drop_oldest: bool,
+}
+
+impl TradeQueue {
+    pub fn new(capacity: usize, drop_oldest: bool) -> Self {
+        TradeQueue {
+            events: Mutex::new(VecDeque::with_capacity(capacity)),
+            ready: Notify::new(),
+            capacity,
+            drop_oldest,
+        }
+    }
+
+    /**
+     * Adds a launch, dropping one per the full-queue policy if needed
+     *
+     * Never waits, so the stream loop keeps its pace under bursts.
+     *
+     * @param event - Detected launch event
+     * @returns Option<LaunchEvent> - The launch that was dropped, if any
+     */
+    pub fn push(&self, event: LaunchEvent) -> Option<LaunchEvent> {
+        let mut events = self.events.lock().unwrap();
+
+        let dropped = if events.len() < self.capacity {
+            events.push_back(event);
+            None
+        } else if self.drop_oldest {
+            let oldest = events.pop_front();
+            events.push_back(event);
+            oldest
+        } else {
+            Some(event)
+        };
+
+        set_gauge("trade_queue_depth", events.len() as f64);
+        drop(events);
+        self.ready.notify_one();
+        dropped
+    }
+
+    /// Waits for the next launch
+    pub async fn pop(&self) -> LaunchEvent {
+        loop {
+            if let Some(event) = self.events.lock().unwrap().pop_front() {
+                // Pass the wakeup on in case more launches are waiting
+                self.ready.notify_one();
+                return event;
+            }
+            self.ready.notified().await;
+        }
+    }
+
+    /// Number of launches waiting
+    pub fn len(&self) -> usize {
+        self.events.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Queues a launch for the trade workers, counting it if the queue is full
+pub fn enqueue_trade(event: LaunchEvent) {
+    if let Some(dropped) = TRADE_QUEUE.push(event) {
+        increment_counter("trade_queue_dropped");
+        println!(
+            "🗑️ Trade queue full ({}), dropped launch in TX {} ({})",
+            TRADE_QUEUE.capacity,
+            dropped.tx_id(),
+            CONFIG.trade.queue_full_policy
+        );
+    }
+}
+
+/**
+ * Starts the trade workers
+ *
+ * @returns Vec<JoinHandle<()>> - One handle per worker
+ */
+pub fn start_trade_workers() -> Vec<JoinHandle<()>> {
+    let workers = CONFIG.trade.trade_workers.max(1);
+    println!("👷 Starting {} trade workers (queue capacity {})", workers, TRADE_QUEUE.capacity);
+
+    (0..workers)
+        .map(|_| {
+            tokio::spawn(async {
+                loop {
+                    let event = TRADE_QUEUE.pop().await;
+                    set_gauge("trade_queue_depth", TRADE_QUEUE.len() as f64);
+                    run_trade_task(event).await;
+                }
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::pubkey::Pubkey;
+    use std::{sync::Arc, time::Duration};
+
+    fn launch(tx_id: &str) -> LaunchEvent {
+        LaunchEvent::Migration {
+            mint: Pubkey::new_unique(),
+            quote_mint: spl_token::native_mint::ID,
+            migrate_type: 0,
+            tx_id: tx_id.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn full_queue_drops_the_oldest_launch() {
+        let queue = TradeQueue::new(2, true);
+        assert!(queue.push(launch("a")).is_none());
+        assert!(queue.push(launch("b")).is_none());
+
+        let dropped = queue.push(launch("c")).expect("queue was full");
+        assert_eq!(dropped.tx_id(), "a");
+        assert_eq!(queue.pop().await.tx_id(), "b");
+        assert_eq!(queue.pop().await.tx_id(), "c");
+    }
+
+    #[tokio::test]
+    async fn full_queue_drops_the_newest_launch() {
+        let queue = TradeQueue::new(2, false);
+        queue.push(launch("a"));
+        queue.push(launch("b"));
+
+        let dropped = queue.push(launch("c")).expect("queue was full");
+        assert_eq!(dropped.tx_id(), "c");
+        assert_eq!(queue.pop().await.tx_id(), "a");
+        assert_eq!(queue.pop().await.tx_id(), "b");
+    }
+
+    #[tokio::test]
+    async fn launches_come_out_in_order() {
+        let queue = TradeQueue::new(2, false);
+        queue.push(launch("a"));
+        assert_eq!(queue.pop().await.tx_id(), "a");
+        queue.push(launch("b"));
+        queue.push(launch("c"));
+
+        assert_eq!(queue.pop().await.tx_id(), "b");
+        assert_eq!(queue.pop().await.tx_id(), "c");
+        assert!(queue.is_empty());
+    }
+
+    #[tokio::test]
+    async fn push_wakes_a_waiting_pop() {
+        let queue = Arc::new(TradeQueue::new(2, false));
+        let waiting = tokio::spawn({
+            let queue = queue.clone();
+            async move { queue.pop().await }
+        });
+        // Let the worker find the queue empty and start waiting
+        tokio::task::yield_now().await;
+
+        queue.push(launch("a"));
+        let event = tokio::time::timeout(Duration::from_secs(1), waiting)
+            .await
+            .expect("push should wake the waiting pop")
+            .unwrap();
+        assert_eq!(event.tx_id(), "a");
+    }
+}
@@ -0,0 +1,516 @@
+/**
+ * 🎯 Sniper Orchestration Module - Bonk.fun Trading Sniper Bot
+ * 
+ * This module wires the services, background tasks and gRPC monitoring
+ * together behind the `Sniper` type, so the bot can be embedded in another
+ * program as well as run from the command line.
+ * 
+ * Embedding:
+ * - `Sniper::new(config)` installs the configuration
+ * - `on_event` registers a callback for launches and trade results
+ * - `subscribe_raw` taps every raw `SubscribeUpdate` off the stream
+ * - `run(shutdown)` runs until the token is cancelled
+ * 
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use once_cell::sync::Lazy;
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+use std::{
+    net::SocketAddr,
+    sync::{Arc, RwLock},
+};
+use tokio::{net::TcpListener, sync::broadcast, task::JoinHandle};
+pub use tokio_util::sync::CancellationToken;
+use yellowstone_grpc_client::{GeyserGrpcClient, Interceptor};
+use yellowstone_grpc_proto::geyser::{SubscribeRequestFilterTransactions, SubscribeUpdate};
+
+use crate::*;
+
+/// Something the sniper did that an embedding program may want to see
+#[derive(Debug, Clone)]
+pub enum SniperEvent {
+    /// A launch or migration was detected on the stream, before any filtering
+    Launch(LaunchEvent),
+    /// Trade handling for a launch finished; `error` is None on success or a filtered skip
+    TradeCompleted { tx_id: String, mint: Pubkey, error: Option<String> },
+}
+
+type EventHook = Arc<dyn Fn(&SniperEvent) + Send + Sync>;
+
+static EVENT_HOOKS: Lazy<RwLock<Vec<EventHook>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+/// Passes an event to every registered hook
+pub fn emit_sniper_event(event: SniperEvent) {
+    let hooks = EVENT_HOOKS.read().unwrap().clone();
+    for hook in hooks {
+        hook(&event);
+    }
+}
+
+/// Raw stream updates for `Sniper::subscribe_raw` receivers
+static RAW_UPDATES: Lazy<broadcast::Sender<SubscribeUpdate>> =
+    Lazy::new(|| broadcast::channel(CONFIG.grpc.raw_channel_capacity.max(1)).0);
+
+/// Forwards a raw update to `subscribe_raw` receivers; the update is only cloned when one is listening
+pub fn forward_raw_update(update: &SubscribeUpdate) {
+    if RAW_UPDATES.receiver_count() > 0 {
+        // Errors only when every receiver dropped in the meantime
+        let _ = RAW_UPDATES.send(update.clone());
+    }
+}
+
+/// The sniping bot: services, background tasks and the gRPC pipeline
+pub struct Sniper {
+    config: &'static Config,
+}
+
+impl Sniper {
+    /**
+     * Installs the configuration for this process
+     * 
+     * The configuration is process-wide, so only one `Sniper` can be
+     * created per process.
+     * 
+     * @param config - Parsed configuration
+     * @returns Result<Sniper, ConfigError> - Sniper, or an error if a config is already loaded
+     */
+    pub fn new(config: Config) -> Result<Self, ConfigError> {
+        let config = install_config(config)?;
+        set_quiet(config.log.quiet);
+        Ok(Sniper { config })
+    }
+
+    /// Registers a callback for launches and trade results; hooks run on the pipeline tasks, so keep them quick
+    pub fn on_event(self, hook: impl Fn(&SniperEvent) + Send + Sync + 'static) -> Self {
+        EVENT_HOOKS.write().unwrap().push(Arc::new(hook));
+        self
+    }
+
+    /**
+     * Subscribes to every raw `SubscribeUpdate` received from the stream
+     * 
+     * Updates are forwarded before the sniper processes them. A receiver
+     * that falls more than `[grpc].raw_channel_capacity` updates behind
+     * gets `RecvError::Lagged` and skips ahead.
+     * 
+     * @returns broadcast::Receiver<SubscribeUpdate> - Receiver of raw updates
+     */
+    pub fn subscribe_raw(&self) -> broadcast::Receiver<SubscribeUpdate> {
+        RAW_UPDATES.subscribe()
+    }
+
+    /// The configuration this sniper runs with
+    pub fn config(&self) -> &'static Config {
+        self.config
+    }
+
+    /**
+     * Runs the live sniping pipeline until `shutdown` is cancelled
+     * 
+     * Background tasks are stopped before returning. An error is returned
+     * for an invalid config, a service that fails to start, or a gRPC
+     * endpoint that's unreachable at startup.
+     * 
+     * @param shutdown - Token that stops the sniper when cancelled
+     * @returns Result<(), Box<dyn std::error::Error>> - Success or error
+     */
+    pub async fn run(self, shutdown: CancellationToken) -> Result<(), Box<dyn std::error::Error>> {
+        if let Err(errors) = validate_all_configuration() {
+            return Err(format!("configuration is invalid: {}", errors.join("; ")).into());
+        }
+        
+        if is_dry_run() {
+            println!("🧪 Dry run enabled: trades will be built but not submitted");
+        }
+        
+        // Build the filter set from config up front rather than on the first launch
+        println!("🧹 {} launch filters active", launch_filters().len());
+        
+        // Initialize external services and global state
+        initialize_services().await?;
+        
+        // Start background tasks for optimal performance
+        let tasks = start_background_tasks().await?;
+        
+        // Setup and start gRPC transaction monitoring
+        let result = tokio::select! {
+            result = start_transaction_monitoring() => result,
+            _ = shutdown.cancelled() => {
+                println!("🛑 Shutdown requested, stopping sniper");
+                Ok(())
+            }
+        };
+        
+        for task in tasks {
+            task.abort();
+        }
+        STATS.print_summary();
+        result
+    }
+}
+
+/**
+ * Initializes the selected confirmation service
+ * 
+ * Only the service named by `confirm_service` is brought up, so keys for
+ * the others are never required. Initialization is bounded by
+ * `[services].init_timeout_ms` so a hanging relayer can't stall startup.
+ * 
+ * @returns Result<(), Box<dyn std::error::Error>> - Success or error
+ */
+async fn initialize_services() -> Result<(), Box<dyn std::error::Error>> {
+    // Build the send client now rather than on the first buy
+    let _ = SEND_RPC_CLIENT.clone();
+    
+    let service = CONFIRM_SERVICE.as_str();
+    println!("🔧 Initializing confirmation service: {}", service);
+    
+    #[cfg(not(feature = "relayers"))]
+    println!("⚠️ Built without the relayers feature: {} is not used, transactions go over RPC", service);
+    
+    let init = async {
+        match service {
+            #[cfg(feature = "relayers")]
+            "NOZOMI" => init_nozomi().await,
+            #[cfg(feature = "relayers")]
+            "ZERO_SLOT" => init_zslot().await,
+            #[cfg(feature = "relayers")]
+            "JITO" => init_jito().await,
+            _ => Ok::<(), String>(()),
+        }
+    };
+    
+    let timeout = tokio::time::Duration::from_millis(CONFIG.services.init_timeout_ms);
+    match tokio::time::timeout(timeout, init).await {
+        Ok(Ok(())) => {
+            println!("✅ {} initialized successfully", service);
+            Ok(())
+        }
+        Ok(Err(e)) => Err(format!("Failed to initialize {}: {}", service, e).into()),
+        Err(_) => Err(format!(
+            "Timed out initializing {} after {}ms",
+            service, CONFIG.services.init_timeout_ms
+        )
+        .into()),
+    }
+}
+
+/**
+ * Starts background tasks for optimal performance
+ * 
+ * Background tasks include:
+ * - Blockhash management for transaction signing
+ * - Trade workers executing queued launches
+ * - Recent prioritization fee tracking, when `[priority_fee].dynamic` is set
+ * - Wallet balance caching for the pre-buy balance guard
+ * - Confirmation service health monitoring
+ * - Health monitoring and metrics collection
+ * - Connection keep-alive management
+ * - Position monitoring for max-hold exits
+ * - Control endpoint, when `[control].enabled` is set
+ * 
+ * @returns Result<Vec<JoinHandle<()>>, Box<dyn std::error::Error>> - Handles of the started tasks
+ */
+pub async fn start_background_tasks() -> Result<Vec<JoinHandle<()>>, Box<dyn std::error::Error>> {
+    println!("🔄 Starting background tasks...");
+    let mut tasks = Vec::new();
+    
+    // Start blockhash handler loop in background for optimal performance
+    tasks.push(tokio::spawn(async {
+        println!("📡 Blockhash handler started");
+        loop {
+            match recent_blockhash_handler(RPC_CLIENT.clone()).await {
+                Ok(_) => {
+                    // Successfully updated blockhash
+                }
+                Err(e) => {
+                    eprintln!("❌ Blockhash handler error: {}", e);
+                    // Continue running despite errors
+                }
+            }
+            
+            // Small delay to prevent excessive RPC calls
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        }
+    }));
+    
+    // Follow network fees when [priority_fee].dynamic is set
+    if CONFIG.priority_fee.dynamic {
+        tasks.push(tokio::spawn(async {
+            println!("⛽ Priority fee handler started (p{})", CONFIG.priority_fee.dynamic_percentile);
+            loop {
+                priority_fee_handler(RPC_CLIENT.clone()).await;
+            }
+        }));
+    }
+    
+    // Keep the cached wallet balance fresh for the pre-buy balance guard
+    tasks.push(tokio::spawn(async {
+        println!("💼 Wallet balance handler started");
+        let wallets: Vec<_> = WALLETS.iter().chain(SHADOW_WALLET.as_ref()).map(|wallet| wallet.pubkey()).collect();
+        loop {
+            wallet_balance_handler(RPC_CLIENT.clone(), &wallets).await;
+        }
+    }));
+    
+    // Track how long the gRPC stream has gone without an update
+    tasks.push(tokio::spawn(async {
+        println!("💓 Stream health monitor started");
+        loop {
+            stream_health_handler().await;
+        }
+    }));
+    
+    // Periodically verify the confirmation service and RPC are reachable
+    tasks.push(tokio::spawn(async {
+        println!("🩺 Relayer health monitor started");
+        loop {
+            relayer_health_handler().await;
+        }
+    }));
+    
+    // Pause buying while the [trade].kill_switch_path file exists
+    if let Some(path) = CONFIG.trade.kill_switch_path.as_deref() {
+        let path = std::path::PathBuf::from(path);
+        tasks.push(tokio::spawn(async move {
+            println!("⛔ Kill switch watcher started ({})", path.display());
+            loop {
+                kill_switch_handler(&path).await;
+            }
+        }));
+    }
+    
+    // Trade workers draining the launch queue filled by the stream loop
+    tasks.extend(start_trade_workers());
+    
+    // Poll position reserves, force-sell positions held past [trade].max_hold_seconds and run exit ladders
+    tasks.push(tokio::spawn(async {
+        println!(
+            "⏰ Position monitor started (every {}ms, max hold {}s)",
+            CONFIG.trade.position_poll_interval_ms, CONFIG.trade.max_hold_seconds
+        );
+        loop {
+            position_monitor_handler().await;
+        }
+    }));
+    
+    // Local control endpoint for listing and dumping positions
+    if CONFIG.control.enabled {
+        let bind: SocketAddr = CONFIG.control.bind.parse()?;
+        let listener = TcpListener::bind(bind).await?;
+        println!("🎛️ Control endpoint listening on http://{}", bind);
+        tasks.push(tokio::spawn(run_control_server(listener)));
+    }
+    
+    println!("✅ Background tasks started successfully");
+    Ok(tasks)
+}
+
+/**
+ * Accepts control connections and serves each on its own task
+ * 
+ * @param listener - Listener bound to `[control].bind`
+ */
+async fn run_control_server(listener: TcpListener) {
+    loop {
+        match listener.accept().await {
+            Ok((socket, _)) => {
+                tokio::spawn(async move {
+                    if let Err(e) = serve_control_connection(socket).await {
+                        eprintln!("❌ Control request failed: {}", e);
+                    }
+                });
+            }
+            Err(e) => eprintln!("❌ Control endpoint accept failed: {}", e),
+        }
+    }
+}
+
+/**
+ * Sets up and starts gRPC transaction monitoring
+ * 
+ * This function supervises the subscription:
+ * 1. Establishes gRPC connection to Helius Laserstream, retrying the
+ *    first connect up to `[grpc].connect_max_attempts` times with backoff
+ * 2. Configures transaction filters for Bonk.fun programs
+ * 3. Starts processing transaction updates
+ * 4. Reconnects when the stream ends, resuming from the last seen slot
+ *    when `[grpc].resume_on_reconnect` is enabled
+ * 
+ * Retries back off exponentially up to `[grpc].reconnect_max_delay_ms`,
+ * with full jitter unless `[grpc].backoff_jitter` is off. A subscription
+ * that stayed up longer than that cap resets the reconnect backoff.
+ * 
+ * The first subscription resumes from `[grpc].slot_state_path` when it
+ * holds a recent enough slot.
+ * 
+ * @returns Result<(), Box<dyn std::error::Error>> - Error only if the endpoint is unreachable at startup
+ */
+async fn start_transaction_monitoring() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🌐 Setting up gRPC transaction monitoring...");
+    
+    let reconnect_cap_ms = CONFIG.grpc.reconnect_max_delay_ms;
+    let max_connect_attempts = CONFIG.grpc.connect_max_attempts.max(1);
+    let mut connect_attempt = 0u32;
+    let mut reconnect_attempt = 0u32;
+    let mut cold_start = true;
+    let mut from_slot = startup_from_slot().await;
+    
+    loop {
+        let grpc_client = match setup_client_grpc(GRPC_ENDPOINT.to_string(), GRPC_TOKEN.to_string()).await {
+            Ok(client) => {
+                println!("✅ gRPC client connected successfully");
+                cold_start = false;
+                client
+            }
+            // A freshly deployed endpoint can be briefly unreachable; don't die on the first try
+            Err(e) if cold_start => {
+                connect_attempt += 1;
+                if connect_attempt >= max_connect_attempts {
+                    eprintln!("❌ Failed to connect to gRPC after {} attempts: {}", connect_attempt, e);
+                    return Err(e);
+                }
+                
+                let delay = backoff_delay_ms(
+                    CONFIG.grpc.connect_retry_delay_ms,
+                    reconnect_cap_ms,
+                    connect_attempt - 1,
+                    CONFIG.grpc.backoff_jitter,
+                );
+                println!(
+                    "🔁 gRPC connect attempt {}/{} failed: {}; retrying in {}ms",
+                    connect_attempt, max_connect_attempts, e, delay
+                );
+                tokio::time::sleep(tokio::time::Duration::from_millis(delay)).await;
+                continue;
+            }
+            Err(e) => {
+                let delay = backoff_delay_ms(
+                    CONFIG.grpc.reconnect_delay_ms,
+                    reconnect_cap_ms,
+                    reconnect_attempt,
+                    CONFIG.grpc.backoff_jitter,
+                );
+                reconnect_attempt = reconnect_attempt.saturating_add(1);
+                eprintln!("❌ Failed to connect to gRPC: {}; retrying in {}ms", e, delay);
+                tokio::time::sleep(tokio::time::Duration::from_millis(delay)).await;
+                continue;
+            }
+        };
+        
+        let subscribed_at = std::time::Instant::now();
+        if let Err(e) = run_subscription(grpc_client, from_slot).await {
+            eprintln!("❌ gRPC subscription failed: {}", e);
+        }
+        
+        // A long-lived stream means the endpoint recovered; start the backoff over
+        if subscribed_at.elapsed().as_millis() as u64 > reconnect_cap_ms {
+            reconnect_attempt = 0;
+        }
+        
+        let delay = backoff_delay_ms(
+            CONFIG.grpc.reconnect_delay_ms,
+            reconnect_cap_ms,
+            reconnect_attempt,
+            CONFIG.grpc.backoff_jitter,
+        );
+        reconnect_attempt = reconnect_attempt.saturating_add(1);
+        println!("🔄 Reconnecting to gRPC in {}ms...", delay);
+        tokio::time::sleep(tokio::time::Duration::from_millis(delay)).await;
+        from_slot = resume_from_slot(last_seen_slot(), get_current_slot());
+    }
+}
+
+/**
+ * Subscribes on a connected client and processes updates until the stream ends
+ * 
+ * @param grpc_client - Connected gRPC client
+ * @param from_slot - Slot to resume from, or None to start at the tip
+ * @returns Result<(), Box<dyn std::error::Error>> - Success or error
+ */
+async fn run_subscription<I: Interceptor>(
+    mut grpc_client: GeyserGrpcClient<I>,
+    from_slot: Option<u64>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Setup subscription channel
+    let (subscribe_tx, subscribe_rx) = match grpc_client.subscribe().await {
+        Ok(channel) => {
+            println!("✅ gRPC subscription channel established");
+            channel
+        }
+        Err(e) => {
+            eprintln!("❌ Failed to create subscription channel: {}", e);
+            return Err(Box::new(e));
+        }
+    };
+
+    // Configure transaction filters for Bonk.fun programs
+    let subscribe_filter = create_transaction_filter();
+    
+    // Send subscription request with error handling
+    match send_subscription_request_grpc(subscribe_tx, subscribe_filter, from_slot).await {
+        Ok(_) => {
+            println!("✅ Transaction filter subscription sent successfully");
+        }
+        Err(e) => {
+            eprintln!("❌ Failed to send subscription request: {}", e);
+            return Err(e);
+        }
+    }
+
+    // Make sure the subscription is actually delivering data before relying on it
+    let update_stream = match await_first_update(subscribe_rx).await {
+        Ok(stream) => stream,
+        Err(e) => {
+            eprintln!("❌ gRPC preflight failed: {}", e);
+            return Err(e);
+        }
+    };
+
+    // Start processing transaction updates with comprehensive error handling
+    println!("🎯 Starting transaction processing loop...");
+    match process_updates_grpc(update_stream).await {
+        Ok(_) => {
+            println!("⚠️ Transaction stream ended");
+        }
+        Err(e) => {
+            eprintln!("❌ Error processing transaction updates: {}", e);
+            return Err(e);
+        }
+    }
+
+    Ok(())
+}
+
+/**
+ * Creates transaction filter for Bonk.fun programs
+ * 
+ * This function configures which programs to monitor:
+ * - Moonshot program for token launches
+ * - Pump.fun program for pump tokens
+ * - Raydium launchpad for liquidity events
+ * 
+ * Vote and failed transactions are excluded unless `[grpc].include_vote`
+ * or `[grpc].include_failed` ask for them.
+ * 
+ * @returns SubscribeRequestFilterTransactions - Configured filter
+ */
+fn create_transaction_filter() -> SubscribeRequestFilterTransactions {
+    println!("🔍 Configuring transaction filters...");
+    
+    SubscribeRequestFilterTransactions {
+        account_include: vec![
+            MOONSHOT_PROGRAM_ID.to_string(),
+            PUMP_FUN_PROGRAM_ID.to_string(),
+            RAYDIUM_LAUNCHPAD_PROGRAM_ID.to_string(),
+        ],
+        account_exclude: vec![],
+        account_required: vec![],
+        vote: Some(CONFIG.grpc.include_vote),
+        failed: Some(CONFIG.grpc.include_failed),
+        signature: None,
+    }
+}
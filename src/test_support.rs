@@ -0,0 +1,248 @@
+/**
+ * 🧪 Test Support Module - Bonk.fun Trading Sniper Bot
+ * 
+ * Builders for synthetic `SubscribeUpdate` frames so the processing
+ * pipeline can be driven without a live gRPC connection, plus a fixed
+ * blockhash and a manual clock for deterministic trade-path runs. Built
+ * for unit tests, and for benches with the `test-support` cargo feature.
+ * 
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use borsh::BorshSerialize;
+use futures::Stream;
+use solana_sdk::{hash::Hash, pubkey::Pubkey};
+use std::{
+    ops::Deref,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+use yellowstone_grpc_proto::{
+    geyser::{
+        SubscribeUpdate, SubscribeUpdateTransaction, SubscribeUpdateTransactionInfo,
+        subscribe_update::UpdateOneof,
+    },
+    prelude::{CompiledInstruction, Message, MessageAddressTableLookup, Transaction, TransactionStatusMeta},
+    tonic::Status,
+};
+
+use crate::{
+    BONK_BUY_IN_DISC, BONK_INIT_DISC, BlockhashSource, BonkBuyParam, Clock, SystemClock, replay_stream,
+    set_clock,
+};
+
+/// Builds a transaction `SubscribeUpdate` from account keys and instructions
+#[derive(Debug, Default, Clone)]
+pub struct TransactionUpdateBuilder {
+    account_keys: Vec<Pubkey>,
+    loaded_writable: Vec<Pubkey>,
+    loaded_readonly: Vec<Pubkey>,
+    instructions: Vec<CompiledInstruction>,
+    signature: Vec<u8>,
+    slot: u64,
+    without_meta: bool,
+}
+
+impl TransactionUpdateBuilder {
+    pub fn new() -> Self {
+        Self {
+            signature: vec![1; 64],
+            ..Default::default()
+        }
+    }
+
+    pub fn account_keys(mut self, keys: Vec<Pubkey>) -> Self {
+        self.account_keys = keys;
+        self
+    }
+
+    /// Appends lookup-table addresses after the static keys, described by one table lookup
+    pub fn loaded_addresses(mut self, writable: Vec<Pubkey>, readonly: Vec<Pubkey>) -> Self {
+        self.loaded_writable = writable;
+        self.loaded_readonly = readonly;
+        self
+    }
+
+    pub fn instruction(mut self, program_id_index: u32, accounts: Vec<u8>, data: Vec<u8>) -> Self {
+        self.instructions.push(CompiledInstruction {
+            program_id_index,
+            accounts,
+            data,
+        });
+        self
+    }
+
+    pub fn signature(mut self, signature: [u8; 64]) -> Self {
+        self.signature = signature.to_vec();
+        self
+    }
+
+    pub fn slot(mut self, slot: u64) -> Self {
+        self.slot = slot;
+        self
+    }
+
+    /// Leaves `meta` unset, like a `processed` update that arrived before it
+    pub fn without_meta(mut self) -> Self {
+        self.without_meta = true;
+        self
+    }
+
+    pub fn build(self) -> SubscribeUpdate {
+        let to_bytes = |keys: &[Pubkey]| keys.iter().map(|key| key.to_bytes().to_vec()).collect();
+
+        // The lookup's index counts must match the loaded addresses, or the parser rejects the message
+        let address_table_lookups = if self.loaded_writable.is_empty() && self.loaded_readonly.is_empty() {
+            Vec::new()
+        } else {
+            vec![MessageAddressTableLookup {
+                account_key: Pubkey::default().to_bytes().to_vec(),
+                writable_indexes: (0..self.loaded_writable.len() as u8).collect(),
+                readonly_indexes: (0..self.loaded_readonly.len() as u8).collect(),
+            }]
+        };
+
+        let message = Message {
+            account_keys: to_bytes(&self.account_keys),
+            instructions: self.instructions,
+            address_table_lookups,
+            ..Default::default()
+        };
+
+        let meta = TransactionStatusMeta {
+            loaded_writable_addresses: to_bytes(&self.loaded_writable),
+            loaded_readonly_addresses: to_bytes(&self.loaded_readonly),
+            ..Default::default()
+        };
+
+        SubscribeUpdate {
+            update_oneof: Some(UpdateOneof::Transaction(SubscribeUpdateTransaction {
+                transaction: Some(SubscribeUpdateTransactionInfo {
+                    signature: self.signature.clone(),
+                    transaction: Some(Transaction {
+                        signatures: vec![self.signature],
+                        message: Some(message),
+                    }),
+                    meta: (!self.without_meta).then_some(meta),
+                    ..Default::default()
+                }),
+                slot: self.slot,
+            })),
+            ..Default::default()
+        }
+    }
+}
+
+/// Encodes Raydium Launchpad `initialize` data for a constant-curve launch
+pub fn bonk_initialize_data(name: &str, symbol: &str, uri: &str) -> Vec<u8> {
+    let mut data = BONK_INIT_DISC.to_vec();
+    data.push(6); // decimals
+    for value in [name, symbol, uri] {
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(value.as_bytes());
+    }
+    data.push(0); // constant curve
+    for value in [1_000_000_000_000_000u64, 793_100_000_000_000, 85_000_000_000] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.push(0); // migrate_type
+    for value in [0u64, 0, 0] {
+        data.extend_from_slice(&value.to_le_bytes()); // vesting params
+    }
+    data
+}
+
+/// Encodes Raydium Launchpad `buy_exact_in` data
+pub fn bonk_buy_data(param: &BonkBuyParam) -> Vec<u8> {
+    let mut data = BONK_BUY_IN_DISC.to_vec();
+    param
+        .serialize(&mut data)
+        .expect("serializing into a Vec cannot fail");
+    data
+}
+
+/// Wraps synthetic updates in the stream shape `process_updates_grpc` expects
+pub fn update_stream(
+    updates: Vec<SubscribeUpdate>,
+) -> impl Stream<Item = Result<SubscribeUpdate, Status>> + Unpin {
+    replay_stream(updates)
+}
+
+/// Always returns the same blockhash, so signed transactions are reproducible
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FixedBlockhash(pub Hash);
+
+impl BlockhashSource for FixedBlockhash {
+    fn latest_blockhash(&self) -> Hash {
+        self.0
+    }
+}
+
+/// A clock that only moves when advanced, for cooldown and window checks
+#[derive(Debug)]
+pub struct ManualClock {
+    start: Instant,
+    offset: Mutex<Duration>,
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        ManualClock {
+            start: Instant::now(),
+            offset: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Moves the clock forward by `by`
+    pub fn advance(&self, by: Duration) {
+        *self.offset.lock().unwrap() += by;
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.start + *self.offset.lock().unwrap()
+    }
+}
+
+/// Serializes tests that swap the global clock
+static CLOCK_LOCK: Mutex<()> = Mutex::new(());
+
+/// A `ManualClock` installed as the trade path's clock until dropped, when
+/// the system clock comes back. Only one test holds one at a time.
+pub struct ManualClockGuard {
+    clock: Arc<ManualClock>,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl ManualClockGuard {
+    pub fn install() -> Self {
+        // A test that panicked while holding the clock still restored it on drop
+        let lock = CLOCK_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let clock = Arc::new(ManualClock::new());
+        set_clock(clock.clone());
+        ManualClockGuard { clock, _lock: lock }
+    }
+}
+
+impl Deref for ManualClockGuard {
+    type Target = ManualClock;
+
+    fn deref(&self) -> &ManualClock {
+        &self.clock
+    }
+}
+
+impl Drop for ManualClockGuard {
+    fn drop(&mut self) {
+        set_clock(Arc::new(SystemClock));
+    }
+}
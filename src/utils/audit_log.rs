@@ -0,0 +1,119 @@
+use once_cell::sync::Lazy;
+use serde_json::json;
+use solana_sdk::signature::Signature;
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{CONFIG, LaunchEvent, increment_counter, launch_event_json};
+
+/// Append-only audit file from `[output].audit_log_path`, if one is set and could be opened
+static AUDIT_LOG: Lazy<Option<Mutex<File>>> = Lazy::new(|| {
+    let path = CONFIG.output.audit_log_path.as_deref()?;
+    match OpenOptions::new().create(true).append(true).open(path) {
+        Ok(file) => {
+            println!("🗒️ Writing launch dispositions to {}", path);
+            Some(Mutex::new(file))
+        }
+        Err(e) => {
+            eprintln!("❌ Failed to open audit log {}: {}", path, e);
+            None
+        }
+    }
+});
+
+/// What finally happened to a detected launch
+#[derive(Debug, Clone)]
+pub enum Disposition {
+    /// A buy was sent; `signature` is None in dry-run mode
+    Traded { signature: Option<Signature> },
+    /// A filter or guard decided not to buy
+    Filtered { reason: String },
+    /// Trade handling failed or panicked
+    Errored { message: String },
+}
+
+/// Which wallet a buy was made for: the configured strategy or its `[trade.shadow]` mirror
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeRole {
+    Primary,
+    Shadow,
+}
+
+impl TradeRole {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TradeRole::Primary => "primary",
+            TradeRole::Shadow => "shadow",
+        }
+    }
+
+    /// Counter name for this role; shadow buys are tallied under `name{wallet="shadow"}`
+    pub fn counter(&self, name: &str) -> String {
+        match self {
+            TradeRole::Primary => name.to_string(),
+            TradeRole::Shadow => format!("{}{{wallet=\"shadow\"}}", name),
+        }
+    }
+
+    /// Log prefix marking shadow output
+    pub fn log_tag(&self) -> &'static str {
+        match self {
+            TradeRole::Primary => "",
+            TradeRole::Shadow => "[shadow] ",
+        }
+    }
+}
+
+impl Disposition {
+    fn label(&self) -> &'static str {
+        match self {
+            Disposition::Traded { .. } => "traded",
+            Disposition::Filtered { .. } => "filtered",
+            Disposition::Errored { .. } => "errored",
+        }
+    }
+}
+
+/// Appends one JSON line with the launch, the wallet role and its disposition to the audit log.
+/// Does nothing unless `[output].audit_log_path` is set.
+pub fn record_disposition(event: &LaunchEvent, role: TradeRole, disposition: &Disposition) {
+    match role {
+        TradeRole::Primary => {
+            increment_counter(&format!("launch_dispositions{{disposition=\"{}\"}}", disposition.label()))
+        }
+        TradeRole::Shadow => increment_counter(&format!(
+            "launch_dispositions{{disposition=\"{}\",wallet=\"shadow\"}}",
+            disposition.label()
+        )),
+    }
+
+    let Some(file) = AUDIT_LOG.as_ref() else {
+        return;
+    };
+
+    let decided_at_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default();
+
+    let detail = match disposition {
+        Disposition::Traded { signature } => json!(signature.map(|s| s.to_string())),
+        Disposition::Filtered { reason } => json!(reason),
+        Disposition::Errored { message } => json!(message),
+    };
+    let line = json!({
+        "launch": launch_event_json(event, decided_at_ms),
+        "wallet": role.label(),
+        "disposition": disposition.label(),
+        "detail": detail,
+        "decided_at_ms": decided_at_ms,
+    });
+
+    if let Err(e) = writeln!(file.lock().unwrap(), "{}", line) {
+        eprintln!("❌ Failed to write audit log entry for TX {}: {}", event.tx_id(), e);
+    }
+}
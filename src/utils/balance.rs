@@ -0,0 +1,55 @@
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use std::{collections::HashMap, sync::Arc};
+use tokio::time::{Duration, sleep};
+
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+
+static GLOBAL_WALLET_BALANCES: Lazy<Mutex<HashMap<Pubkey, u64>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn set_wallet_balance(wallet: &Pubkey, value: u64) {
+    let mut balances = GLOBAL_WALLET_BALANCES.lock().unwrap();
+    balances.insert(*wallet, value);
+}
+
+/// Returns the last known balance of a wallet in lamports.
+pub fn get_wallet_balance(wallet: &Pubkey) -> u64 {
+    let balances = GLOBAL_WALLET_BALANCES.lock().unwrap();
+    balances.get(wallet).copied().unwrap_or(0)
+}
+
+/// Deducts lamports committed to an in-flight buy from the cached balance so
+/// back-to-back snipes don't all see the same pre-buy balance.
+pub fn debit_wallet_balance(wallet: &Pubkey, amount: u64) {
+    let mut balances = GLOBAL_WALLET_BALANCES.lock().unwrap();
+    let balance = balances.entry(*wallet).or_insert(0);
+    *balance = balance.saturating_sub(amount);
+}
+
+/// Fetches a wallet balance once and stores it in the cache.
+pub async fn refresh_wallet_balance(
+    rpc_client: Arc<RpcClient>,
+    wallet: &Pubkey,
+) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+    let balance = rpc_client.get_balance(wallet).await?;
+    set_wallet_balance(wallet, balance);
+    Ok(balance)
+}
+
+/// Keeps the cached balances fresh, retrying each wallet until an update succeeds.
+pub async fn wallet_balance_handler(rpc_client: Arc<RpcClient>, wallets: &[Pubkey]) {
+    for wallet in wallets {
+        loop {
+            match refresh_wallet_balance(rpc_client.clone(), wallet).await {
+                Ok(_) => break,
+                Err(_) => {
+                    sleep(Duration::from_millis(200)).await;
+                }
+            }
+        }
+    }
+
+    sleep(Duration::from_millis(1000)).await;
+}
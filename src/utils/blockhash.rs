@@ -1,38 +1,110 @@
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{commitment_config::CommitmentConfig, hash::Hash};
-use std::sync::Arc;
-use tokio::time::{Duration, sleep};
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, AtomicU64, Ordering},
+};
+use tokio::time::{Duration, Instant, sleep};
 
 use once_cell::sync::Lazy;
 use std::sync::Mutex;
 
+use crate::{CONFIG, set_gauge};
+
 static GLOBAL_CURRENT_HASH: Lazy<Mutex<Hash>> = Lazy::new(|| Mutex::new(Hash::default()));
+static GLOBAL_CURRENT_SLOT: AtomicU64 = AtomicU64::new(0);
+
+/// When the cached blockhash last actually changed
+static LAST_BLOCKHASH_CHANGE: Lazy<Mutex<Option<Instant>>> = Lazy::new(|| Mutex::new(None));
+
+/// Cleared while the blockhash is stale, since sends would fail anyway
+static TRADING_READY: AtomicBool = AtomicBool::new(true);
+
+/// Whether the blockhash was stale at the last check, so only transitions are logged
+static BLOCKHASH_STALE: AtomicBool = AtomicBool::new(false);
 
 fn set_slot(value: Hash) {
     let mut slot = GLOBAL_CURRENT_HASH.lock().unwrap();
+    if *slot != value {
+        *LAST_BLOCKHASH_CHANGE.lock().unwrap() = Some(Instant::now());
+    }
     *slot = value;
 }
 
-pub fn get_slot() -> Hash {
+/// Returns the most recently fetched blockhash.
+pub fn get_cached_blockhash() -> Hash {
     let slot = GLOBAL_CURRENT_HASH.lock().unwrap();
     *slot
 }
 
+/// Returns the most recently fetched cluster slot, or 0 before the first fetch.
+pub fn get_current_slot() -> u64 {
+    GLOBAL_CURRENT_SLOT.load(Ordering::Relaxed)
+}
+
+/// Returns how long ago the cached blockhash last changed, or None before the first fetch.
+pub fn blockhash_age() -> Option<Duration> {
+    LAST_BLOCKHASH_CHANGE.lock().unwrap().map(|changed| changed.elapsed())
+}
+
+/// Returns false while trading is paused because the blockhash stopped updating.
+pub fn is_trading_ready() -> bool {
+    TRADING_READY.load(Ordering::Relaxed)
+}
+
+/// Publishes the blockhash age and flips readiness when it passes the configured limit.
+fn check_blockhash_staleness() {
+    let Some(age) = blockhash_age() else {
+        return;
+    };
+    set_gauge("blockhash_age_seconds", age.as_secs_f64());
+
+    let stale = age.as_secs() > CONFIG.rpc.max_blockhash_age_secs;
+    let pause = stale && CONFIG.rpc.pause_on_stale_blockhash;
+    TRADING_READY.store(!pause, Ordering::Relaxed);
+
+    // Only transitions are logged, whether or not staleness pauses trading
+    let was_stale = BLOCKHASH_STALE.swap(stale, Ordering::Relaxed);
+    if stale && !was_stale {
+        eprintln!(
+            "🚨 Blockhash hasn't changed for {}s (max {}s), RPC may be frozen{}",
+            age.as_secs(),
+            CONFIG.rpc.max_blockhash_age_secs,
+            if pause { "; pausing trading" } else { "" }
+        );
+    } else if !stale && was_stale {
+        if CONFIG.rpc.pause_on_stale_blockhash {
+            println!("✅ Blockhash updating again, trading resumed");
+        } else {
+            println!("✅ Blockhash updating again");
+        }
+    }
+}
+
 /// Continuously retries fetching the latest blockhash until successful.
 pub async fn recent_blockhash_handler(rpc_client: Arc<RpcClient>) {
     loop {
-        match rpc_client
+        let fetched = rpc_client
             .get_latest_blockhash_with_commitment(CommitmentConfig::processed())
-            .await
-        {
-            Ok((latest_blockhash, _)) => {
-                set_slot(latest_blockhash);
-                break; // Exit the loop once we get a valid blockhash
-            }
-            Err(e) => {
-                sleep(Duration::from_millis(200)).await;
-            }
+            .await;
+        if let Ok((latest_blockhash, _)) = fetched {
+            set_slot(latest_blockhash);
+        }
+
+        // Check on failures too, so an RPC outage still trips the alarm
+        check_blockhash_staleness();
+        if fetched.is_ok() {
+            break; // Exit the loop once we get a valid blockhash
         }
+        sleep(Duration::from_millis(200)).await;
+    }
+
+    // Track the cluster slot alongside the blockhash for update age checks
+    if let Ok(slot) = rpc_client
+        .get_slot_with_commitment(CommitmentConfig::processed())
+        .await
+    {
+        GLOBAL_CURRENT_SLOT.fetch_max(slot, Ordering::Relaxed);
     }
 
     sleep(Duration::from_millis(500)).await;
@@ -0,0 +1,160 @@
+/**
+ * 📦 Jito Bundle Module - Bonk.fun Trading Sniper Bot
+ * 
+ * This module submits several signed transactions as one atomic Jito bundle
+ * through the block engine JSON-RPC API and tracks whether it landed.
+ * 
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use once_cell::sync::Lazy;
+use serde_json::{Value, json};
+use solana_client::client_error::reqwest;
+use solana_sdk::transaction::Transaction;
+use std::fmt;
+use tokio::time::{Duration, Instant, sleep};
+
+use crate::CONFIG;
+
+/// Jito accepts at most five transactions per bundle
+pub const MAX_BUNDLE_SIZE: usize = 5;
+
+static BUNDLE_HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .timeout(Duration::from_millis(CONFIG.services.bundle_timeout_ms))
+        .build()
+        .expect("Failed to build bundle HTTP client")
+});
+
+/// Errors raised while submitting or tracking a bundle
+#[derive(Debug)]
+pub enum BundleError {
+    /// The bundle couldn't be built or encoded
+    Invalid(String),
+    /// The block engine refused the bundle
+    Rejected(String),
+    /// The bundle was accepted but didn't land (failed or dropped)
+    Failed { bundle_id: String, status: String },
+    /// No response or no final status within the timeout
+    Timeout { bundle_id: Option<String> },
+    Transport(String),
+}
+
+impl fmt::Display for BundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BundleError::Invalid(e) => write!(f, "invalid bundle: {}", e),
+            BundleError::Rejected(e) => write!(f, "bundle rejected: {}", e),
+            BundleError::Failed { bundle_id, status } => {
+                write!(f, "bundle {} did not land: {}", bundle_id, status)
+            }
+            BundleError::Timeout { bundle_id: Some(id) } => write!(f, "bundle {} timed out", id),
+            BundleError::Timeout { bundle_id: None } => write!(f, "bundle submission timed out"),
+            BundleError::Transport(e) => write!(f, "bundle transport error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for BundleError {}
+
+/**
+ * Submits signed transactions as one Jito bundle
+ * 
+ * @param txs - Signed transactions, executed in order and all-or-nothing
+ * @returns Result<String, BundleError> - Bundle id accepted by the block engine
+ */
+pub async fn submit_bundle(txs: Vec<Transaction>) -> Result<String, BundleError> {
+    if txs.is_empty() || txs.len() > MAX_BUNDLE_SIZE {
+        return Err(BundleError::Invalid(format!(
+            "bundle must contain 1-{} transactions, got {}",
+            MAX_BUNDLE_SIZE,
+            txs.len()
+        )));
+    }
+
+    let mut encoded = Vec::with_capacity(txs.len());
+    for tx in &txs {
+        let bytes = bincode::serialize(tx).map_err(|e| BundleError::Invalid(e.to_string()))?;
+        encoded.push(STANDARD.encode(bytes));
+    }
+
+    let result = block_engine_call("sendBundle", json!([encoded, { "encoding": "base64" }]))
+        .await
+        .map_err(|e| match e {
+            BundleError::Transport(message) => BundleError::Rejected(message),
+            other => other,
+        })?;
+
+    let bundle_id = result
+        .as_str()
+        .ok_or_else(|| BundleError::Rejected(format!("unexpected response: {}", result)))?
+        .to_string();
+
+    println!("📦 Bundle accepted: {}", bundle_id);
+    Ok(bundle_id)
+}
+
+/**
+ * Waits for a submitted bundle to reach a final status
+ * 
+ * @param bundle_id - Bundle id returned by `submit_bundle`
+ * @param timeout - How long to wait for the bundle to land
+ * @returns Result<(), BundleError> - Ok once landed
+ */
+pub async fn wait_for_bundle(bundle_id: &str, timeout: Duration) -> Result<(), BundleError> {
+    let started = Instant::now();
+
+    while started.elapsed() < timeout {
+        let result = block_engine_call("getInflightBundleStatuses", json!([[bundle_id]])).await?;
+        let status = result["value"][0]["status"].as_str().unwrap_or("Pending");
+
+        match status {
+            "Landed" => {
+                println!("✅ Bundle landed: {}", bundle_id);
+                return Ok(());
+            }
+            "Failed" | "Invalid" => {
+                return Err(BundleError::Failed {
+                    bundle_id: bundle_id.to_string(),
+                    status: status.to_string(),
+                });
+            }
+            _ => sleep(Duration::from_millis(200)).await,
+        }
+    }
+
+    Err(BundleError::Timeout { bundle_id: Some(bundle_id.to_string()) })
+}
+
+/// Calls a block engine JSON-RPC bundle method and returns its `result`
+pub(crate) async fn block_engine_call(method: &str, params: Value) -> Result<Value, BundleError> {
+    let url = format!("{}/api/v1/bundles", CONFIG.services.jito_block_engine_url.trim_end_matches('/'));
+    let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+
+    let response = BUNDLE_HTTP_CLIENT
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                BundleError::Timeout { bundle_id: None }
+            } else {
+                BundleError::Transport(e.to_string())
+            }
+        })?;
+
+    let response: Value = response
+        .json()
+        .await
+        .map_err(|e| BundleError::Transport(e.to_string()))?;
+
+    if let Some(error) = response.get("error") {
+        return Err(BundleError::Rejected(error.to_string()));
+    }
+
+    Ok(response["result"].clone())
+}
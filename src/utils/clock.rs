@@ -0,0 +1,61 @@
+use once_cell::sync::Lazy;
+use solana_sdk::hash::Hash;
+use std::{
+    sync::{Arc, RwLock},
+    time::Instant,
+};
+
+use crate::get_cached_blockhash;
+
+/// Where the trade path gets the blockhash it signs with
+pub trait BlockhashSource: Send + Sync {
+    fn latest_blockhash(&self) -> Hash;
+}
+
+/// Where the trade path reads the time from
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+}
+
+/// The blockhash kept fresh by the blockhash handler
+pub struct CachedBlockhashSource;
+
+impl BlockhashSource for CachedBlockhashSource {
+    fn latest_blockhash(&self) -> Hash {
+        get_cached_blockhash()
+    }
+}
+
+/// The system monotonic clock
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+static BLOCKHASH_SOURCE: Lazy<RwLock<Arc<dyn BlockhashSource>>> =
+    Lazy::new(|| RwLock::new(Arc::new(CachedBlockhashSource)));
+
+static CLOCK: Lazy<RwLock<Arc<dyn Clock>>> = Lazy::new(|| RwLock::new(Arc::new(SystemClock)));
+
+/// Replaces the blockhash source, e.g. with a fixed hash for deterministic tests
+pub fn set_blockhash_source(source: Arc<dyn BlockhashSource>) {
+    *BLOCKHASH_SOURCE.write().unwrap() = source;
+}
+
+/// Replaces the clock, e.g. with a manually advanced one for tests
+pub fn set_clock(clock: Arc<dyn Clock>) {
+    *CLOCK.write().unwrap() = clock;
+}
+
+/// Blockhash to sign trade transactions with
+pub fn current_blockhash() -> Hash {
+    BLOCKHASH_SOURCE.read().unwrap().latest_blockhash()
+}
+
+/// Current time as seen by the trade path
+pub fn clock_now() -> Instant {
+    CLOCK.read().unwrap().now()
+}
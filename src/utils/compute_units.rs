@@ -0,0 +1,113 @@
+use once_cell::sync::Lazy;
+use solana_client::rpc_config::RpcSimulateTransactionConfig;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    compute_budget::{self, ComputeBudgetInstruction},
+    instruction::Instruction,
+    message::Message,
+    pubkey::Pubkey,
+    transaction::Transaction,
+};
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::Mutex,
+};
+
+use crate::{CONFIG, PRIORITY_FEE, SEND_RPC_CLIENT, increment_counter};
+
+/// Most compute units a transaction may request
+const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Sized compute unit limits, keyed by instruction shape and base token program
+static SIZED_LIMITS: Lazy<Mutex<HashMap<(u64, Pubkey), u32>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Hashes the program and account count of every non compute budget instruction.
+/// Buys of different launches share a shape, so they share a sized limit.
+pub fn instruction_shape(ixs: &[Instruction]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for ix in ixs.iter().filter(|ix| ix.program_id != compute_budget::id()) {
+        ix.program_id.hash(&mut hasher);
+        ix.accounts.len().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Highest compute unit limit a buy can end up requesting: the static `cu`,
+/// or anything up to the maximum once `[priority_fee].auto_cu` sizes it.
+pub fn compute_unit_limit_ceiling() -> u64 {
+    if CONFIG.priority_fee.auto_cu {
+        MAX_COMPUTE_UNIT_LIMIT as u64
+    } else {
+        PRIORITY_FEE.0
+    }
+}
+
+/// Adds `[priority_fee].auto_cu_margin_pct` on top of the units a simulation consumed.
+pub fn compute_unit_limit_with_margin(units_consumed: u64) -> u32 {
+    let margin = 1.0 + CONFIG.priority_fee.auto_cu_margin_pct / 100.0;
+    ((units_consumed as f64 * margin).ceil() as u64).min(MAX_COMPUTE_UNIT_LIMIT as u64) as u32
+}
+
+/// Replaces the compute unit limit in `ixs` with one sized from a simulation.
+/// Sized limits are cached per instruction shape and base token program, since
+/// Token-2022 transfers burn more units than SPL Token ones in the same shape;
+/// when the simulation fails the static `cu` limit is left in place. Expects
+/// `ixs` from `compute_budget_ixs`. Returns the limit set, None if it was left.
+pub async fn apply_auto_compute_unit_limit(
+    ixs: &mut [Instruction],
+    payer: &Pubkey,
+    base_token_program: &Pubkey,
+) -> Option<u32> {
+    let key = (instruction_shape(ixs), *base_token_program);
+    let cached = SIZED_LIMITS.lock().unwrap().get(&key).copied();
+
+    let limit = match cached {
+        Some(limit) => limit,
+        None => match simulate_units_consumed(ixs, payer).await {
+            Ok(units) => {
+                let limit = compute_unit_limit_with_margin(units);
+                SIZED_LIMITS.lock().unwrap().insert(key, limit);
+                println!("📐 Sized compute unit limit: {} units consumed, limit {}", units, limit);
+                limit
+            }
+            Err(e) => {
+                increment_counter("auto_cu_fallbacks");
+                println!("⚠️ Compute unit sizing failed, keeping static cu: {}", e);
+                return None;
+            }
+        },
+    };
+
+    if let Some(limit_ix) = ixs.first_mut().filter(|ix| ix.program_id == compute_budget::id()) {
+        *limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(limit);
+    }
+    Some(limit)
+}
+
+/// Simulates `ixs` with the maximum limit and returns the units they consumed.
+async fn simulate_units_consumed(ixs: &[Instruction], payer: &Pubkey) -> Result<u64, String> {
+    let mut unbounded = ixs.to_vec();
+    if let Some(limit_ix) = unbounded.first_mut().filter(|ix| ix.program_id == compute_budget::id()) {
+        *limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(MAX_COMPUTE_UNIT_LIMIT);
+    }
+
+    let tx = Transaction::new_unsigned(Message::new(&unbounded, Some(payer)));
+    let config = RpcSimulateTransactionConfig {
+        sig_verify: false,
+        replace_recent_blockhash: true,
+        commitment: Some(CommitmentConfig::processed()),
+        ..Default::default()
+    };
+
+    let result = SEND_RPC_CLIENT
+        .simulate_transaction_with_config(&tx, config)
+        .await
+        .map_err(|e| e.to_string())?
+        .value;
+
+    if let Some(err) = result.err {
+        return Err(format!("simulation failed: {}", err));
+    }
+    result.units_consumed.ok_or_else(|| "simulation returned no units consumed".to_string())
+}
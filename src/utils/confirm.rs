@@ -0,0 +1,45 @@
+use solana_client::client_error::ClientError;
+use solana_sdk::{commitment_config::CommitmentConfig, signature::Signature, transaction::TransactionError};
+use tokio::time::{Duration, Instant, sleep};
+
+use crate::RPC_CLIENT;
+
+/// Time between signature status polls, roughly one slot
+const CONFIRM_POLL_INTERVAL: Duration = Duration::from_millis(400);
+
+/// What happened to a submitted transaction
+#[derive(Debug, Clone)]
+pub enum ConfirmOutcome {
+    /// Confirmed on-chain without error
+    Landed { slot: u64 },
+    /// Landed on-chain but the transaction itself failed (e.g. slippage)
+    Failed { slot: u64, error: TransactionError },
+    /// Not confirmed within the timeout; it may still land or be dropped
+    Timeout,
+}
+
+/// Polls a signature's status until it's confirmed, fails, or the timeout passes.
+/// A transaction that lands with a program error is reported as `Failed`.
+pub async fn confirm_signature(
+    signature: &Signature,
+    timeout: Duration,
+) -> Result<ConfirmOutcome, ClientError> {
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        let statuses = RPC_CLIENT.get_signature_statuses(&[*signature]).await?.value;
+        if let Some(Some(status)) = statuses.into_iter().next() {
+            if let Some(error) = status.err {
+                return Ok(ConfirmOutcome::Failed { slot: status.slot, error });
+            }
+            if status.satisfies_commitment(CommitmentConfig::confirmed()) {
+                return Ok(ConfirmOutcome::Landed { slot: status.slot });
+            }
+        }
+
+        if Instant::now() + CONFIRM_POLL_INTERVAL > deadline {
+            return Ok(ConfirmOutcome::Timeout);
+        }
+        sleep(CONFIRM_POLL_INTERVAL).await;
+    }
+}
@@ -0,0 +1,130 @@
+use serde_json::{Value, json};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+use crate::{CONFIG, STREAM_HEALTH, positions_json, sell_all_positions};
+
+/// Largest request head accepted; the endpoints take no body
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+/// Header carrying the shared secret from `[control].secret`
+const SECRET_HEADER: &str = "x-control-secret";
+
+/// Headers only browsers send; their presence means a web page made the request
+const BROWSER_HEADERS: [&str; 2] = ["origin", "referer"];
+
+/// Serves one control request on an accepted connection, then closes it.
+pub async fn serve_control_connection(mut socket: TcpStream) -> std::io::Result<()> {
+    let mut buf = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 1024];
+
+    while !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+        if buf.len() >= MAX_REQUEST_BYTES {
+            return write_response(&mut socket, 413, &json!({ "error": "request too large" })).await;
+        }
+        let read = socket.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(());
+        }
+        buf.extend_from_slice(&chunk[..read]);
+    }
+
+    let head = String::from_utf8_lossy(&buf);
+    let mut lines = head.lines();
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_string();
+    let path = request_line.next().unwrap_or_default().to_string();
+
+    let headers: Vec<(&str, &str)> = lines
+        .take_while(|line| !line.is_empty())
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim(), value.trim()))
+        .collect();
+
+    // Requests from a web page could be cross-site; the CLI and scripts never send these
+    if headers
+        .iter()
+        .any(|(name, _)| BROWSER_HEADERS.iter().any(|h| name.eq_ignore_ascii_case(h)))
+    {
+        return write_response(&mut socket, 403, &json!({ "error": "browser requests are not accepted" })).await;
+    }
+
+    let secret = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(SECRET_HEADER))
+        .map(|(_, value)| value.to_string())
+        .unwrap_or_default();
+
+    let (status, body) = handle_control_request(&method, &path, &secret).await;
+    write_response(&mut socket, status, &body).await
+}
+
+/// Routes a control request to its handler, returning the status and JSON body.
+async fn handle_control_request(method: &str, path: &str, secret: &str) -> (u16, Value) {
+    if !secret_matches(secret) {
+        return (401, json!({ "error": "missing or invalid control secret" }));
+    }
+
+    match (method, path) {
+        ("GET", "/positions") => (200, positions_json()),
+        ("GET", "/health") => (
+            200,
+            json!({
+                "stream_healthy": STREAM_HEALTH.is_healthy(),
+                "last_update_ms": STREAM_HEALTH.last_update_ms(),
+                "seconds_since_last_update": STREAM_HEALTH.seconds_since_last(),
+            }),
+        ),
+        ("POST", "/sell-all") => {
+            let results = sell_all_positions()
+                .await
+                .into_iter()
+                .map(|(mint, result)| match result {
+                    Ok(signature) => json!({ "mint": mint.to_string(), "signature": signature.to_string() }),
+                    Err(e) => json!({ "mint": mint.to_string(), "error": e.to_string() }),
+                })
+                .collect::<Vec<_>>();
+            (200, json!({ "results": results }))
+        }
+        (_, "/positions") | (_, "/sell-all") | (_, "/health") => (405, json!({ "error": "method not allowed" })),
+        _ => (404, json!({ "error": "not found" })),
+    }
+}
+
+/// Compares the presented secret without bailing out on the first mismatch.
+fn secret_matches(presented: &str) -> bool {
+    let expected = CONFIG.control.secret.as_bytes();
+    // Validation refuses to start without a secret; never treat an empty one as open
+    if expected.is_empty() {
+        return false;
+    }
+
+    let presented = presented.as_bytes();
+    presented.len() == expected.len()
+        && presented.iter().zip(expected).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+async fn write_response(socket: &mut TcpStream, status: u16, body: &Value) -> std::io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        _ => "Error",
+    };
+    let body = body.to_string();
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    );
+
+    socket.write_all(response.as_bytes()).await?;
+    socket.shutdown().await
+}
@@ -0,0 +1,32 @@
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::time::{Duration, sleep};
+
+use crate::set_gauge;
+
+/// How often the kill switch file is checked
+const KILL_SWITCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// True while the `[trade].kill_switch_path` file exists
+static KILL_SWITCH_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Returns true while buying is paused by the kill switch file
+pub fn trading_paused() -> bool {
+    KILL_SWITCH_ACTIVE.load(Ordering::Relaxed)
+}
+
+/// Checks whether the kill switch file exists, logs any change, then waits.
+pub async fn kill_switch_handler(path: &Path) {
+    let active = tokio::fs::try_exists(path).await.unwrap_or(false);
+
+    if KILL_SWITCH_ACTIVE.swap(active, Ordering::Relaxed) != active {
+        set_gauge("trading_paused", if active { 1.0 } else { 0.0 });
+        if active {
+            println!("⛔ Kill switch {} present, trading paused", path.display());
+        } else {
+            println!("▶️ Kill switch {} removed, trading resumed", path.display());
+        }
+    }
+
+    sleep(KILL_SWITCH_POLL_INTERVAL).await;
+}
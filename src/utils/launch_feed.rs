@@ -0,0 +1,74 @@
+use serde_json::{Value, json};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{BonkCurveParams, LaunchEvent};
+
+/// Version of the launch feed JSON schema; bump on breaking changes
+const LAUNCH_FEED_SCHEMA_VERSION: u32 = 1;
+
+/// Prints a launch or migration as a single JSON line on stdout.
+pub fn emit_launch_event(event: &LaunchEvent) {
+    let detected_at_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default();
+
+    println!("{}", launch_event_json(event, detected_at_ms));
+}
+
+/// Serializes a launch into the stable launch feed schema.
+pub fn launch_event_json(event: &LaunchEvent, detected_at_ms: u64) -> Value {
+    match event {
+        LaunchEvent::Bonk { mint_info, buy, buy_param, tx_id } => {
+            let mint_param = &mint_info.base_mint_param;
+            json!({
+                "schema_version": LAUNCH_FEED_SCHEMA_VERSION,
+                "launchpad": "bonk",
+                "program": buy.program.to_string(),
+                "mint": buy.base_token_mint.to_string(),
+                "name": mint_param.name,
+                "symbol": mint_param.symbol,
+                "uri": mint_param.uri,
+                "decimals": mint_param.decimals,
+                "curve": curve_json(&mint_info.curve_param),
+                "share_fee_rate": buy_param.share_fee_rate,
+                "detected_at_ms": detected_at_ms,
+                "signature": tx_id,
+            })
+        }
+        LaunchEvent::Migration { mint, quote_mint, migrate_type, tx_id } => json!({
+            "schema_version": LAUNCH_FEED_SCHEMA_VERSION,
+            "launchpad": "bonk",
+            "event": "migration",
+            "mint": mint.to_string(),
+            "quote_mint": quote_mint.to_string(),
+            "migrate_type": migrate_type,
+            "detected_at_ms": detected_at_ms,
+            "signature": tx_id,
+        }),
+    }
+}
+
+fn curve_json(curve: &BonkCurveParams) -> Value {
+    match curve {
+        BonkCurveParams::Constant(c) => json!({
+            "type": "constant",
+            "supply": c.supply,
+            "total_base_sell": c.total_base_sell,
+            "total_quote_fund_raising": c.total_quote_fund_raising,
+            "migrate_type": c.migrate_type,
+        }),
+        BonkCurveParams::Fixed(c) => json!({
+            "type": "fixed",
+            "supply": c.supply,
+            "total_quote_fund_raising": c.total_quote_fund_raising,
+            "migrate_type": c.migrate_type,
+        }),
+        BonkCurveParams::Linear(c) => json!({
+            "type": "linear",
+            "supply": c.supply,
+            "total_quote_fund_raising": c.total_quote_fund_raising,
+            "migrate_type": c.migrate_type,
+        }),
+    }
+}
@@ -0,0 +1,87 @@
+use once_cell::sync::Lazy;
+use solana_sdk::{
+    address_lookup_table::{AddressLookupTableAccount, state::AddressLookupTable},
+    hash::Hash,
+    instruction::Instruction,
+    message::{Message, VersionedMessage, v0},
+    pubkey::Pubkey,
+    signer::keypair::Keypair,
+    transaction::VersionedTransaction,
+};
+use std::{collections::HashMap, str::FromStr, sync::Mutex};
+
+use crate::{CONFIG, RPC_CLIENT, SniperError};
+
+/// Lookup tables fetched so far, keyed by table address
+static LOOKUP_TABLE_CACHE: Lazy<Mutex<HashMap<Pubkey, AddressLookupTableAccount>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Returns the configured lookup tables, fetching any not cached yet.
+/// Tables that can't be fetched or decoded are logged and left out.
+pub async fn load_lookup_tables() -> Vec<AddressLookupTableAccount> {
+    let keys: Vec<Pubkey> = CONFIG
+        .trade
+        .lookup_tables
+        .iter()
+        .filter_map(|key| Pubkey::from_str(key).ok())
+        .collect();
+
+    let missing: Vec<Pubkey> = {
+        let cache = LOOKUP_TABLE_CACHE.lock().unwrap();
+        keys.iter().filter(|key| !cache.contains_key(key)).copied().collect()
+    };
+
+    if !missing.is_empty() {
+        match RPC_CLIENT.get_multiple_accounts(&missing).await {
+            Ok(accounts) => {
+                let mut cache = LOOKUP_TABLE_CACHE.lock().unwrap();
+                for (key, account) in missing.iter().zip(accounts) {
+                    let Some(account) = account else {
+                        println!("⚠️ Lookup table {} not found", key);
+                        continue;
+                    };
+                    match AddressLookupTable::deserialize(&account.data) {
+                        Ok(table) => {
+                            cache.insert(*key, AddressLookupTableAccount {
+                                key: *key,
+                                addresses: table.addresses.to_vec(),
+                            });
+                        }
+                        Err(e) => println!("⚠️ Failed to decode lookup table {}: {}", key, e),
+                    }
+                }
+            }
+            Err(e) => println!("⚠️ Failed to fetch lookup tables: {}", e),
+        }
+    }
+
+    let cache = LOOKUP_TABLE_CACHE.lock().unwrap();
+    keys.iter().filter_map(|key| cache.get(key).cloned()).collect()
+}
+
+/// Compiles a message for the payer: v0 against the lookup tables, or legacy when there are none.
+pub fn compile_message(
+    ixs: &[Instruction],
+    payer: &Pubkey,
+    lookup_tables: &[AddressLookupTableAccount],
+) -> Result<VersionedMessage, SniperError> {
+    if lookup_tables.is_empty() {
+        return Ok(VersionedMessage::Legacy(Message::new(ixs, Some(payer))));
+    }
+
+    v0::Message::try_compile(payer, ixs, lookup_tables, Hash::default())
+        .map(VersionedMessage::V0)
+        .map_err(|e| SniperError::TransactionBuild(format!("failed to compile v0 message: {}", e)))
+}
+
+/// Signs a compiled message with every signer against the given blockhash; the fee payer comes first.
+pub fn sign_message(
+    mut message: VersionedMessage,
+    signers: &[&Keypair],
+    blockhash: Hash,
+) -> Result<VersionedTransaction, SniperError> {
+    let payer = message.static_account_keys().first().copied().unwrap_or_default();
+    message.set_recent_blockhash(blockhash);
+    VersionedTransaction::try_new(message, signers)
+        .map_err(|e| SniperError::TransactionBuild(format!("failed to sign transaction for {}: {}", payer, e)))
+}
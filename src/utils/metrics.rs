@@ -0,0 +1,55 @@
+use once_cell::sync::Lazy;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+static COUNTERS: Lazy<Mutex<BTreeMap<String, u64>>> = Lazy::new(|| Mutex::new(BTreeMap::new()));
+static GAUGES: Lazy<Mutex<BTreeMap<String, f64>>> = Lazy::new(|| Mutex::new(BTreeMap::new()));
+
+/// Adds `value` to a named counter, creating it at zero if needed.
+pub fn add_counter(name: &str, value: u64) {
+    let mut counters = COUNTERS.lock().unwrap();
+    *counters.entry(name.to_string()).or_insert(0) += value;
+}
+
+/// Increments a named counter by one.
+pub fn increment_counter(name: &str) {
+    add_counter(name, 1);
+}
+
+/// Returns the current value of a named counter.
+pub fn counter_value(name: &str) -> u64 {
+    let counters = COUNTERS.lock().unwrap();
+    counters.get(name).copied().unwrap_or(0)
+}
+
+/// Sets a named gauge to `value`.
+pub fn set_gauge(name: &str, value: f64) {
+    let mut gauges = GAUGES.lock().unwrap();
+    gauges.insert(name.to_string(), value);
+}
+
+/// Returns the current value of a named gauge.
+pub fn gauge_value(name: &str) -> Option<f64> {
+    let gauges = GAUGES.lock().unwrap();
+    gauges.get(name).copied()
+}
+
+/// Renders all counters and gauges in Prometheus text format.
+pub fn render_metrics() -> String {
+    let mut out = String::new();
+
+    for (name, value) in COUNTERS.lock().unwrap().iter() {
+        out.push_str(&format!("# TYPE {} counter\n{} {}\n", metric_family(name), name, value));
+    }
+
+    for (name, value) in GAUGES.lock().unwrap().iter() {
+        out.push_str(&format!("# TYPE {} gauge\n{} {}\n", metric_family(name), name, value));
+    }
+
+    out
+}
+
+/// Strips labels (`name{label="x"}`) to get the metric family name.
+fn metric_family(name: &str) -> &str {
+    name.split('{').next().unwrap_or(name)
+}
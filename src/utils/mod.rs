@@ -1,10 +1,44 @@
+pub mod audit_log;
+pub mod balance;
 pub mod blockhash;
+pub mod bundle;
+pub mod clock;
+pub mod compute_units;
 pub mod confirm;
+pub mod control;
+pub mod kill_switch;
+pub mod launch_feed;
+pub mod lookup_tables;
+pub mod metrics;
 pub mod parse_data;
+pub mod priority_fee;
 pub mod pump_swap_quote;
+pub mod rate_limit;
+pub mod relayer_health;
+pub mod replay;
+pub mod session_stats;
 pub mod setup_subscribe;
+pub mod slot_state;
+pub mod submit;
+pub use audit_log::*;
+pub use balance::*;
 pub use blockhash::*;
+pub use bundle::*;
+pub use clock::*;
+pub use compute_units::*;
 pub use confirm::*;
+pub use control::*;
+pub use kill_switch::*;
+pub use launch_feed::*;
+pub use lookup_tables::*;
+pub use metrics::*;
 pub use parse_data::*;
+pub use priority_fee::*;
 pub use pump_swap_quote::*;
+pub use rate_limit::*;
+pub use relayer_health::*;
+pub use replay::*;
+pub use session_stats::*;
 pub use setup_subscribe::*;
+pub use slot_state::*;
+pub use submit::*;
@@ -3,31 +3,113 @@ use std::str::from_utf8;
 
 use solana_sdk::pubkey::Pubkey;
 
-/// Reads a little-endian u32 from the buffer starting at offset.
-pub fn read_u64_le(data: &[u8], offset: &mut usize) -> u64 {
-    let val = u64::from_le_bytes(data[*offset..*offset + 8].try_into().unwrap());
-    *offset += 8;
-    val
+use crate::ParseError;
+
+/// Longest string field accepted; launch names, symbols and URIs are far shorter
+pub const MAX_STRING_LEN: usize = 1024;
+
+/// Returns `len` bytes at offset and advances it, or an EOF error.
+fn take<'a>(data: &'a [u8], offset: &mut usize, len: usize) -> Result<&'a [u8], ParseError> {
+    let available = data.len().saturating_sub(*offset);
+    if available < len {
+        return Err(ParseError::UnexpectedEof { needed: len, available });
+    }
+    let bytes = &data[*offset..*offset + len];
+    *offset += len;
+    Ok(bytes)
 }
 
-pub fn read_u32_le(data: &[u8], offset: &mut usize) -> u32 {
-    let val = u32::from_le_bytes(data[*offset..*offset + 4].try_into().unwrap());
-    *offset += 4;
-    val
+pub fn read_u8(data: &[u8], offset: &mut usize) -> Result<u8, ParseError> {
+    Ok(take(data, offset, 1)?[0])
+}
+
+/// Reads a little-endian u64 from the buffer starting at offset.
+pub fn read_u64_le(data: &[u8], offset: &mut usize) -> Result<u64, ParseError> {
+    Ok(u64::from_le_bytes(take(data, offset, 8)?.try_into().unwrap()))
+}
+
+pub fn read_u32_le(data: &[u8], offset: &mut usize) -> Result<u32, ParseError> {
+    Ok(u32::from_le_bytes(take(data, offset, 4)?.try_into().unwrap()))
+}
+
+/// Runs one `read_*` call, tagging a failure with the field name and the offset it started at.
+pub fn read_field<T>(
+    field: &'static str,
+    offset: &mut usize,
+    read: impl FnOnce(&mut usize) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let start = *offset;
+    read(offset).map_err(|e| ParseError::in_field(start, field, e))
 }
 
 /// Reads a UTF-8 string prefixed with u32 length.
-pub fn read_string(data: &[u8], offset: &mut usize) -> String {
-    let len = read_u32_le(data, offset) as usize;
-    let bytes = &data[*offset..*offset + len];
-    *offset += len;
-    from_utf8(bytes).unwrap().to_string()
+/// The length is checked against the remaining data and `MAX_STRING_LEN` before anything is copied.
+pub fn read_string(data: &[u8], offset: &mut usize) -> Result<String, ParseError> {
+    let len = read_string_len(data, offset)?;
+    read_string_body(data, offset, len)
+}
+
+/// Reads the u32 length prefix of a string, rejecting lengths past the data or `MAX_STRING_LEN`.
+pub fn read_string_len(data: &[u8], offset: &mut usize) -> Result<usize, ParseError> {
+    let len = read_u32_le(data, offset)? as usize;
+    let max = MAX_STRING_LEN.min(data.len().saturating_sub(*offset));
+    if len > max {
+        return Err(ParseError::StringTooLong { len, max });
+    }
+    Ok(len)
+}
+
+/// Reads `len` bytes of UTF-8 string body.
+pub fn read_string_body(data: &[u8], offset: &mut usize, len: usize) -> Result<String, ParseError> {
+    let bytes = take(data, offset, len)?;
+    from_utf8(bytes)
+        .map(str::to_string)
+        .map_err(|_| ParseError::InvalidUtf8)
 }
 
-pub fn read_pubkey(data: &[u8], offset: &mut usize) -> Pubkey {
-    let key_bytes: [u8; 32] = data[*offset..*offset + 32]
-        .try_into()
-        .expect("slice with incorrect length");
-    *offset += 32;
-    Pubkey::new_from_array(key_bytes)
-}
\ No newline at end of file
+pub fn read_pubkey(data: &[u8], offset: &mut usize) -> Result<Pubkey, ParseError> {
+    let key_bytes: [u8; 32] = take(data, offset, 32)?.try_into().unwrap();
+    Ok(Pubkey::new_from_array(key_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prefixed(len: u32, body: &[u8]) -> Vec<u8> {
+        let mut data = len.to_le_bytes().to_vec();
+        data.extend_from_slice(body);
+        data
+    }
+
+    #[test]
+    fn max_length_prefix_is_too_long() {
+        let data = prefixed(u32::MAX, b"abc");
+        let mut offset = 0;
+
+        assert_eq!(
+            read_string(&data, &mut offset),
+            Err(ParseError::StringTooLong { len: u32::MAX as usize, max: 3 })
+        );
+    }
+
+    #[test]
+    fn length_past_the_limit_is_too_long_even_with_data_left() {
+        let data = prefixed(MAX_STRING_LEN as u32 + 1, &vec![b'a'; MAX_STRING_LEN + 1]);
+        let mut offset = 0;
+
+        assert_eq!(
+            read_string(&data, &mut offset),
+            Err(ParseError::StringTooLong { len: MAX_STRING_LEN + 1, max: MAX_STRING_LEN })
+        );
+    }
+
+    #[test]
+    fn string_filling_the_rest_of_the_data_is_read() {
+        let data = prefixed(5, b"hello");
+        let mut offset = 0;
+
+        assert_eq!(read_string(&data, &mut offset).as_deref(), Ok("hello"));
+        assert_eq!(offset, data.len());
+    }
+}
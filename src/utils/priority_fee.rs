@@ -0,0 +1,84 @@
+use solana_client::nonblocking::rpc_client::RpcClient;
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+use tokio::time::{Duration, sleep};
+
+use crate::{CONFIG, PRIORITY_FEE, RAYDIUM_LAUNCHPAD_PROGRAM_ID, set_gauge};
+
+/// Last compute unit price derived from recent fees; 0 until the first fetch
+static DYNAMIC_PRIORITY_FEE: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the compute unit price to use, in micro-lamports.
+/// This is the dynamic price when `[priority_fee].dynamic` is on and a fetch has succeeded,
+/// and the static `priority_fee_micro_lamport` otherwise.
+pub fn current_priority_fee() -> u64 {
+    let dynamic = DYNAMIC_PRIORITY_FEE.load(Ordering::Relaxed);
+    if CONFIG.priority_fee.dynamic && dynamic > 0 {
+        dynamic
+    } else {
+        PRIORITY_FEE.1
+    }
+}
+
+/// Returns the value at `percentile` (0-100) of `fees`, or None if there are none.
+pub fn fee_percentile(mut fees: Vec<u64>, percentile: u8) -> Option<u64> {
+    if fees.is_empty() {
+        return None;
+    }
+    fees.sort_unstable();
+    let rank = (fees.len() - 1) * percentile.min(100) as usize / 100;
+    Some(fees[rank])
+}
+
+/// Refreshes the dynamic compute unit price from recent launchpad prioritization fees, then waits.
+pub async fn priority_fee_handler(rpc_client: Arc<RpcClient>) {
+    let config = &CONFIG.priority_fee;
+
+    match rpc_client
+        .get_recent_prioritization_fees(&[RAYDIUM_LAUNCHPAD_PROGRAM_ID])
+        .await
+    {
+        Ok(recent) => {
+            let fees = recent.iter().map(|fee| fee.prioritization_fee).collect();
+            if let Some(fee) = fee_percentile(fees, config.dynamic_percentile) {
+                // Keep at least 1 so a quiet window never reverts to the static price
+                let fee = fee.clamp(1, config.max_micro_lamport);
+                DYNAMIC_PRIORITY_FEE.store(fee, Ordering::Relaxed);
+                set_gauge("priority_fee_micro_lamports", fee as f64);
+            }
+        }
+        Err(e) => {
+            println!("⚠️ Failed to fetch recent prioritization fees: {}", e);
+        }
+    }
+
+    sleep(Duration::from_millis(config.dynamic_refresh_ms)).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fees() -> Vec<u64> {
+        vec![50, 10, 40, 20, 30]
+    }
+
+    #[test]
+    fn percentiles_pick_from_the_sorted_fees() {
+        assert_eq!(fee_percentile(fees(), 0), Some(10));
+        assert_eq!(fee_percentile(fees(), 50), Some(30));
+        assert_eq!(fee_percentile(fees(), 100), Some(50));
+    }
+
+    #[test]
+    fn no_fees_have_no_percentile() {
+        assert_eq!(fee_percentile(Vec::new(), 50), None);
+    }
+
+    #[test]
+    fn percentile_over_100_is_the_highest_fee() {
+        assert_eq!(fee_percentile(fees(), 250), Some(50));
+    }
+}
@@ -0,0 +1,188 @@
+use once_cell::sync::Lazy;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::{CONFIG, clock_now, increment_counter, set_gauge};
+
+/// Window used by `max_trades_per_minute`
+const TRADE_WINDOW: Duration = Duration::from_secs(60);
+
+/// Limiter shared by every buy of the run
+static RATE_LIMITER: Lazy<Mutex<TradeRateLimiter>> = Lazy::new(|| Mutex::new(TradeRateLimiter::default()));
+
+/// Buys submitted (or about to be) since launch, counted against `max_trades`
+static TRADES_STARTED: AtomicU64 = AtomicU64::new(0);
+
+/// Why a buy was throttled
+#[derive(Debug, Clone, Copy)]
+pub enum RateLimitRejection {
+    /// `max_trades_per_minute` buys already happened in the last 60s
+    WindowFull { limit: u32 },
+    /// The previous buy was less than `cooldown_ms` ago
+    Cooldown { remaining: Duration },
+}
+
+impl fmt::Display for RateLimitRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateLimitRejection::WindowFull { limit } => write!(f, "{} trades per minute limit reached", limit),
+            RateLimitRejection::Cooldown { remaining } => write!(f, "cooldown active for another {}ms", remaining.as_millis()),
+        }
+    }
+}
+
+/// Buy start times that new buys are throttled against
+#[derive(Debug, Default)]
+pub struct TradeRateLimiter {
+    /// Start times of buys inside the current window, oldest first
+    recent: VecDeque<Instant>,
+    /// Start time of the most recent buy, kept separately since cooldowns may outlast the window
+    last_trade: Option<Instant>,
+}
+
+impl TradeRateLimiter {
+    /**
+     * Reserves a trade slot at `now`, or reports why the limiter is throttling
+     *
+     * Rejections are counted so throttling is visible in the metrics.
+     *
+     * @param now - Time the buy starts
+     * @param max_per_minute - Buys allowed per 60s window, 0 for no limit
+     * @param cooldown - Least time between two buys
+     * @returns Result<(), RateLimitRejection> - Ok once the slot is taken, or why not
+     */
+    pub fn try_acquire(
+        &mut self,
+        now: Instant,
+        max_per_minute: u32,
+        cooldown: Duration,
+    ) -> Result<(), RateLimitRejection> {
+        while self.recent.front().is_some_and(|t| now.duration_since(*t) >= TRADE_WINDOW) {
+            self.recent.pop_front();
+        }
+
+        if let Some(last) = self.last_trade {
+            let elapsed = now.duration_since(last);
+            if elapsed < cooldown {
+                increment_counter("trades_cooldown_skipped");
+                return Err(RateLimitRejection::Cooldown { remaining: cooldown - elapsed });
+            }
+        }
+
+        if max_per_minute > 0 && self.recent.len() >= max_per_minute as usize {
+            increment_counter("trades_rate_limited");
+            return Err(RateLimitRejection::WindowFull { limit: max_per_minute });
+        }
+
+        self.recent.push_back(now);
+        self.last_trade = Some(now);
+        Ok(())
+    }
+}
+
+/// Reserves a trade slot in the run's limiter, or reports why it's throttling.
+/// The limits are `[trade].max_trades_per_minute` and `[trade].cooldown_ms`.
+pub fn try_acquire_trade_slot(max_per_minute: u32, cooldown: Duration) -> Result<(), RateLimitRejection> {
+    RATE_LIMITER.lock().unwrap().try_acquire(clock_now(), max_per_minute, cooldown)
+}
+
+/// Takes one buy from the `max_trades` budget, returning how many remain after it.
+/// Returns None once the budget is spent; a `max_trades` of 0 means unlimited.
+pub fn try_take_trade_budget() -> Option<u64> {
+    let max_trades = CONFIG.trade.max_trades;
+    if max_trades == 0 {
+        return Some(u64::MAX);
+    }
+
+    let taken = TRADES_STARTED
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |started| {
+            (started < max_trades).then_some(started + 1)
+        })
+        .ok();
+
+    match taken {
+        Some(started) => {
+            let remaining = max_trades - started - 1;
+            set_gauge("trades_remaining", remaining as f64);
+            if remaining == 0 {
+                println!("🛑 Trade limit of {} reached; streaming continues but no further buys will be sent", max_trades);
+            }
+            Some(remaining)
+        }
+        None => {
+            increment_counter("trades_limit_skipped");
+            None
+        }
+    }
+}
+
+/// Gives back a buy taken by `try_take_trade_budget` that was never submitted,
+/// so throttled, dry-run or unbuildable buys don't use up the budget.
+pub fn refund_trade_budget() {
+    let max_trades = CONFIG.trade.max_trades;
+    if max_trades == 0 {
+        return;
+    }
+
+    if let Ok(started) = TRADES_STARTED.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |started| started.checked_sub(1)) {
+        set_gauge("trades_remaining", (max_trades + 1 - started) as f64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Clock, test_support::ManualClock};
+
+    const COOLDOWN: Duration = Duration::from_secs(5);
+
+    #[test]
+    fn full_window_rejects_until_it_rolls_over() {
+        let clock = ManualClock::new();
+        let mut limiter = TradeRateLimiter::default();
+        assert!(limiter.try_acquire(clock.now(), 2, Duration::ZERO).is_ok());
+        assert!(limiter.try_acquire(clock.now(), 2, Duration::ZERO).is_ok());
+
+        assert!(matches!(
+            limiter.try_acquire(clock.now(), 2, Duration::ZERO),
+            Err(RateLimitRejection::WindowFull { limit: 2 })
+        ));
+
+        // Once the first buys are a full window old their slots free up
+        clock.advance(TRADE_WINDOW);
+        assert!(limiter.try_acquire(clock.now(), 2, Duration::ZERO).is_ok());
+    }
+
+    #[test]
+    fn cooldown_rejects_with_the_time_left() {
+        let clock = ManualClock::new();
+        let mut limiter = TradeRateLimiter::default();
+        assert!(limiter.try_acquire(clock.now(), 0, COOLDOWN).is_ok());
+
+        clock.advance(Duration::from_secs(2));
+        let Err(RateLimitRejection::Cooldown { remaining }) = limiter.try_acquire(clock.now(), 0, COOLDOWN) else {
+            panic!("expected the cooldown to reject");
+        };
+        assert_eq!(remaining, Duration::from_secs(3));
+
+        clock.advance(Duration::from_secs(3));
+        assert!(limiter.try_acquire(clock.now(), 0, COOLDOWN).is_ok());
+    }
+
+    #[test]
+    fn rejected_buys_take_no_slot() {
+        let clock = ManualClock::new();
+        let mut limiter = TradeRateLimiter::default();
+        limiter.try_acquire(clock.now(), 1, Duration::ZERO).unwrap();
+        assert!(limiter.try_acquire(clock.now(), 1, Duration::ZERO).is_err());
+
+        // Only the accepted buy counts, so the window frees up a full minute after it
+        clock.advance(TRADE_WINDOW - Duration::from_millis(1));
+        assert!(limiter.try_acquire(clock.now(), 1, Duration::ZERO).is_err());
+        clock.advance(Duration::from_millis(1));
+        assert!(limiter.try_acquire(clock.now(), 1, Duration::ZERO).is_ok());
+    }
+}
@@ -0,0 +1,98 @@
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+use crate::{CONFIRM_SERVICE, RPC_CLIENT, block_engine_call, set_gauge};
+
+/// Consecutive failures after which a service is considered down
+const MAX_CONSECUTIVE_FAILURES: u32 = 3;
+
+/// Time between active health probes
+const HEALTH_PROBE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How long an unhealthy service is skipped before one submission is let through to test it
+const HALF_OPEN_COOLDOWN: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Default, Clone, Copy)]
+struct ServiceHealth {
+    last_success: Option<Instant>,
+    /// Last failure, or the last trial let through while unhealthy
+    last_failure: Option<Instant>,
+    consecutive_failures: u32,
+}
+
+static RELAYER_HEALTH: Lazy<Mutex<HashMap<String, ServiceHealth>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Records the outcome of a probe or submission against a service.
+pub fn record_service_result(service: &str, ok: bool) {
+    let mut health = RELAYER_HEALTH.lock().unwrap();
+    let entry = health.entry(service.to_string()).or_default();
+
+    if ok {
+        entry.last_success = Some(Instant::now());
+        entry.consecutive_failures = 0;
+    } else {
+        entry.last_failure = Some(Instant::now());
+        entry.consecutive_failures += 1;
+        if entry.consecutive_failures == MAX_CONSECUTIVE_FAILURES {
+            eprintln!("🚨 {} marked unhealthy after {} consecutive failures", service, entry.consecutive_failures);
+        }
+    }
+
+    let healthy = entry.consecutive_failures < MAX_CONSECUTIVE_FAILURES;
+    set_gauge(&format!("relayer_healthy{{service=\"{}\"}}", service), if healthy { 1.0 } else { 0.0 });
+    if let Some(last_success) = entry.last_success {
+        set_gauge(
+            &format!("relayer_last_success_seconds_ago{{service=\"{}\"}}", service),
+            last_success.elapsed().as_secs_f64(),
+        );
+    }
+}
+
+/// Returns false once a service has failed too many times in a row.
+///
+/// Only Jito is probed, so the other relayers can only recover through a
+/// submission: once `HALF_OPEN_COOLDOWN` has passed since the last failure,
+/// one call returns true and its recorded outcome decides what comes next.
+pub fn is_service_healthy(service: &str) -> bool {
+    let mut health = RELAYER_HEALTH.lock().unwrap();
+    let Some(entry) = health.get_mut(service) else {
+        return true;
+    };
+    if entry.consecutive_failures < MAX_CONSECUTIVE_FAILURES {
+        return true;
+    }
+
+    let cooled_down = match entry.last_failure {
+        Some(last_failure) => last_failure.elapsed() >= HALF_OPEN_COOLDOWN,
+        None => true,
+    };
+    if !cooled_down {
+        return false;
+    }
+
+    // Restart the cooldown so only this one submission tests the service
+    entry.last_failure = Some(Instant::now());
+    println!("🩺 {} cooled down, letting one submission through to test it", service);
+    true
+}
+
+/// Probes the active confirmation service and the RPC fallback once.
+pub async fn relayer_health_handler() {
+    let service = CONFIRM_SERVICE.as_str();
+
+    // Jito exposes a cheap JSON-RPC probe; the other relayers are tracked
+    // from submission outcomes recorded by the dispatcher.
+    if service == "JITO" {
+        let ok = block_engine_call("getTipAccounts", serde_json::json!([])).await.is_ok();
+        record_service_result(service, ok);
+    }
+
+    let rpc_ok = RPC_CLIENT.get_health().await.is_ok();
+    record_service_result("RPC", rpc_ok);
+
+    sleep(HEALTH_PROBE_INTERVAL).await;
+}
@@ -0,0 +1,34 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+use futures::stream::{self, Stream};
+use std::{fs, path::Path};
+use yellowstone_grpc_proto::{geyser::SubscribeUpdate, prost::Message, tonic::Status};
+
+/// Loads recorded updates from a file with one base64-encoded protobuf
+/// `SubscribeUpdate` per line. Blank lines and `#` comments are skipped.
+pub fn load_replay_updates(path: &Path) -> Result<Vec<SubscribeUpdate>, Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(path)?;
+    let mut updates = Vec::new();
+
+    for (line_no, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let bytes = STANDARD
+            .decode(line)
+            .map_err(|e| format!("line {}: invalid base64: {}", line_no + 1, e))?;
+        let update = SubscribeUpdate::decode(bytes.as_slice())
+            .map_err(|e| format!("line {}: invalid SubscribeUpdate: {}", line_no + 1, e))?;
+        updates.push(update);
+    }
+
+    Ok(updates)
+}
+
+/// Wraps recorded updates in the same stream shape the gRPC subscription yields.
+pub fn replay_stream(
+    updates: Vec<SubscribeUpdate>,
+) -> impl Stream<Item = Result<SubscribeUpdate, Status>> + Unpin {
+    stream::iter(updates.into_iter().map(Ok))
+}
@@ -0,0 +1,129 @@
+use once_cell::sync::Lazy;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::{Launchpad, counter_value};
+
+/// Totals for the current run, printed when the sniper stops
+pub static STATS: Lazy<SessionStats> = Lazy::new(SessionStats::new);
+
+/// Running totals of one sniper session
+#[derive(Debug)]
+pub struct SessionStats {
+    started_at: Instant,
+    transactions_processed: AtomicU64,
+    launches: Mutex<BTreeMap<String, u64>>,
+    sol_spent_lamports: AtomicU64,
+    fees_paid_lamports: AtomicU64,
+    positions_closed: AtomicU64,
+    positions_won: AtomicU64,
+    positions_lost: AtomicU64,
+    realized_pnl_lamports: AtomicI64,
+}
+
+impl SessionStats {
+    pub fn new() -> Self {
+        SessionStats {
+            started_at: Instant::now(),
+            transactions_processed: AtomicU64::new(0),
+            launches: Mutex::new(BTreeMap::new()),
+            sol_spent_lamports: AtomicU64::new(0),
+            fees_paid_lamports: AtomicU64::new(0),
+            positions_closed: AtomicU64::new(0),
+            positions_won: AtomicU64::new(0),
+            positions_lost: AtomicU64::new(0),
+            realized_pnl_lamports: AtomicI64::new(0),
+        }
+    }
+
+    /// Counts one transaction update taken off the stream
+    pub fn record_transaction(&self) {
+        self.transactions_processed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Counts one detected launch on `launchpad`
+    pub fn record_launch(&self, launchpad: Launchpad) {
+        *self.launches.lock().unwrap().entry(format!("{:?}", launchpad)).or_insert(0) += 1;
+    }
+
+    /// Adds a landed buy's spend and the fees paid for it
+    pub fn record_buy_landed(&self, sol_spent_lamports: u64, fees_lamports: u64) {
+        self.sol_spent_lamports.fetch_add(sol_spent_lamports, Ordering::Relaxed);
+        self.fees_paid_lamports.fetch_add(fees_lamports, Ordering::Relaxed);
+    }
+
+    /// Counts a sold position; `proceeds_lamports` is None when the SOL received isn't known
+    pub fn record_position_closed(&self, sol_spent_lamports: u64, proceeds_lamports: Option<u64>) {
+        self.positions_closed.fetch_add(1, Ordering::Relaxed);
+
+        let Some(proceeds) = proceeds_lamports else {
+            return;
+        };
+        let pnl = proceeds as i64 - sol_spent_lamports as i64;
+        self.realized_pnl_lamports.fetch_add(pnl, Ordering::Relaxed);
+        if pnl >= 0 {
+            self.positions_won.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.positions_lost.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Time since the session started
+    pub fn uptime(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    /// Prints the end-of-session report
+    pub fn print_summary(&self) {
+        let sol = |lamports: u64| lamports as f64 / 10_f64.powi(9);
+        let uptime = self.uptime().as_secs();
+
+        println!("📋 ===== Session summary =====");
+        println!("⏱️ Uptime: {}h {}m {}s", uptime / 3600, uptime / 60 % 60, uptime % 60);
+        println!("📨 Transactions processed: {}", self.transactions_processed.load(Ordering::Relaxed));
+
+        let launches = self.launches.lock().unwrap();
+        if launches.is_empty() {
+            println!("🎯 Launches detected: 0");
+        } else {
+            let per_program: Vec<String> = launches.iter().map(|(name, count)| format!("{} {}", name, count)).collect();
+            println!("🎯 Launches detected: {}", per_program.join(", "));
+        }
+
+        println!(
+            "💸 Buys: {} attempted, {} landed, {} failed, {} unconfirmed",
+            counter_value("trades_attempted"),
+            counter_value("buys_landed"),
+            counter_value("buys_failed"),
+            counter_value("buys_confirm_timeout")
+        );
+        println!(
+            "💰 SOL spent: {} SOL, fees paid: {} SOL",
+            sol(self.sol_spent_lamports.load(Ordering::Relaxed)),
+            sol(self.fees_paid_lamports.load(Ordering::Relaxed))
+        );
+
+        let closed = self.positions_closed.load(Ordering::Relaxed);
+        if closed > 0 {
+            let won = self.positions_won.load(Ordering::Relaxed);
+            let lost = self.positions_lost.load(Ordering::Relaxed);
+            println!(
+                "📈 Closed positions: {} ({} won, {} lost, {} unpriced), realized PnL {} SOL",
+                closed,
+                won,
+                lost,
+                closed - won - lost,
+                self.realized_pnl_lamports.load(Ordering::Relaxed) as f64 / 10_f64.powi(9)
+            );
+        }
+        println!("📋 ===========================");
+    }
+}
+
+impl Default for SessionStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
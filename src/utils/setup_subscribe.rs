@@ -22,13 +22,50 @@
  * @version 2.0.0
  */
 
-use futures::SinkExt;
-use std::collections::HashMap;
+use futures::{SinkExt, Stream, StreamExt, stream};
+use rand::Rng;
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 use yellowstone_grpc_client::{ClientTlsConfig, GeyserGrpcClient, Interceptor};
-use yellowstone_grpc_proto::geyser::{
-    CommitmentLevel, SubscribeRequest, SubscribeRequestFilterTransactions,
+use yellowstone_grpc_proto::{
+    geyser::{
+        CommitmentLevel, SubscribeRequest, SubscribeRequestFilterSlots, SubscribeRequestFilterTransactions,
+        SubscribeUpdate,
+    },
+    tonic::Status,
 };
 
+use crate::{CONFIG, set_gauge};
+
+/**
+ * Computes the delay before a connect or reconnect attempt
+ * 
+ * The ceiling doubles per attempt from `base_ms` up to `cap_ms`. With
+ * `jitter` the delay is drawn uniformly from `[0, ceiling]` (full
+ * jitter), so a fleet that lost the same stream doesn't reconnect in
+ * lockstep; otherwise the ceiling itself is used.
+ * 
+ * @param base_ms - Delay ceiling of the first attempt
+ * @param cap_ms - Largest ceiling any attempt may reach
+ * @param attempt - Zero-based count of consecutive failed attempts
+ * @param jitter - Draw the delay at random below the ceiling (`[grpc].backoff_jitter` for the stream)
+ * @returns u64 - Delay in milliseconds, never above `max(base_ms, cap_ms)`
+ */
+pub fn backoff_delay_ms(base_ms: u64, cap_ms: u64, attempt: u32, jitter: bool) -> u64 {
+    let ceiling = base_ms
+        .saturating_mul(1u64 << attempt.min(16))
+        .min(cap_ms.max(base_ms));
+
+    if jitter {
+        rand::thread_rng().gen_range(0..=ceiling)
+    } else {
+        ceiling
+    }
+}
+
 /**
  * Sets up gRPC client connection with comprehensive configuration
  * 
@@ -37,6 +74,7 @@ use yellowstone_grpc_proto::geyser::{
  * 
  * Connection Features:
  * - TLS/SSL encryption for secure communication
+ * - HTTP/2 keepalive pings from the `[grpc]` config
  * - Authentication token validation
  * - Connection timeout and retry logic
  * - Native root certificate validation
@@ -84,6 +122,19 @@ pub async fn setup_client_grpc(
         }
     };
     
+    // Configure HTTP/2 keepalive so idle streams aren't silently dropped
+    let grpc_config = &CONFIG.grpc;
+    let client = client
+        .http2_keep_alive_interval(Duration::from_secs(grpc_config.keep_alive_interval_secs))
+        .keep_alive_timeout(Duration::from_secs(grpc_config.keep_alive_timeout_secs))
+        .keep_alive_while_idle(grpc_config.keep_alive_while_idle);
+    println!(
+        "💓 Keepalive configured: every {}s, timeout {}s, while idle: {}",
+        grpc_config.keep_alive_interval_secs,
+        grpc_config.keep_alive_timeout_secs,
+        grpc_config.keep_alive_while_idle
+    );
+    
     // Configure TLS with native root certificates
     let client = match client.tls_config(ClientTlsConfig::new().with_native_roots()) {
         Ok(client) => {
@@ -112,6 +163,91 @@ pub async fn setup_client_grpc(
     Ok(client)
 }
 
+/**
+ * Checks whether a stream error was caused by a keepalive ping timing out
+ * 
+ * @param status - Error status yielded by the stream
+ * @returns bool - True if the connection died on a keepalive timeout
+ */
+pub fn is_keepalive_timeout(status: &Status) -> bool {
+    let message = status.message().to_ascii_lowercase();
+    message.contains("keepalive") || message.contains("keep-alive") || message.contains("ping timeout")
+}
+
+/**
+ * Picks the slot to resume from after a reconnect
+ * 
+ * Resumes from just after the last seen slot when `resume_on_reconnect` is
+ * set. If the gap to the current cluster slot exceeds `max_resume_gap_slots`
+ * the downtime is too long to be worth replaying, so the stream starts at
+ * the tip instead.
+ * 
+ * @param last_seen_slot - Highest slot processed before the disconnect (0 if none)
+ * @param current_slot - Current cluster slot (0 if unknown)
+ * @returns Option<u64> - Slot to pass as `from_slot`, or None for the tip
+ */
+pub fn resume_from_slot(last_seen_slot: u64, current_slot: u64) -> Option<u64> {
+    let grpc_config = &CONFIG.grpc;
+    if !grpc_config.resume_on_reconnect || last_seen_slot == 0 {
+        return None;
+    }
+
+    let gap = current_slot.saturating_sub(last_seen_slot);
+    if gap > grpc_config.max_resume_gap_slots {
+        println!(
+            "⏭️ Missed {} slots while disconnected (max {}), starting at tip",
+            gap, grpc_config.max_resume_gap_slots
+        );
+        return None;
+    }
+
+    Some(last_seen_slot + 1)
+}
+
+/**
+ * Waits for the first update on a fresh subscription
+ * 
+ * A wrong token or program filter leaves the stream silent, which looks the
+ * same as a quiet market. Any update (including a ping) proves the stream is
+ * live. The first update is put back in front of the stream so nothing is
+ * lost, and its latency is recorded in the "grpc_first_update_ms" gauge.
+ * 
+ * Skipped when `[grpc].preflight_timeout_secs` is 0.
+ * 
+ * @param stream - Update stream returned by the subscription
+ * @returns Result<impl Stream, Box<dyn std::error::Error>> - The full stream, or an error if no data arrived
+ */
+pub async fn await_first_update<S>(
+    mut stream: S,
+) -> Result<impl Stream<Item = Result<SubscribeUpdate, Status>> + Unpin, Box<dyn std::error::Error>>
+where
+    S: Stream<Item = Result<SubscribeUpdate, Status>> + Unpin,
+{
+    let timeout_secs = CONFIG.grpc.preflight_timeout_secs;
+    if timeout_secs == 0 {
+        return Ok(stream::iter(None).chain(stream));
+    }
+
+    println!("⏳ Waiting up to {}s for the first gRPC update...", timeout_secs);
+    let started = Instant::now();
+
+    match tokio::time::timeout(Duration::from_secs(timeout_secs), stream.next()).await {
+        Ok(Some(Ok(first))) => {
+            let latency_ms = started.elapsed().as_millis();
+            set_gauge("grpc_first_update_ms", latency_ms as f64);
+            println!("✅ gRPC stream healthy, first update received in {}ms", latency_ms);
+            Ok(stream::iter(Some(Ok(first))).chain(stream))
+        }
+        Ok(Some(Err(status))) => Err(format!("gRPC stream failed before the first update: {}", status).into()),
+        Ok(None) => Err("gRPC stream closed before the first update".into()),
+        Err(_) => Err(format!(
+            "No data received within {}s, check endpoint/token/filter",
+            timeout_secs
+        )
+        .into()),
+    }
+}
+
 /**
  * Validates gRPC endpoint format
  * 
@@ -153,11 +289,13 @@ fn is_valid_grpc_endpoint(endpoint: &str) -> bool {
  * 
  * @param tx - Subscription sender channel
  * @param subscribe_args - Transaction filter configuration
+ * @param from_slot - Slot to replay from, or None to start at the tip
  * @returns Result<(), Box<dyn std::error::Error>> - Success or error
  */
 pub async fn send_subscription_request_grpc<T>(
     mut tx: T,
     subscribe_args: SubscribeRequestFilterTransactions,
+    from_slot: Option<u64>,
 ) -> Result<(), Box<dyn std::error::Error>>
 where
     T: SinkExt<SubscribeRequest> + Unpin,
@@ -180,13 +318,32 @@ where
         println!("   {}. {}", i + 1, program);
     }
     
+    // Slot status updates tell when a processed launch's slot gets confirmed
+    let mut slots_filter = HashMap::new();
+    if CONFIG.trade.require_confirmed {
+        slots_filter.insert(
+            "confirm_tracker".to_string(),
+            SubscribeRequestFilterSlots {
+                filter_by_commitment: Some(false),
+                ..Default::default()
+            },
+        );
+        println!("🔒 Launches will wait for their slot to be confirmed");
+    }
+    
     // Create subscription request with optimal settings
     let subscription_request = SubscribeRequest {
         transactions: accounts_filter,
+        slots: slots_filter,
         commitment: Some(CommitmentLevel::Processed as i32),
+        from_slot,
         ..Default::default()
     };
     
+    if let Some(slot) = from_slot {
+        println!("⏪ Resuming subscription from slot {}", slot);
+    }
+    
     // Send subscription request with error handling
     println!("📤 Sending subscription request...");
     match tx.send(subscription_request).await {
@@ -258,27 +415,111 @@ pub fn validate_subscription_filter(filter: &SubscribeRequestFilterTransactions)
     Ok(())
 }
 
+/// Idle time after which the stream is reported as unhealthy
+pub const STREAM_IDLE_WARN_SECS: u64 = 30;
+
+/// How often the stream health monitor checks for idleness
+const STREAM_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Health of the gRPC stream, shared by every component that watches it
+pub static STREAM_HEALTH: StreamHealth = StreamHealth::new();
+
+/// Time of the last update received on the gRPC stream
+#[derive(Debug)]
+pub struct StreamHealth {
+    last_update_ms: AtomicU64,
+}
+
+impl StreamHealth {
+    pub const fn new() -> Self {
+        StreamHealth {
+            last_update_ms: AtomicU64::new(0),
+        }
+    }
+
+    /// Records that an update (of any kind, pings included) just arrived
+    pub fn mark_activity(&self) {
+        self.last_update_ms.store(epoch_ms(), Ordering::Relaxed);
+    }
+
+    /// Epoch milliseconds of the last update, or None before the first one
+    pub fn last_update_ms(&self) -> Option<u64> {
+        match self.last_update_ms.load(Ordering::Relaxed) {
+            0 => None,
+            ms => Some(ms),
+        }
+    }
+
+    /// Seconds since the last update, or None before the first one
+    pub fn seconds_since_last(&self) -> Option<u64> {
+        self.last_update_ms()
+            .map(|last| epoch_ms().saturating_sub(last) / 1000)
+    }
+
+    /// True once an update arrived within the last `STREAM_IDLE_WARN_SECS`
+    pub fn is_healthy(&self) -> bool {
+        self.seconds_since_last()
+            .is_some_and(|idle| idle < STREAM_IDLE_WARN_SECS)
+    }
+}
+
+impl Default for StreamHealth {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn epoch_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
 /**
- * Creates connection health check function
- * 
- * This function returns a closure that can be used to check
- * the health of the gRPC connection and trigger reconnection if needed.
+ * Reports how long the stream has been idle, then waits
  * 
- * @returns impl Fn() -> bool - Health check function
+ * Publishes the idle time as the `stream_idle_seconds` gauge and warns
+ * while no update has arrived for `STREAM_IDLE_WARN_SECS`.
  */
-pub fn create_health_check() -> impl Fn() -> bool {
-    let mut last_activity = std::time::Instant::now();
-    
-    move || {
-        let now = std::time::Instant::now();
-        let duration = now.duration_since(last_activity);
-        
-        // Consider connection healthy if activity within last 30 seconds
-        if duration.as_secs() < 30 {
-            last_activity = now;
-            true
-        } else {
-            false
+pub async fn stream_health_handler() {
+    if let Some(idle_secs) = STREAM_HEALTH.seconds_since_last() {
+        set_gauge("stream_idle_seconds", idle_secs as f64);
+        if idle_secs >= STREAM_IDLE_WARN_SECS {
+            println!("⚠️ No gRPC update for {}s, the stream may be stalled", idle_secs);
+        }
+    }
+
+    tokio::time::sleep(STREAM_HEALTH_CHECK_INTERVAL).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE_MS: u64 = 250;
+    const CAP_MS: u64 = 10_000;
+
+    #[test]
+    fn backoff_without_jitter_doubles_up_to_the_cap() {
+        let delays: Vec<u64> = (0..8).map(|attempt| backoff_delay_ms(BASE_MS, CAP_MS, attempt, false)).collect();
+        assert_eq!(delays, [250, 500, 1_000, 2_000, 4_000, 8_000, 10_000, 10_000]);
+        assert_eq!(backoff_delay_ms(BASE_MS, CAP_MS, u32::MAX, false), CAP_MS);
+    }
+
+    #[test]
+    fn backoff_with_jitter_stays_under_the_ceiling() {
+        for attempt in 0..20 {
+            let ceiling = BASE_MS.saturating_mul(1 << attempt.min(16)).min(CAP_MS);
+            for _ in 0..100 {
+                let delay = backoff_delay_ms(BASE_MS, CAP_MS, attempt, true);
+                assert!(delay <= ceiling, "attempt {}: {} > {}", attempt, delay, ceiling);
+            }
         }
     }
+
+    #[test]
+    fn backoff_cap_below_base_uses_the_base() {
+        assert_eq!(backoff_delay_ms(BASE_MS, 100, 3, false), BASE_MS);
+    }
 }
@@ -0,0 +1,112 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use once_cell::sync::Lazy;
+use solana_sdk::commitment_config::CommitmentConfig;
+use std::sync::Mutex;
+
+use crate::{CONFIG, RPC_CLIENT};
+
+/// How often the last seen slot is written to `[grpc].slot_state_path`
+const SLOT_STATE_SAVE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// When the slot state file was last written
+static LAST_SLOT_STATE_SAVE: Lazy<Mutex<Option<Instant>>> = Lazy::new(|| Mutex::new(None));
+
+/// Set while a save runs on the blocking pool, so writes to the temp file never overlap
+static SLOT_STATE_SAVING: AtomicBool = AtomicBool::new(false);
+
+/// Reads a saved slot, or None if the file is missing or doesn't hold a slot.
+pub fn load_slot_state(path: &Path) -> Option<u64> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(e) => {
+            println!("⚠️ Failed to read slot state {}: {}, starting at tip", path.display(), e);
+            return None;
+        }
+    };
+
+    match content.trim().parse::<u64>() {
+        Ok(slot) if slot > 0 => Some(slot),
+        _ => {
+            println!("⚠️ Slot state {} is corrupted, ignoring it and starting at tip", path.display());
+            None
+        }
+    }
+}
+
+/// Writes a slot through a temp file and rename, so a crash mid-write can't leave a torn file.
+pub fn save_slot_state(path: &Path, slot: u64) -> std::io::Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, slot.to_string())?;
+    fs::rename(&tmp_path, path)
+}
+
+/// Saves the slot if `[grpc].slot_state_path` is set and the last save is old enough.
+/// The write runs on the blocking pool so a slow disk can't stall the update stream;
+/// a save that would overlap one still in flight is skipped.
+pub fn maybe_save_slot_state(slot: u64) {
+    let Some(path) = CONFIG.grpc.slot_state_path.as_deref() else {
+        return;
+    };
+    if slot == 0 {
+        return;
+    }
+
+    let mut last_save = LAST_SLOT_STATE_SAVE.lock().unwrap();
+    if last_save.is_some_and(|saved| saved.elapsed() < SLOT_STATE_SAVE_INTERVAL) {
+        return;
+    }
+    if SLOT_STATE_SAVING.swap(true, Ordering::AcqRel) {
+        return;
+    }
+    *last_save = Some(Instant::now());
+    drop(last_save);
+
+    let path = PathBuf::from(path);
+    tokio::task::spawn_blocking(move || {
+        if let Err(e) = save_slot_state(&path, slot) {
+            println!("⚠️ Failed to save slot state to {}: {}", path.display(), e);
+        }
+        SLOT_STATE_SAVING.store(false, Ordering::Release);
+    });
+}
+
+/**
+ * Picks the slot for the first subscription from the saved slot state
+ * 
+ * The saved slot is only used when the current cluster slot can be fetched
+ * and is at most `max_resume_gap_slots` ahead of it; otherwise the downtime
+ * is too long to be worth replaying and the stream starts at the tip.
+ * 
+ * @returns Option<u64> - Slot to pass as `from_slot`, or None for the tip
+ */
+pub async fn startup_from_slot() -> Option<u64> {
+    let path = CONFIG.grpc.slot_state_path.as_deref()?;
+    let saved_slot = load_slot_state(Path::new(path))?;
+
+    let current_slot = match RPC_CLIENT.get_slot_with_commitment(CommitmentConfig::processed()).await {
+        Ok(slot) => slot,
+        Err(e) => {
+            println!("⚠️ Couldn't fetch the current slot to check saved slot {}: {}, starting at tip", saved_slot, e);
+            return None;
+        }
+    };
+
+    let gap = current_slot.saturating_sub(saved_slot);
+    if gap > CONFIG.grpc.max_resume_gap_slots {
+        println!(
+            "⏭️ Saved slot {} is {} slots behind (max {}), starting at tip",
+            saved_slot, gap, CONFIG.grpc.max_resume_gap_slots
+        );
+        return None;
+    }
+
+    println!("💾 Resuming from saved slot {} ({} slots behind)", saved_slot, gap);
+    Some(saved_slot + 1)
+}
@@ -0,0 +1,331 @@
+/**
+ * 📤 Transaction Submission Module - Bonk.fun Trading Sniper Bot
+ * 
+ * This module sends signed transactions and retries transient failures
+ * within a fixed time budget, since a snipe that lands late is worthless.
+ * 
+ * Repository: https://github.com/solship/bonkfun-trading-snipper-grpc.git
+ * @author solship
+ * @version 2.0.0
+ */
+
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    rpc_client::SerializableTransaction,
+};
+use solana_sdk::{signature::Signature, transaction::TransactionError};
+use std::{fmt, future::Future};
+use tokio::time::{Duration, Instant, sleep, timeout};
+
+use crate::{
+    CONFIRM_SERVICE, SEND_RPC_CLIENT, SniperError, increment_counter, is_service_healthy, record_service_result,
+};
+#[cfg(feature = "relayers")]
+use crate::{CONFIG, JITO_CLIENT, NOZOMI_CLIENT, ZSLOT_CLIENT};
+
+/// Errors raised while submitting a transaction
+#[derive(Debug)]
+pub enum SubmitError {
+    Rpc(ClientError),
+    Relayer { service: &'static str, message: String },
+    /// The relayer accepted the connection but never answered
+    RelayerTimeout { service: &'static str, timeout: Duration },
+    /// The transaction couldn't be built or signed, so nothing was sent
+    Build(SniperError),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Rpc(e) => write!(f, "rpc submission failed: {}", e),
+            SubmitError::Relayer { service, message } => {
+                write!(f, "{} submission failed: {}", service, message)
+            }
+            SubmitError::RelayerTimeout { service, timeout } => {
+                write!(f, "{} submission timed out after {}ms", service, timeout.as_millis())
+            }
+            SubmitError::Build(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<ClientError> for SubmitError {
+    fn from(e: ClientError) -> Self {
+        SubmitError::Rpc(e)
+    }
+}
+
+/**
+ * Runs one relayer call, giving up once `limit` has passed
+ * 
+ * A relayer that accepts the request but never answers would otherwise
+ * hold the trade task forever. A timeout is counted in
+ * `relayer_submit_timeouts{service="..."}` and returned as its own error,
+ * which the retry loop treats as a failed attempt.
+ * 
+ * @param service - Relayer being called, for errors and metrics
+ * @param limit - Longest the call may take
+ * @param call - The relayer request
+ * @returns Result<T, SubmitError> - The call's result, or a relayer error or timeout
+ */
+pub async fn relayer_call<F, T, E>(service: &'static str, limit: Duration, call: F) -> Result<T, SubmitError>
+where
+    F: Future<Output = Result<T, E>>,
+    E: fmt::Display,
+{
+    match timeout(limit, call).await {
+        Ok(result) => result.map_err(|e| SubmitError::Relayer {
+            service,
+            message: e.to_string(),
+        }),
+        Err(_) => {
+            increment_counter(&format!("relayer_submit_timeouts{{service=\"{}\"}}", service));
+            Err(SubmitError::RelayerTimeout { service, timeout: limit })
+        }
+    }
+}
+
+/**
+ * Sends a signed transaction through the configured confirmation service
+ * 
+ * Transactions bound for Jito must already carry a tip transfer
+ * (see `jito_tip_ix`). Uninitialized relayers fall back to direct RPC.
+ * Each relayer call is bounded by `[services].submit_timeout_ms`.
+ * 
+ * @param tx - Signed legacy or versioned transaction
+ * @returns Result<Signature, SubmitError> - Signature or submission error
+ */
+pub async fn submit_transaction<T: SerializableTransaction>(tx: &T) -> Result<Signature, SubmitError> {
+    let signature = *tx.get_signature();
+    let service = CONFIRM_SERVICE.as_str();
+
+    // Skip a relayer that keeps failing and go straight to RPC
+    if !is_service_healthy(service) {
+        println!("⚠️ {} is unhealthy, falling back to RPC", service);
+        return Ok(SEND_RPC_CLIENT.send_transaction(tx).await?);
+    }
+
+    #[cfg(feature = "relayers")]
+    let limit = Duration::from_millis(CONFIG.services.submit_timeout_ms);
+
+    #[cfg(feature = "relayers")]
+    let relayer_result = match service {
+        "NOZOMI" => match NOZOMI_CLIENT.get() {
+            Some(client) => Some(relayer_call("NOZOMI", limit, client.send_transaction(tx)).await.map(|_| ())),
+            None => None,
+        },
+        "ZERO_SLOT" => match ZSLOT_CLIENT.get() {
+            Some(client) => Some(relayer_call("ZERO_SLOT", limit, client.send_transaction(tx)).await.map(|_| ())),
+            None => None,
+        },
+        "JITO" => match JITO_CLIENT.get() {
+            Some(client) => Some(relayer_call("JITO", limit, client.send_transaction(tx)).await.map(|_| ())),
+            None => None,
+        },
+        _ => None,
+    };
+
+    // Built without relayer clients: everything goes over RPC
+    #[cfg(not(feature = "relayers"))]
+    let relayer_result: Option<Result<(), SubmitError>> = None;
+
+    match relayer_result {
+        Some(Ok(())) => {
+            record_service_result(service, true);
+            Ok(signature)
+        }
+        Some(Err(e)) => {
+            record_service_result(service, false);
+            Err(e)
+        }
+        None => Ok(SEND_RPC_CLIENT.send_transaction(tx).await?),
+    }
+}
+
+/**
+ * Classifies a submission error as worth retrying
+ * 
+ * Expired blockhashes and network/node hiccups are transient. Errors that
+ * will repeat on every attempt (insufficient funds, program errors) are not.
+ * Relayer errors are opaque, so they are treated as transient.
+ * 
+ * @param err - Error returned by the submission
+ * @returns bool - True if the transaction should be rebuilt and resent
+ */
+pub fn is_transient_error(err: &SubmitError) -> bool {
+    match err {
+        SubmitError::Rpc(e) => is_transient_client_error(e),
+        SubmitError::Relayer { .. } | SubmitError::RelayerTimeout { .. } => true,
+        SubmitError::Build(_) => false,
+    }
+}
+
+/// True if an RPC error is worth retrying (network issues, expired blockhash)
+pub fn is_transient_client_error(err: &ClientError) -> bool {
+    if let Some(tx_err) = err.get_transaction_error() {
+        return matches!(
+            tx_err,
+            TransactionError::BlockhashNotFound | TransactionError::WouldExceedMaxBlockCostLimit
+        );
+    }
+
+    match err.kind() {
+        ClientErrorKind::Io(_) | ClientErrorKind::Reqwest(_) | ClientErrorKind::Middleware(_) => true,
+        ClientErrorKind::RpcError(_) => true,
+        _ => false,
+    }
+}
+
+/**
+ * Decides whether the next attempt must resend the exact transaction just sent
+ * 
+ * A relayer that timed out may still have forwarded it. Resending the same
+ * signed transaction can land it at most once, while re-signing with a new
+ * blockhash makes a second transaction that could land as well. That holds
+ * for every later attempt too, until the blockhash of the unanswered send
+ * has expired and it can no longer land.
+ * 
+ * @param err - Error of the attempt just made
+ * @param unanswered - Whether an earlier attempt of this transaction timed out
+ * @returns bool - True to resend the same transaction, false to rebuild it
+ */
+fn must_resend(err: &SubmitError, unanswered: bool) -> bool {
+    let blockhash_expired = matches!(
+        err,
+        SubmitError::Rpc(e) if matches!(e.get_transaction_error(), Some(TransactionError::BlockhashNotFound))
+    );
+    !blockhash_expired && (unanswered || matches!(err, SubmitError::RelayerTimeout { .. }))
+}
+
+/**
+ * Submits a transaction, retrying transient failures with exponential backoff
+ * 
+ * The transaction is rebuilt on every attempt so each retry picks up the
+ * freshest cached blockhash, except after a relayer timeout: then the same
+ * signed transaction goes out again (see `must_resend`). Retries stop at
+ * `max_attempts` or once the total `budget` has elapsed, whichever comes
+ * first. A transaction that fails to build is returned as an error without
+ * sending anything.
+ * 
+ * @param tx_builder - Builds and signs a fresh transaction
+ * @param max_attempts - Maximum number of sends
+ * @param base_delay - Delay before the first retry, doubled each time
+ * @param budget - Total time allowed for all attempts
+ * @returns Result<Signature, SubmitError> - Signature or last error
+ */
+pub async fn submit_with_retry<F, T>(
+    tx_builder: F,
+    max_attempts: u32,
+    base_delay: Duration,
+    budget: Duration,
+) -> Result<Signature, SubmitError>
+where
+    F: Fn() -> Result<T, SniperError>,
+    T: SerializableTransaction,
+{
+    let started = Instant::now();
+    let mut delay = base_delay;
+    let mut attempt = 0;
+    let mut unanswered = false;
+    let mut resend: Option<T> = None;
+
+    loop {
+        attempt += 1;
+        let tx = match resend.take() {
+            Some(tx) => tx,
+            None => tx_builder().map_err(SubmitError::Build)?,
+        };
+
+        match submit_transaction(&tx).await {
+            Ok(signature) => {
+                if attempt > 1 {
+                    println!("✅ Transaction submitted on attempt {}: {}", attempt, signature);
+                }
+                return Ok(signature);
+            }
+            Err(e) if !is_transient_error(&e) => {
+                eprintln!("❌ Permanent submission error, not retrying: {}", e);
+                return Err(e);
+            }
+            Err(e) => {
+                let elapsed = started.elapsed();
+                if attempt >= max_attempts || elapsed + delay >= budget {
+                    eprintln!(
+                        "❌ Giving up after {} attempts in {}ms: {}",
+                        attempt,
+                        elapsed.as_millis(),
+                        e
+                    );
+                    return Err(e);
+                }
+
+                println!("⚠️ Transient submission error on attempt {}: {}, retrying in {}ms", attempt, e, delay.as_millis());
+                unanswered = must_resend(&e, unanswered);
+                if unanswered {
+                    resend = Some(tx);
+                }
+                sleep(delay).await;
+                delay *= 2;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: Duration = Duration::from_millis(500);
+
+    #[tokio::test(start_paused = true)]
+    async fn relayer_that_never_answers_times_out() {
+        let call = std::future::pending::<Result<(), String>>();
+        let result = relayer_call("NOZOMI", LIMIT, call).await;
+
+        assert!(matches!(
+            result,
+            Err(SubmitError::RelayerTimeout { service: "NOZOMI", timeout }) if timeout == LIMIT
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn relayer_error_is_kept_apart_from_a_timeout() {
+        let call = async { Err::<(), _>("rejected") };
+        let result = relayer_call("ZERO_SLOT", LIMIT, call).await;
+
+        assert!(matches!(
+            result,
+            Err(SubmitError::Relayer { service: "ZERO_SLOT", ref message }) if message == "rejected"
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn relayer_answer_within_the_limit_is_returned() {
+        let call = async {
+            tokio::time::sleep(LIMIT / 2).await;
+            Ok::<_, String>(7)
+        };
+
+        assert!(matches!(relayer_call("JITO", LIMIT, call).await, Ok(7)));
+    }
+
+    #[test]
+    fn timed_out_send_is_resent_as_is() {
+        let timeout = SubmitError::RelayerTimeout { service: "NOZOMI", timeout: LIMIT };
+        let rejected = SubmitError::Relayer { service: "NOZOMI", message: "rejected".to_string() };
+
+        assert!(must_resend(&timeout, false));
+        assert!(!must_resend(&rejected, false));
+        // The unanswered send may still land, so later errors don't license a re-sign either
+        assert!(must_resend(&rejected, true));
+    }
+
+    #[test]
+    fn expired_blockhash_allows_a_rebuild() {
+        let expired = SubmitError::Rpc(ClientError::from(TransactionError::BlockhashNotFound));
+
+        assert!(!must_resend(&expired, true));
+    }
+}